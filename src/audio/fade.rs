@@ -0,0 +1,203 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Volume fades
+//!
+//! Fade in, fade out and crossfade any sound source. The fades are driven
+//! by the time elapsed since the last frame: call update once per frame
+//! with the faded sources.
+
+use audio::{Playing, Stopped};
+use system::Time;
+use traits::SoundSource;
+
+/// Linear volume ramp applied to a sound source
+pub struct Fade {
+    #[doc(hidden)]
+    from: f32,
+    #[doc(hidden)]
+    to: f32,
+    #[doc(hidden)]
+    duration: f32,
+    #[doc(hidden)]
+    elapsed: f32,
+    #[doc(hidden)]
+    stop_at_end: bool,
+    #[doc(hidden)]
+    finished: bool
+}
+
+impl Fade {
+    /// Create a new fade between two volumes
+    ///
+    /// The fade doesn't start or stop the source by itself, use
+    /// fade_in and fade_out for that.
+    ///
+    /// # Arguments
+    /// * from - The volume at the start, in the range [0, 100]
+    /// * to - The volume at the end, in the range [0, 100]
+    /// * duration - The duration of the fade
+    ///
+    /// Return a new Fade
+    pub fn new(from: f32, to: f32, duration: &Time) -> Fade {
+        Fade {
+            from: from,
+            to: to,
+            duration: duration.as_seconds(),
+            elapsed: 0.,
+            stop_at_end: false,
+            finished: false
+        }
+    }
+
+    /// Advance the fade and apply the volume to the source
+    ///
+    /// If the fade was created with fade_out, the source is stopped
+    /// when the fade ends and its volume is restored, so that playing
+    /// it again is not silent.
+    ///
+    /// # Arguments
+    /// * source - The faded source
+    /// * elapsed - Time elapsed since the last update
+    pub fn update<S: SoundSource>(&mut self, source: &mut S, elapsed: &Time) -> () {
+        if self.finished {
+            return
+        }
+        self.elapsed += elapsed.as_seconds();
+        if self.elapsed >= self.duration {
+            self.elapsed = self.duration;
+            self.finished = true;
+            if self.stop_at_end {
+                source.stop();
+                source.set_volume(self.from);
+                return
+            }
+        }
+        source.set_volume(self.get_volume())
+    }
+
+    /// Get the current volume of the fade
+    ///
+    /// Return the interpolated volume
+    pub fn get_volume(&self) -> f32 {
+        let progress = if self.duration > 0. { self.elapsed / self.duration } else { 1. };
+        self.from + (self.to - self.from) * progress
+    }
+
+    /// Tell whether the fade reached its end
+    ///
+    /// Return true if the fade is finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// Two simultaneous fades, from a source to another
+pub struct Crossfade {
+    #[doc(hidden)]
+    fade_out: Fade,
+    #[doc(hidden)]
+    fade_in: Fade
+}
+
+impl Crossfade {
+    /// Advance the crossfade and apply the volumes to the sources
+    ///
+    /// # Arguments
+    /// * from - The source fading out
+    /// * to - The source fading in
+    /// * elapsed - Time elapsed since the last update
+    pub fn update<A: SoundSource, B: SoundSource>(&mut self,
+                                                 from: &mut A,
+                                                 to: &mut B,
+                                                 elapsed: &Time) -> () {
+        self.fade_out.update(from, elapsed);
+        self.fade_in.update(to, elapsed)
+    }
+
+    /// Tell whether the crossfade reached its end
+    ///
+    /// Return true if the crossfade is finished
+    pub fn is_finished(&self) -> bool {
+        self.fade_out.is_finished() && self.fade_in.is_finished()
+    }
+}
+
+/// Start playing a source from silence up to a volume
+///
+/// If the source is already playing, it isn't restarted and the fade
+/// starts from its current volume.
+///
+/// # Arguments
+/// * source - The source to fade in
+/// * volume - The volume to reach, in the range [0, 100]
+/// * duration - The duration of the fade
+///
+/// Return the fade to update every frame
+pub fn fade_in<S: SoundSource>(source: &mut S, volume: f32, duration: &Time) -> Fade {
+    let from = if source.get_status() == Stopped {
+        0.
+    } else {
+        source.get_volume()
+    };
+    source.set_volume(from);
+    if source.get_status() != Playing {
+        source.play();
+    }
+    Fade::new(from, volume, duration)
+}
+
+/// Fade a source out to silence, then stop it
+///
+/// # Arguments
+/// * source - The source to fade out
+/// * duration - The duration of the fade
+///
+/// Return the fade to update every frame
+pub fn fade_out<S: SoundSource>(source: &S, duration: &Time) -> Fade {
+    let mut fade = Fade::new(source.get_volume(), 0., duration);
+    fade.stop_at_end = true;
+    fade
+}
+
+/// Fade a source out while another one fades in
+///
+/// The source fading in reaches the volume the other one had
+/// when the crossfade started.
+///
+/// # Arguments
+/// * from - The source to fade out
+/// * to - The source to fade in
+/// * duration - The duration of the crossfade
+///
+/// Return the crossfade to update every frame
+pub fn crossfade<A: SoundSource, B: SoundSource>(from: &mut A,
+                                                 to: &mut B,
+                                                 duration: &Time) -> Crossfade {
+    let volume = from.get_volume();
+    Crossfade {
+        fade_out: fade_out(from, duration),
+        fade_in: fade_in(to, volume, duration)
+    }
+}
@@ -128,3 +128,46 @@ pub fn get_direction() -> Vector3f {
         ffi::sfListener_getDirection()
     }
 }
+
+/// Set the upward vector of the listener in the scene
+///
+/// The up vector is the vector that points upward from the
+/// listener's perspective. Together with the direction, it
+/// defines the 3D orientation of the listener in the scene.
+/// The up vector doesn't have to be normalized.
+/// The default listener's up vector is (0, 1, 0).
+///
+/// # Arguments
+/// * up_vector - New listener's up vector
+pub fn set_up_vector(up_vector: &Vector3f) -> () {
+    unsafe {
+        ffi::sfListener_setUpVector(*up_vector)
+    }
+}
+
+/// Set the upward vector of the listener in the scene
+///
+/// The up vector is the vector that points upward from the
+/// listener's perspective. Together with the direction, it
+/// defines the 3D orientation of the listener in the scene.
+/// The up vector doesn't have to be normalized.
+/// The default listener's up vector is (0, 1, 0).
+///
+/// # Arguments
+/// * x - X coordinate of the listener's up vector
+/// * y - Y coordinate of the listener's up vector
+/// * z - Z coordinate of the listener's up vector
+pub fn set_up_vector3f(x: f32, y: f32, z: f32) -> () {
+    unsafe {
+        ffi::sfListener_setUpVector(Vector3f::new(x, y, z))
+    }
+}
+
+/// Get the current upward vector of the listener in the scene
+///
+/// Return the listener's up vector
+pub fn get_up_vector() -> Vector3f {
+    unsafe {
+        ffi::sfListener_getUpVector()
+    }
+}
@@ -26,11 +26,15 @@
 * Sounds, streaming (musics or custom sources), recording, spatialization
 */
 
-pub use audio::sound_buffer::SoundBuffer;
+pub use audio::sound_buffer::{SoundBuffer, SoundFileFormat};
 pub use audio::sound_status::{Status, Playing, Paused, Stopped};
-pub use audio::music::Music;
+pub use audio::music::{Music, TimeSpan};
 pub use audio::sound::Sound;
 pub use audio::sound_buffer_recorder::SoundBufferRecorder;
+pub use audio::sound_stream::{SoundStream, SoundStreamPlayer};
+pub use audio::sound_recorder::{SoundRecorder, SoundRecorderDriver};
+pub use audio::playback_watcher::PlaybackWatcher;
+pub use audio::sound_pool::SoundPool;
 
 /// Sound implementation using reference counting to manage shared resources
 pub mod rc {
@@ -46,7 +50,12 @@ mod platform {
 
 mod sound_buffer;
 pub mod listener;
+pub mod fade;
 mod sound_status;
 mod music;
 mod sound;
 mod sound_buffer_recorder;
+mod sound_stream;
+mod sound_recorder;
+mod playback_watcher;
+mod sound_pool;
@@ -32,20 +32,49 @@ use std::{ptr, mem};
 use std::ffi::CString;
 
 use audio::Status;
-use system::Time;
+use system::{Time, InputStream};
 use system::vector3::Vector3f;
-use traits::Wrappable;
+use system::input_stream::OwnedInputStream;
+use traits::{Wrappable, SoundSource};
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::audio::music as ffi;
 
+/// Range of time of a music, defined by a starting offset and a length
+pub struct TimeSpan {
+    /// The beginning offset of the time range
+    pub offset: Time,
+    /// The length of the time range
+    pub length: Time
+}
+
+impl TimeSpan {
+    /// Create a new time span
+    ///
+    /// # Arguments
+    /// * offset - The beginning offset of the time range
+    /// * length - The length of the time range
+    ///
+    /// Return a new TimeSpan
+    pub fn new(offset: Time, length: Time) -> TimeSpan {
+        TimeSpan {
+            offset: offset,
+            length: length
+        }
+    }
+}
+
 /// Play Music
 ///
 /// Streamed music played from an audio file.
 /// Musics are sounds that are streamed rather than completely loaded in memory.
 pub struct Music {
     #[doc(hidden)]
-    music: *mut ffi::sfMusic
+    music: *mut ffi::sfMusic,
+    #[doc(hidden)]
+    stream: Option<OwnedInputStream>,
+    #[doc(hidden)]
+    data: Option<Vec<u8>>
 }
 
 impl Music {
@@ -71,7 +100,9 @@ impl Music {
             None
         } else {
             Some(Music{
-                    music: music_tmp
+                    music: music_tmp,
+                    stream: None,
+                    data: None
                 })
         }
     }
@@ -84,17 +115,52 @@ impl Music {
     /// ogg, wav, flac, aiff, au, raw, paf, svx, nist, voc, ircam,
     /// w64, mat4, mat5 pvf, htk, sds, avr, sd2, caf, wve, mpc2k, rf64.
     ///
+    /// The music is streamed, so it keeps reading the file data while
+    /// it plays: the data is copied and owned by the music.
+    ///
     /// # Arguments
     /// * mem - Pointer to the file data in memory
     ///
     /// Return Some(Music) or None
     pub fn new_from_memory(mem: &[u8]) -> Option<Music> {
-        let music_tmp = unsafe { ffi::sfMusic_createFromMemory(&mem[0], mem.len() as size_t) };
+        if mem.is_empty() {
+            return None
+        }
+        let data = mem.to_vec();
+        let music_tmp = unsafe {
+            ffi::sfMusic_createFromMemory(data.as_ptr(), data.len() as size_t)
+        };
         if music_tmp.is_null() {
             None
         } else {
             Some(Music{
-                    music: music_tmp
+                    music: music_tmp,
+                    stream: None,
+                    data: Some(data)
+                })
+        }
+    }
+
+    /// Create a new music and load it from a custom stream
+    ///
+    /// This function doesn't start playing the music (call
+    /// play to do so). The music is streamed, so it keeps reading
+    /// from the stream while it plays: the stream is owned by the music.
+    ///
+    /// # Arguments
+    /// * stream - Source stream to read from
+    ///
+    /// Return Some(Music) or None
+    pub fn new_from_stream(stream: Box<InputStream + 'static>) -> Option<Music> {
+        let mut stream = OwnedInputStream::new(stream);
+        let music_tmp = unsafe { ffi::sfMusic_createFromStream(stream.unwrap()) };
+        if music_tmp.is_null() {
+            None
+        } else {
+            Some(Music{
+                    music: music_tmp,
+                    stream: Some(stream),
+                    data: None
                 })
         }
     }
@@ -134,6 +200,35 @@ impl Music {
         Wrappable::wrap( unsafe { ffi::sfMusic_getDuration(self.music) })
     }
 
+    /// Set the beginning and duration of the music's loop sequence
+    ///
+    /// When the music loops, it restarts from the beginning of the
+    /// loop sequence instead of the beginning of the music, which
+    /// allows an intro to be played only once.
+    /// The loop points are clamped to the duration of the music, and
+    /// the default loop sequence covers the whole music.
+    /// Loop points have no effect unless looping is enabled with set_loop.
+    ///
+    /// # Arguments
+    /// * time_points - The offset and length of the loop sequence
+    pub fn set_loop_points(&mut self, time_points: TimeSpan) -> () {
+        unsafe {
+            ffi::sfMusic_setLoopPoints(self.music, ffi::sfTimeSpan {
+                offset: time_points.offset.unwrap(),
+                length: time_points.length.unwrap()
+            })
+        }
+    }
+
+    /// Get the positions of the music's loop sequence
+    ///
+    /// Return the offset and length of the loop sequence
+    pub fn get_loop_points(&self) -> TimeSpan {
+        let time_points = unsafe { ffi::sfMusic_getLoopPoints(self.music) };
+        TimeSpan::new(Wrappable::wrap(time_points.offset),
+                      Wrappable::wrap(time_points.length))
+    }
+
     /// Start or resume playing a music
     ///
     /// This function starts the music if it was stopped, resumes
@@ -388,6 +483,72 @@ impl Music {
     }
 }
 
+impl SoundSource for Music {
+    fn play(&mut self) -> () {
+        self.play()
+    }
+
+    fn pause(&mut self) -> () {
+        self.pause()
+    }
+
+    fn stop(&mut self) -> () {
+        self.stop()
+    }
+
+    fn get_status(&self) -> Status {
+        self.get_status()
+    }
+
+    fn set_volume(&mut self, volume: f32) -> () {
+        self.set_volume(volume)
+    }
+
+    fn get_volume(&self) -> f32 {
+        self.get_volume()
+    }
+
+    fn set_pitch(&mut self, pitch: f32) -> () {
+        self.set_pitch(pitch)
+    }
+
+    fn get_pitch(&self) -> f32 {
+        self.get_pitch()
+    }
+
+    fn set_position(&mut self, position: &Vector3f) -> () {
+        self.set_position(position)
+    }
+
+    fn get_position(&self) -> Vector3f {
+        self.get_position()
+    }
+
+    fn set_attenuation(&mut self, attenuation: f32) -> () {
+        self.set_attenuation(attenuation)
+    }
+
+    fn get_attenuation(&self) -> f32 {
+        self.get_attenuation()
+    }
+
+    fn set_min_distance(&mut self, distance: f32) -> () {
+        self.set_min_distance(distance)
+    }
+
+    fn get_min_distance(&self) -> f32 {
+        self.get_min_distance()
+    }
+
+    fn set_relative_to_listener(&mut self, relative: bool) -> () {
+        self.set_relative_to_listener(relative)
+    }
+
+    fn is_relative_to_listener(&self) -> bool {
+        self.is_relative_to_listener()
+    }
+}
+
 impl Drop for Music {
     /// Destructor for class Music. Destroy all the ressource.
     fn drop(&mut self) {
@@ -0,0 +1,81 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Detect the end of the playback of sounds and musics
+//!
+//! CSFML provides no notification when a source stops playing, so a
+//! PlaybackWatcher remembers the last status it saw and reports the
+//! transition to the stopped state exactly once.
+
+use audio::{Status, Stopped};
+use traits::SoundSource;
+
+/// Detect the end of the playback of a sound source
+///
+/// Call has_finished once per frame (or whenever convenient) with the
+/// watched source: it returns true the first time the source is seen
+/// stopped after having been seen playing or paused. Stopping the
+/// source explicitly is reported as well.
+pub struct PlaybackWatcher {
+    #[doc(hidden)]
+    status: Status
+}
+
+impl PlaybackWatcher {
+    /// Create a new playback watcher
+    ///
+    /// Return a new PlaybackWatcher
+    pub fn new() -> PlaybackWatcher {
+        PlaybackWatcher {
+            status: Stopped
+        }
+    }
+
+    /// Check whether a source just finished playing
+    ///
+    /// # Arguments
+    /// * source - The watched source
+    ///
+    /// Return true if the source stopped since the last check
+    pub fn has_finished<S: SoundSource>(&mut self, source: &S) -> bool {
+        let status = source.get_status();
+        let finished = self.status != Stopped && status == Stopped;
+        self.status = status;
+        finished
+    }
+
+    /// Get the last status seen by the watcher
+    ///
+    /// Return the status of the source at the last check
+    pub fn get_status(&self) -> Status {
+        self.status
+    }
+
+    /// Forget the last status seen by the watcher
+    ///
+    /// This is useful when the watcher is reused for another source.
+    pub fn reset(&mut self) -> () {
+        self.status = Stopped
+    }
+}
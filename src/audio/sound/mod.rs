@@ -32,7 +32,7 @@ use std::mem;
 use audio::{Status, SoundBuffer};
 use system::Time;
 use system::vector3::Vector3f;
-use traits::Wrappable;
+use traits::{Wrappable, SoundSource};
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::audio::sound as ffi;
@@ -371,6 +371,72 @@ impl<'s> Sound<'s> {
     }
 }
 
+impl<'s> SoundSource for Sound<'s> {
+    fn play(&mut self) -> () {
+        self.play()
+    }
+
+    fn pause(&mut self) -> () {
+        self.pause()
+    }
+
+    fn stop(&mut self) -> () {
+        self.stop()
+    }
+
+    fn get_status(&self) -> Status {
+        self.get_status()
+    }
+
+    fn set_volume(&mut self, volume: f32) -> () {
+        self.set_volume(volume)
+    }
+
+    fn get_volume(&self) -> f32 {
+        self.get_volume()
+    }
+
+    fn set_pitch(&mut self, pitch: f32) -> () {
+        self.set_pitch(pitch)
+    }
+
+    fn get_pitch(&self) -> f32 {
+        self.get_pitch()
+    }
+
+    fn set_position(&mut self, position: &Vector3f) -> () {
+        self.set_position(position)
+    }
+
+    fn get_position(&self) -> Vector3f {
+        self.get_position()
+    }
+
+    fn set_attenuation(&mut self, attenuation: f32) -> () {
+        self.set_attenuation(attenuation)
+    }
+
+    fn get_attenuation(&self) -> f32 {
+        self.get_attenuation()
+    }
+
+    fn set_min_distance(&mut self, distance: f32) -> () {
+        self.set_min_distance(distance)
+    }
+
+    fn get_min_distance(&self) -> f32 {
+        self.get_min_distance()
+    }
+
+    fn set_relative_to_listener(&mut self, relative: bool) -> () {
+        self.set_relative_to_listener(relative)
+    }
+
+    fn is_relative_to_listener(&self) -> bool {
+        self.is_relative_to_listener()
+    }
+}
+
 #[unsafe_destructor]
 impl<'s> Drop for Sound<'s> {
     /// Destructor for class Sound. Destroy all the ressource.
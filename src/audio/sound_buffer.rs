@@ -26,15 +26,31 @@
 //!
 //! A sound buffer holds the data of a sound, which is an array of audio samples.
 
+use libc::c_uint;
 use std::ptr;
+use std::mem;
 use std::ffi::CString;
+use std::io::{File, TempDir};
+use core::raw;
 
 use traits::Wrappable;
-use system::Time;
+use system::{Time, InputStream};
+use system::input_stream::InputStreamBridge;
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::audio::sound_buffer as ffi;
 
+/// Audio file formats which a SoundBuffer can be encoded to in memory
+#[derive(Clone, PartialEq, Eq, Show, Copy)]
+pub enum SoundFileFormat {
+    /// 16 bits PCM WAV file
+    Wav,
+    /// Ogg Vorbis file
+    Ogg,
+    /// FLAC file
+    Flac
+}
+
 /// Storage of audio sample
 ///
 /// A sound buffer holds the data of a sound, which is an array of audio samples.
@@ -72,6 +88,81 @@ impl SoundBuffer {
         }
     }
 
+    /// Create a new sound buffer and load it from a custom stream
+    ///
+    /// The supported audio formats are the same as new.
+    ///
+    /// # Arguments
+    /// * stream - Source stream to read from
+    ///
+    /// Return an option to a SoundBuffer object or None.
+    pub fn new_from_stream(stream: &mut InputStream) -> Option<SoundBuffer> {
+        let mut bridge = InputStreamBridge::new(stream);
+        let sound_buffer = unsafe {
+            ffi::sfSoundBuffer_createFromStream(bridge.unwrap())
+        };
+        if sound_buffer.is_null() {
+            None
+        } else {
+            Some(SoundBuffer{
+                    sound_buffer: sound_buffer,
+                    dropable: true
+                })
+        }
+    }
+
+    /// Create a new sound buffer and load it from an array of samples in memory
+    ///
+    /// The samples are interleaved 16 bits signed integers: for a
+    /// stereo sound, the left and right samples alternate.
+    /// The samples are copied, so the slice can be freed afterwards.
+    ///
+    /// # Arguments
+    /// * samples - The array of samples
+    /// * channel_count - Number of channels (1 = mono, 2 = stereo, ...)
+    /// * sample_rate - Sample rate (number of samples to play per second)
+    ///
+    /// Return Some(SoundBuffer) or None
+    pub fn new_from_samples(samples: &[i16],
+                            channel_count: uint,
+                            sample_rate: uint) -> Option<SoundBuffer> {
+        let sound_buffer = unsafe {
+            ffi::sfSoundBuffer_createFromSamples(samples.as_ptr(),
+                                                 samples.len() as u64,
+                                                 channel_count as c_uint,
+                                                 sample_rate as c_uint)
+        };
+        if sound_buffer.is_null() {
+            None
+        } else {
+            Some(SoundBuffer {
+                    sound_buffer: sound_buffer,
+                    dropable: true
+                })
+        }
+    }
+
+    /// Get the array of audio samples stored in a sound buffer
+    ///
+    /// The samples are interleaved 16 bits signed integers, and their
+    /// number is given by get_sample_count.
+    ///
+    /// Return a read-only slice of the samples
+    pub fn get_samples(&self) -> &[i16] {
+        unsafe {
+            let samples = ffi::sfSoundBuffer_getSamples(self.sound_buffer);
+            // An empty buffer has no samples, but a slice needs a non null pointer
+            if samples.is_null() {
+                mem::transmute(raw::Slice { data: 1 as *const i16, len: 0 })
+            } else {
+                mem::transmute(raw::Slice {
+                    data: samples,
+                    len: ffi::sfSoundBuffer_getSampleCount(self.sound_buffer) as uint
+                })
+            }
+        }
+    }
+
     /// Create a new sound buffer by copying an existing one
     ///
     /// Return an option to a cloned SoundBuffer object or None.
@@ -99,9 +190,9 @@ impl SoundBuffer {
     /// Return true if saving succeeded, false if it faileds
     pub fn save_to_file(&self, filename: &str) -> bool {
         let mut return_value: bool = false;
-        let c_str = CString::from_slice(filename.as_bytes()).as_ptr();
+        let c_str = CString::from_slice(filename.as_bytes());
         unsafe {
-            match ffi::sfSoundBuffer_saveToFile(self.sound_buffer, c_str) {
+            match ffi::sfSoundBuffer_saveToFile(self.sound_buffer, c_str.as_ptr()) {
                 SFFALSE => return_value = false,
                 SFTRUE  => return_value = true
             }
@@ -109,6 +200,45 @@ impl SoundBuffer {
         return_value
     }
 
+    /// Encode a sound buffer to an audio file in memory
+    ///
+    /// CSFML can only write encoded audio to files: WAV is encoded
+    /// here, OGG and FLAC go through a temporary file which is read
+    /// back and removed.
+    ///
+    /// # Arguments
+    /// * format - The format of the encoded data
+    ///
+    /// Return Some(data) with the encoded file data, or None if the
+    /// encoding failed
+    pub fn save_to_memory(&self, format: SoundFileFormat) -> Option<Vec<u8>> {
+        match format {
+            SoundFileFormat::Wav  => Some(encode_wav(self.get_samples(),
+                                                     self.get_channel_count(),
+                                                     self.get_sample_rate())),
+            SoundFileFormat::Ogg  => self.save_through_file("ogg"),
+            SoundFileFormat::Flac => self.save_through_file("flac")
+        }
+    }
+
+    // Encode to a temporary file with the given extension and read it back.
+    fn save_through_file(&self, extension: &str) -> Option<Vec<u8>> {
+        let dir = match TempDir::new("rsfml") {
+            Ok(dir) => dir,
+            Err(_)  => return None
+        };
+        let path = dir.path().join(format!("sound.{}", extension));
+        let saved = match path.as_str() {
+            Some(filename) => self.save_to_file(filename),
+            None           => false
+        };
+        if saved {
+            File::open(&path).read_to_end().ok()
+        } else {
+            None
+        }
+    }
+
     /// Get the number of samples stored in a sound buffer
     ///
     /// The array of samples can be accessed with the
@@ -179,3 +309,31 @@ impl Drop for SoundBuffer {
     }
 }
 
+// Write a 16 bits PCM WAV file in memory.
+fn encode_wav(samples: &[i16], channel_count: uint, sample_rate: uint) -> Vec<u8> {
+    let data_size = (samples.len() * 2) as u32;
+    let mut out = Vec::with_capacity(44 + samples.len() * 2);
+    out.push_all(b"RIFF");
+    push_le(&mut out, 36 + data_size, 4);
+    out.push_all(b"WAVE");
+    out.push_all(b"fmt ");
+    push_le(&mut out, 16, 4);
+    push_le(&mut out, 1, 2);
+    push_le(&mut out, channel_count as u32, 2);
+    push_le(&mut out, sample_rate as u32, 4);
+    push_le(&mut out, (sample_rate * channel_count * 2) as u32, 4);
+    push_le(&mut out, (channel_count * 2) as u32, 2);
+    push_le(&mut out, 16, 2);
+    out.push_all(b"data");
+    push_le(&mut out, data_size, 4);
+    for sample in samples.iter() {
+        push_le(&mut out, *sample as u16 as u32, 2);
+    }
+    out
+}
+
+fn push_le(out: &mut Vec<u8>, value: u32, bytes: uint) {
+    for i in range(0, bytes) {
+        out.push((value >> (8 * i)) as u8);
+    }
+}
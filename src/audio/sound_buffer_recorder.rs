@@ -28,9 +28,11 @@
 //! so that it can be played, saved to a file, etc.
 
 use libc::c_uint;
+use std::ffi::CString;
 
 use traits::Wrappable;
 use audio::sound_buffer::SoundBuffer;
+use audio::sound_recorder;
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::audio::sound_buffer_recorder as ffi;
@@ -113,6 +115,52 @@ impl SoundBufferRecorder {
         }
     }
 
+    /// Set the audio capture device
+    ///
+    /// This function sets the audio capture device to the device
+    /// with the given name. It can be called on the fly (i.e.
+    /// while recording). If you do so while recording and
+    /// opening the device fails, the capture stops.
+    ///
+    /// # Arguments
+    /// * name - The name of the audio capture device
+    ///
+    /// Return true if the device was successfully set, false otherwise
+    pub fn set_device(&mut self, name: &str) -> bool {
+        let c_str = CString::from_slice(name.as_bytes());
+        match unsafe { ffi::sfSoundBufferRecorder_setDevice(self.sound_buffer_recorder, c_str.as_ptr()) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Get the name of the current audio capture device
+    ///
+    /// Return the name of the current audio capture device
+    pub fn get_device(&self) -> String {
+        unsafe {
+            sound_recorder::to_string(ffi::sfSoundBufferRecorder_getDevice(self.sound_buffer_recorder))
+        }
+    }
+
+    /// Get a list of the names of all available audio capture devices
+    ///
+    /// Return the names of all available audio capture devices
+    pub fn get_available_devices() -> Vec<String> {
+        sound_recorder::get_available_devices()
+    }
+
+    /// Get the name of the default audio capture device
+    ///
+    /// This function returns the name of the default audio
+    /// capture device. If none is available, an empty string
+    /// is returned.
+    ///
+    /// Return the name of the default audio capture device
+    pub fn get_default_device() -> String {
+        sound_recorder::get_default_device()
+    }
+
     /// Check if the system supports audio capture
     ///
     /// This function should always be called before using
@@ -0,0 +1,152 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Polyphonic pool of sounds
+//!
+//! OpenAL limits the number of sounds which can exist at the same time,
+//! so a SoundPool creates a fixed set of sounds once and reuses them,
+//! stealing the oldest voice when all of them are busy.
+
+use audio::{Sound, SoundBuffer, Stopped};
+use system::vector3::Vector3f;
+
+/// Polyphonic pool of sounds
+///
+/// Each sound of the pool is a voice. Playing a buffer uses a stopped
+/// voice if there is one, otherwise the voice which started playing
+/// the earliest is interrupted and reused.
+pub struct SoundPool<'s> {
+    #[doc(hidden)]
+    voices: Vec<Sound<'s>>,
+    #[doc(hidden)]
+    stamps: Vec<u64>,
+    #[doc(hidden)]
+    counter: u64
+}
+
+impl<'s> SoundPool<'s> {
+    /// Create a new sound pool
+    ///
+    /// # Arguments
+    /// * voice_count - Number of sounds of the pool
+    ///
+    /// Return Some(SoundPool) or None if the sounds can't be created
+    pub fn new(voice_count: uint) -> Option<SoundPool<'s>> {
+        let mut voices = Vec::with_capacity(voice_count);
+        for _ in range(0, voice_count) {
+            match Sound::new() {
+                Some(sound) => voices.push(sound),
+                None        => return None
+            }
+        }
+        Some(SoundPool {
+                voices: voices,
+                stamps: Vec::from_elem(voice_count, 0u64),
+                counter: 0
+            })
+    }
+
+    /// Play a sound buffer on a free voice of the pool
+    ///
+    /// If all the voices are busy, the oldest one is stopped and reused.
+    ///
+    /// # Arguments
+    /// * buffer - The sound buffer to play
+    /// * volume - Volume of the sound, in the range [0, 100]
+    /// * pitch - Pitch of the sound
+    /// * position - Position of the sound in the scene
+    ///
+    /// Return the index of the voice playing the sound, or None if the pool is empty
+    pub fn play(&mut self,
+                buffer: &'s SoundBuffer,
+                volume: f32,
+                pitch: f32,
+                position: &Vector3f) -> Option<uint> {
+        let index = match self.find_voice() {
+            Some(index) => index,
+            None        => return None
+        };
+        self.counter += 1;
+        self.stamps[index] = self.counter;
+
+        let voice = &mut self.voices[index];
+        voice.stop();
+        voice.set_buffer(buffer);
+        voice.set_volume(volume);
+        voice.set_pitch(pitch);
+        voice.set_position(position);
+        voice.play();
+        Some(index)
+    }
+
+    /// Stop all the voices of the pool
+    pub fn stop_all(&mut self) -> () {
+        for voice in self.voices.iter_mut() {
+            voice.stop()
+        }
+    }
+
+    /// Get a voice of the pool
+    ///
+    /// # Arguments
+    /// * index - Index of the voice, as returned by play
+    ///
+    /// Return Some(voice) or None if the index is out of range
+    pub fn get_voice_mut(&mut self, index: uint) -> Option<&mut Sound<'s>> {
+        if index < self.voices.len() {
+            Some(&mut self.voices[index])
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of voices of the pool
+    ///
+    /// Return the number of voices
+    pub fn get_voice_count(&self) -> uint {
+        self.voices.len()
+    }
+
+    /// Get the number of voices currently playing or paused
+    ///
+    /// Return the number of busy voices
+    pub fn get_busy_count(&self) -> uint {
+        self.voices.iter().filter(|voice| voice.get_status() != Stopped).count()
+    }
+
+    // Pick the first stopped voice, or the one started the earliest.
+    fn find_voice(&self) -> Option<uint> {
+        let mut oldest: Option<uint> = None;
+        for (i, voice) in self.voices.iter().enumerate() {
+            if voice.get_status() == Stopped {
+                return Some(i)
+            }
+            oldest = match oldest {
+                Some(j) if self.stamps[j] <= self.stamps[i] => Some(j),
+                _                                           => Some(i)
+            };
+        }
+        oldest
+    }
+}
@@ -0,0 +1,331 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Custom audio capture
+//!
+//! SoundRecorder lets you process the captured audio samples as soon
+//! as they are available (voice chat, level meters...), instead of
+//! only recording them into a SoundBuffer.
+
+use libc::{c_void, c_uint, c_char, size_t};
+use std::mem;
+use std::ffi::{CString, c_str_to_bytes};
+use core::raw;
+
+use system::Time;
+use traits::Wrappable;
+
+use ffi::sfml_types::{SfBool, SFTRUE, SFFALSE};
+use ffi::audio::sound_recorder as ffi;
+
+/// Receiver of the audio samples captured by a SoundRecorderDriver
+///
+/// on_process_samples and on_stop are called from the capture thread,
+/// so the implementor has to be sendable.
+pub trait SoundRecorder {
+    /// Start capturing audio data
+    ///
+    /// This is called once before the capture begins. The default
+    /// implementation does nothing.
+    ///
+    /// Return true to start the capture, or false to abort it
+    fn on_start(&mut self) -> bool {
+        true
+    }
+
+    /// Process a new chunk of captured audio samples
+    ///
+    /// # Arguments
+    /// * samples - The captured samples
+    ///
+    /// Return true to continue the capture, or false to stop it
+    fn on_process_samples(&mut self, samples: &[i16]) -> bool;
+
+    /// Stop capturing audio data
+    ///
+    /// This is called once after the capture ends. The default
+    /// implementation does nothing.
+    fn on_stop(&mut self) {}
+}
+
+/// Driver of the audio capture for a custom SoundRecorder
+///
+/// The driver owns the recorder and feeds it with the captured
+/// samples while the capture runs.
+pub struct SoundRecorderDriver<R: SoundRecorder + Send> {
+    #[doc(hidden)]
+    sound_recorder: *mut ffi::sfSoundRecorder,
+    #[doc(hidden)]
+    recorder: Box<R>
+}
+
+impl<R: SoundRecorder + Send> SoundRecorderDriver<R> {
+    /// Create a new driver for a custom sound recorder
+    ///
+    /// # Arguments
+    /// * recorder - The receiver of the captured samples
+    ///
+    /// Return Some(SoundRecorderDriver) or None
+    pub fn new(recorder: R) -> Option<SoundRecorderDriver<R>> {
+        let mut recorder = Box::new(recorder);
+        let user_data = &mut *recorder as *mut R as *mut c_void;
+        let sound_recorder = unsafe {
+            ffi::sfSoundRecorder_create(start::<R>, process::<R>, stop::<R>, user_data)
+        };
+        if sound_recorder.is_null() {
+            None
+        } else {
+            Some(SoundRecorderDriver {
+                    sound_recorder: sound_recorder,
+                    recorder: recorder
+                })
+        }
+    }
+
+    /// Get a mutable access to the underlying recorder
+    ///
+    /// The capture is stopped first, so that the capture thread
+    /// can't use the recorder at the same time.
+    ///
+    /// Return the recorder fed by this driver
+    pub fn get_recorder_mut(&mut self) -> &mut R {
+        self.stop();
+        &mut *self.recorder
+    }
+
+    /// Start the capture
+    ///
+    /// The sampleRate parameter defines the number of audio samples
+    /// captured per second. The higher, the better the quality
+    /// (for example, 44100 samples/sec is CD quality).
+    /// This function uses its own thread so that it doesn't block
+    /// the rest of the program while the capture runs.
+    /// Please note that only one capture can happen at the same time.
+    ///
+    /// # Arguments
+    /// * sample_rate - Desired capture rate, in number of samples per second
+    ///
+    /// Return true if the capture started, false otherwise
+    pub fn start(&mut self, sample_rate: uint) -> bool {
+        match unsafe { ffi::sfSoundRecorder_start(self.sound_recorder, sample_rate as c_uint) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Stop the capture
+    pub fn stop(&mut self) -> () {
+        unsafe {
+            ffi::sfSoundRecorder_stop(self.sound_recorder)
+        }
+    }
+
+    /// Get the sample rate of the capture
+    ///
+    /// The sample rate defines the number of audio samples
+    /// captured per second. The higher, the better the quality
+    /// (for example, 44100 samples/sec is CD quality).
+    ///
+    /// Return the sample rate, in samples per second
+    pub fn get_sample_rate(&self) -> uint {
+        unsafe {
+            ffi::sfSoundRecorder_getSampleRate(self.sound_recorder) as uint
+        }
+    }
+
+    /// Set the channel count of the audio capture device
+    ///
+    /// This method allows you to specify the number of channels
+    /// used for recording. Currently only 16-bit mono (1) and
+    /// 16-bit stereo (2) are supported.
+    /// The default channel count is 1 (mono).
+    ///
+    /// # Arguments
+    /// * channel_count - Number of channels
+    pub fn set_channel_count(&mut self, channel_count: uint) -> () {
+        unsafe {
+            ffi::sfSoundRecorder_setChannelCount(self.sound_recorder, channel_count as c_uint)
+        }
+    }
+
+    /// Get the number of channels used by the recorder
+    ///
+    /// Return the number of channels
+    pub fn get_channel_count(&self) -> uint {
+        unsafe {
+            ffi::sfSoundRecorder_getChannelCount(self.sound_recorder) as uint
+        }
+    }
+
+    /// Set the processing interval
+    ///
+    /// The processing interval controls the period between calls
+    /// to on_process_samples. A lower interval reduces the latency
+    /// of the capture, at the cost of more frequent calls.
+    /// Note: this is only a hint, the actual period may vary.
+    /// The default processing interval is 100 ms.
+    ///
+    /// # Arguments
+    /// * interval - Processing interval
+    pub fn set_processing_interval(&mut self, interval: Time) -> () {
+        unsafe {
+            ffi::sfSoundRecorder_setProcessingInterval(self.sound_recorder, interval.unwrap())
+        }
+    }
+
+    /// Set the audio capture device
+    ///
+    /// This function sets the audio capture device to the device
+    /// with the given name. It can be called on the fly (i.e.
+    /// while recording). If you do so while recording and
+    /// opening the device fails, the capture stops.
+    ///
+    /// # Arguments
+    /// * name - The name of the audio capture device
+    ///
+    /// Return true if the device was successfully set, false otherwise
+    pub fn set_device(&mut self, name: &str) -> bool {
+        let c_str = CString::from_slice(name.as_bytes());
+        match unsafe { ffi::sfSoundRecorder_setDevice(self.sound_recorder, c_str.as_ptr()) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Get the name of the current audio capture device
+    ///
+    /// Return the name of the current audio capture device
+    pub fn get_device(&self) -> String {
+        unsafe {
+            to_string(ffi::sfSoundRecorder_getDevice(self.sound_recorder))
+        }
+    }
+
+    /// Get a list of the names of all available audio capture devices
+    ///
+    /// Return the names of all available audio capture devices
+    pub fn get_available_devices() -> Vec<String> {
+        get_available_devices()
+    }
+
+    /// Get the name of the default audio capture device
+    ///
+    /// This function returns the name of the default audio
+    /// capture device. If none is available, an empty string
+    /// is returned.
+    ///
+    /// Return the name of the default audio capture device
+    pub fn get_default_device() -> String {
+        get_default_device()
+    }
+
+    /// Check if the system supports audio capture
+    ///
+    /// This function should always be called before using
+    /// the audio capture features. If it returns false, then
+    /// any attempt to start a capture will fail.
+    ///
+    /// Return true if audio capture is supported, false otherwise
+    pub fn is_available() -> bool {
+        match unsafe { ffi::sfSoundRecorder_isAvailable() } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+}
+
+#[unsafe_destructor]
+impl<R: SoundRecorder + Send> Drop for SoundRecorderDriver<R> {
+    /// Destructor for class SoundRecorderDriver. Destroy all the ressource.
+    fn drop(&mut self) {
+        unsafe {
+            ffi::sfSoundRecorder_destroy(self.sound_recorder);
+        }
+    }
+}
+
+// Shared with SoundBufferRecorder, which uses the same device list.
+#[doc(hidden)]
+pub fn get_available_devices() -> Vec<String> {
+    let mut count = 0 as size_t;
+    unsafe {
+        let devices = ffi::sfSoundRecorder_getAvailableDevices(&mut count);
+        if devices.is_null() {
+            return Vec::new()
+        }
+        range(0, count as uint).map(|i| to_string(*devices.offset(i as int))).collect()
+    }
+}
+
+#[doc(hidden)]
+pub fn get_default_device() -> String {
+    unsafe {
+        to_string(ffi::sfSoundRecorder_getDefaultDevice())
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn to_string(name: *const c_char) -> String {
+    if name.is_null() {
+        String::new()
+    } else {
+        String::from_utf8_lossy(c_str_to_bytes(&name)).into_owned()
+    }
+}
+
+#[doc(hidden)]
+extern "C" fn start<R: SoundRecorder>(user_data: *mut c_void) -> SfBool {
+    unsafe {
+        let recorder: &mut R = mem::transmute(user_data);
+        match recorder.on_start() {
+            true  => SFTRUE,
+            false => SFFALSE
+        }
+    }
+}
+
+#[doc(hidden)]
+extern "C" fn process<R: SoundRecorder>(samples: *const i16,
+                                        sample_count: size_t,
+                                        user_data: *mut c_void) -> SfBool {
+    unsafe {
+        let recorder: &mut R = mem::transmute(user_data);
+        let samples: &[i16] = mem::transmute(raw::Slice {
+            data: samples,
+            len: sample_count as uint
+        });
+        match recorder.on_process_samples(samples) {
+            true  => SFTRUE,
+            false => SFFALSE
+        }
+    }
+}
+
+#[doc(hidden)]
+extern "C" fn stop<R: SoundRecorder>(user_data: *mut c_void) {
+    unsafe {
+        let recorder: &mut R = mem::transmute(user_data);
+        recorder.on_stop()
+    }
+}
@@ -0,0 +1,497 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Custom streamed audio sources
+//!
+//! SoundStream lets you play audio which is generated or decoded on the
+//! fly (synthesizers, tracker music, network voice chat...) instead of
+//! being entirely loaded in a SoundBuffer.
+
+use libc::{c_void, c_uint, c_float};
+use std::mem;
+
+use audio::Status;
+use system::Time;
+use system::vector3::Vector3f;
+use traits::{Wrappable, SoundSource};
+
+use ffi::sfml_types::{SfBool, SFTRUE, SFFALSE};
+use ffi::system::time::sfTime;
+use ffi::audio::sound_stream as ffi;
+
+/// Source of audio data for a SoundStreamPlayer
+///
+/// The callbacks are called from the audio streaming thread, so the
+/// implementor has to be sendable.
+pub trait SoundStream {
+    /// Request a new chunk of audio samples from the stream
+    ///
+    /// The returned samples are read before the next call, so they can
+    /// be stored in an internal buffer which is reused across calls.
+    ///
+    /// Return Some(samples) to continue playback, or None to stop it
+    fn on_get_data(&mut self) -> Option<&[i16]>;
+
+    /// Change the current playing position in the stream source
+    ///
+    /// # Arguments
+    /// * offset - New playing position, from the beginning of the stream
+    fn on_seek(&mut self, offset: Time);
+}
+
+/// Player for a custom SoundStream
+///
+/// The player owns the stream and asks it for new samples whenever
+/// the audio buffers run out while it plays.
+pub struct SoundStreamPlayer<S: SoundStream + Send> {
+    #[doc(hidden)]
+    sound_stream: *mut ffi::sfSoundStream,
+    #[doc(hidden)]
+    stream: Box<S>
+}
+
+impl<S: SoundStream + Send> SoundStreamPlayer<S> {
+    /// Create a new player for a custom sound stream
+    ///
+    /// This function doesn't start playing the stream (call
+    /// play to do so).
+    ///
+    /// # Arguments
+    /// * stream - The source of the audio samples
+    /// * channel_count - Number of channels of the stream
+    /// * sample_rate - Sample rate of the stream, in samples per second
+    ///
+    /// Return Some(SoundStreamPlayer) or None
+    pub fn new(stream: S,
+               channel_count: uint,
+               sample_rate: uint) -> Option<SoundStreamPlayer<S>> {
+        let mut stream = Box::new(stream);
+        let user_data = &mut *stream as *mut S as *mut c_void;
+        let sound_stream = unsafe {
+            ffi::sfSoundStream_create(get_data::<S>,
+                                      seek::<S>,
+                                      channel_count as c_uint,
+                                      sample_rate as c_uint,
+                                      user_data)
+        };
+        if sound_stream.is_null() {
+            None
+        } else {
+            Some(SoundStreamPlayer {
+                    sound_stream: sound_stream,
+                    stream: stream
+                })
+        }
+    }
+
+    /// Get a mutable access to the underlying stream
+    ///
+    /// The player is stopped first, so that the streaming thread
+    /// can't use the stream at the same time.
+    ///
+    /// Return the stream played by this player
+    pub fn get_stream_mut(&mut self) -> &mut S {
+        self.stop();
+        &mut *self.stream
+    }
+
+    /// Start or resume playing the stream
+    ///
+    /// This function starts the stream if it was stopped, resumes
+    /// it if it was paused, and restarts it from beginning if it
+    /// was it already playing.
+    /// This function uses its own thread so that it doesn't block
+    /// the rest of the program while the stream is played.
+    pub fn play(&mut self) -> () {
+        unsafe {
+            ffi::sfSoundStream_play(self.sound_stream)
+        }
+    }
+
+    /// Pause the stream
+    ///
+    /// This function pauses the stream if it was playing,
+    /// otherwise (stream already paused or stopped) it has no effect.
+    pub fn pause(&mut self) -> () {
+        unsafe {
+            ffi::sfSoundStream_pause(self.sound_stream)
+        }
+    }
+
+    /// Stop playing the stream
+    ///
+    /// This function stops the stream if it was playing or paused,
+    /// and does nothing if it was already stopped.
+    /// It also resets the playing position (unlike pause).
+    pub fn stop(&mut self) -> () {
+        unsafe {
+            ffi::sfSoundStream_stop(self.sound_stream)
+        }
+    }
+
+    /// Get the current status of the stream (stopped, paused, playing)
+    ///
+    /// Return current status
+    pub fn get_status(&self) -> Status {
+        unsafe { mem::transmute(ffi::sfSoundStream_getStatus(self.sound_stream)) }
+    }
+
+    /// Return the number of channels of the stream
+    ///
+    /// 1 channel means a mono sound, 2 means stereo, etc.
+    ///
+    /// Return the number of channels
+    pub fn get_channel_count(&self) -> uint {
+        unsafe {
+            ffi::sfSoundStream_getChannelCount(self.sound_stream) as uint
+        }
+    }
+
+    /// Get the sample rate of the stream
+    ///
+    /// The sample rate is the number of audio samples played per
+    /// second. The higher, the better the quality.
+    ///
+    /// Return the sample rate, in number of samples per second
+    pub fn get_sample_rate(&self) -> uint {
+        unsafe {
+            ffi::sfSoundStream_getSampleRate(self.sound_stream) as uint
+        }
+    }
+
+    /// Set whether or not the stream should loop after reaching the end
+    ///
+    /// If set, the stream is sought back to the beginning after
+    /// reaching the end and so on, until it is stopped or
+    /// set_loop(false) is called.
+    /// The default looping state for streams is false.
+    ///
+    /// # Arguments
+    /// * loop - true to play in loop, false to play once
+    pub fn set_loop(&mut self, lloop: bool) -> () {
+        unsafe {
+            match lloop {
+                true    => ffi::sfSoundStream_setLoop(self.sound_stream, SFTRUE),
+                false   => ffi::sfSoundStream_setLoop(self.sound_stream, SFFALSE)
+            }
+        }
+    }
+
+    /// Tell whether or not the stream is in loop mode
+    ///
+    /// Return true if the stream is looping, false otherwise
+    pub fn get_loop(&self) -> bool {
+        match unsafe { ffi::sfSoundStream_getLoop(self.sound_stream) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Get the current playing position of the stream
+    ///
+    /// Return the current playing position
+    pub fn get_playing_offset(&self) -> Time {
+        Wrappable::wrap(unsafe { ffi::sfSoundStream_getPlayingOffset(self.sound_stream) })
+    }
+
+    /// Change the current playing position of the stream
+    ///
+    /// The playing position can be changed when the stream is
+    /// either paused or playing.
+    ///
+    /// # Arguments
+    /// * time_offset - New playing position
+    pub fn set_playing_offset(&mut self, time_offset: Time) -> () {
+        unsafe {
+            ffi::sfSoundStream_setPlayingOffset(self.sound_stream, time_offset.unwrap())
+        }
+    }
+
+    /// Set the pitch of the stream
+    ///
+    /// The pitch represents the perceived fundamental frequency
+    /// of a sound; thus you can make a stream more acute or grave
+    /// by changing its pitch. A side effect of changing the pitch
+    /// is to modify the playing speed of the stream as well.
+    /// The default value for the pitch is 1.
+    ///
+    /// # Arguments
+    /// * pitch - new pitch to apply to the stream
+    pub fn set_pitch(&mut self, pitch: f32) -> () {
+        unsafe {
+            ffi::sfSoundStream_setPitch(self.sound_stream, pitch as c_float)
+        }
+    }
+
+    /// Get the pitch of the stream
+    ///
+    /// Return the pitch of the stream
+    pub fn get_pitch(&self) -> f32 {
+        unsafe {
+            ffi::sfSoundStream_getPitch(self.sound_stream) as f32
+        }
+    }
+
+    /// Set the volume of the stream
+    ///
+    /// The volume is a value between 0 (mute) and 100 (full volume).
+    /// The default value for the volume is 100.
+    ///
+    /// # Arguments
+    /// * volume - Volume of the stream
+    pub fn set_volume(&mut self, volume: f32) -> () {
+        unsafe {
+            ffi::sfSoundStream_setVolume(self.sound_stream, volume as c_float)
+        }
+    }
+
+    /// Get the volume of the stream
+    ///
+    /// Return the volume of the stream, in the range [0, 100]
+    pub fn get_volume(&self) -> f32 {
+        unsafe {
+            ffi::sfSoundStream_getVolume(self.sound_stream) as f32
+        }
+    }
+
+    /// Make the stream's position relative to the listener or absolute
+    ///
+    /// Making a stream relative to the listener will ensure that it will always
+    /// be played the same way regardless the position of the listener.
+    /// The default value is false (position is absolute).
+    ///
+    /// # Arguments
+    /// * relative - true to set the position relative, false to set it absolute
+    pub fn set_relative_to_listener(&mut self, relative: bool) -> () {
+        unsafe {
+            match relative {
+                true    => ffi::sfSoundStream_setRelativeToListener(self.sound_stream, SFTRUE),
+                false   => ffi::sfSoundStream_setRelativeToListener(self.sound_stream, SFFALSE)
+            }
+        }
+    }
+
+    /// Tell whether the stream's position is relative to the listener or is absolute
+    ///
+    /// Return true if the position is relative, false if it's absolute
+    pub fn is_relative_to_listener(&self) -> bool {
+        match unsafe { ffi::sfSoundStream_isRelativeToListener(self.sound_stream) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Set the minimum distance of the stream
+    ///
+    /// The "minimum distance" of a stream is the maximum
+    /// distance at which it is heard at its maximum volume. Further
+    /// than the minimum distance, it will start to fade out according
+    /// to its attenuation factor. A value of 0 ("inside the head
+    /// of the listener") is an invalid value and is forbidden.
+    /// The default value of the minimum distance is 1.
+    ///
+    /// # Arguments
+    /// * distance - New minimum distance of the stream
+    pub fn set_min_distance(&mut self, distance: f32) -> () {
+        unsafe {
+            ffi::sfSoundStream_setMinDistance(self.sound_stream, distance as c_float)
+        }
+    }
+
+    /// Get the minimum distance of the stream
+    ///
+    /// Return the minimum distance of the stream
+    pub fn get_min_distance(&self) -> f32 {
+        unsafe {
+            ffi::sfSoundStream_getMinDistance(self.sound_stream) as f32
+        }
+    }
+
+    /// Set the attenuation factor of the stream
+    ///
+    /// The attenuation is a multiplicative factor which makes
+    /// the stream more or less loud according to its distance
+    /// from the listener. An attenuation of 0 will produce a
+    /// non-attenuated stream, i.e. its volume will always be the same
+    /// whether it is heard from near or from far.
+    /// The default value of the attenuation is 1.
+    ///
+    /// # Arguments
+    /// * attenuation - New attenuation factor of the stream
+    pub fn set_attenuation(&mut self, attenuation: f32) -> () {
+        unsafe {
+            ffi::sfSoundStream_setAttenuation(self.sound_stream, attenuation as c_float)
+        }
+    }
+
+    /// Get the attenuation factor of the stream
+    ///
+    /// Return the attenuation factor of the stream
+    pub fn get_attenuation(&self) -> f32 {
+        unsafe {
+            ffi::sfSoundStream_getAttenuation(self.sound_stream) as f32
+        }
+    }
+
+    /// Set the 3D position of the stream in the audio scene
+    ///
+    /// Only streams with one channel (mono streams) can be
+    /// spatialized.
+    /// The default position of a stream is (0, 0, 0).
+    ///
+    /// # Arguments
+    /// * position - Position of the stream in the scene
+    pub fn set_position(&mut self, position: &Vector3f) -> () {
+        unsafe {
+            ffi::sfSoundStream_setPosition(self.sound_stream, *position)
+        }
+    }
+
+    /// Set the 3D position of the stream in the audio scene
+    ///
+    /// Only streams with one channel (mono streams) can be
+    /// spatialized.
+    /// The default position of a stream is (0, 0, 0).
+    ///
+    /// # Arguments
+    /// * x - X coordinate of the position of the stream in the scene
+    /// * y - Y coordinate of the position of the stream in the scene
+    /// * z - Z coordinate of the position of the stream in the scene
+    pub fn set_position3f(&mut self, x: f32, y: f32, z: f32) -> () {
+        unsafe {
+            ffi::sfSoundStream_setPosition(self.sound_stream, Vector3f::new(x, y, z))
+        }
+    }
+
+    /// Get the 3D position of the stream in the audio scene
+    ///
+    /// Return the position of the stream in the world
+    pub fn get_position(&self) -> Vector3f {
+        unsafe {
+            ffi::sfSoundStream_getPosition(self.sound_stream)
+        }
+    }
+}
+
+impl<S: SoundStream + Send> SoundSource for SoundStreamPlayer<S> {
+    fn play(&mut self) -> () {
+        self.play()
+    }
+
+    fn pause(&mut self) -> () {
+        self.pause()
+    }
+
+    fn stop(&mut self) -> () {
+        self.stop()
+    }
+
+    fn get_status(&self) -> Status {
+        self.get_status()
+    }
+
+    fn set_volume(&mut self, volume: f32) -> () {
+        self.set_volume(volume)
+    }
+
+    fn get_volume(&self) -> f32 {
+        self.get_volume()
+    }
+
+    fn set_pitch(&mut self, pitch: f32) -> () {
+        self.set_pitch(pitch)
+    }
+
+    fn get_pitch(&self) -> f32 {
+        self.get_pitch()
+    }
+
+    fn set_position(&mut self, position: &Vector3f) -> () {
+        self.set_position(position)
+    }
+
+    fn get_position(&self) -> Vector3f {
+        self.get_position()
+    }
+
+    fn set_attenuation(&mut self, attenuation: f32) -> () {
+        self.set_attenuation(attenuation)
+    }
+
+    fn get_attenuation(&self) -> f32 {
+        self.get_attenuation()
+    }
+
+    fn set_min_distance(&mut self, distance: f32) -> () {
+        self.set_min_distance(distance)
+    }
+
+    fn get_min_distance(&self) -> f32 {
+        self.get_min_distance()
+    }
+
+    fn set_relative_to_listener(&mut self, relative: bool) -> () {
+        self.set_relative_to_listener(relative)
+    }
+
+    fn is_relative_to_listener(&self) -> bool {
+        self.is_relative_to_listener()
+    }
+}
+
+#[unsafe_destructor]
+impl<S: SoundStream + Send> Drop for SoundStreamPlayer<S> {
+    /// Destructor for class SoundStreamPlayer. Destroy all the ressource.
+    fn drop(&mut self) {
+        // Destroying the CSFML stream stops the streaming thread, so the
+        // boxed stream is only freed once nothing uses it anymore.
+        unsafe {
+            ffi::sfSoundStream_destroy(self.sound_stream);
+        }
+    }
+}
+
+#[doc(hidden)]
+extern "C" fn get_data<S: SoundStream>(chunk: *mut ffi::sfSoundStreamChunk,
+                                       user_data: *mut c_void) -> SfBool {
+    unsafe {
+        let stream: &mut S = mem::transmute(user_data);
+        match stream.on_get_data() {
+            Some(samples) => {
+                (*chunk).samples = samples.as_ptr() as *mut i16;
+                (*chunk).sampleCount = samples.len() as c_uint;
+                SFTRUE
+            },
+            None => SFFALSE
+        }
+    }
+}
+
+#[doc(hidden)]
+extern "C" fn seek<S: SoundStream>(time_offset: sfTime, user_data: *mut c_void) {
+    unsafe {
+        let stream: &mut S = mem::transmute(user_data);
+        stream.on_seek(Wrappable::wrap(time_offset))
+    }
+}
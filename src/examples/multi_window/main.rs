@@ -0,0 +1,126 @@
+//! Example: a main window and a tool palette window sharing a texture
+
+#![crate_name = "multi_window"]
+#![crate_type = "bin"]
+
+extern crate rsfml;
+
+use rsfml::graphics::{RenderWindow, RenderWindows, RenderTarget, Color, Image,
+    Texture, Sprite, RectangleShape};
+use rsfml::window::{VideoMode, ContextSettings, event, Close, Titlebar};
+use rsfml::window::keyboard::Key;
+use rsfml::window::mouse::MouseButton;
+use rsfml::system::{Vector2f, Vector2i};
+
+fn main () -> () {
+    let setting: ContextSettings = ContextSettings::default();
+    let main_window = match RenderWindow::new(VideoMode::new_init(800, 600, 32),
+        "SFML multiple windows Example", Close, &setting) {
+        Some(window) => window,
+        None => panic!("Cannot create a new Render Window.")
+    };
+    let mut palette_window = match RenderWindow::new(VideoMode::new_init(120, 400, 32),
+        "Palette", Titlebar, &setting) {
+        Some(window) => window,
+        None => panic!("Cannot create a new Render Window.")
+    };
+    palette_window.set_position(&Vector2i::new(20, 100));
+
+    let mut windows = RenderWindows::new();
+    let main_id = windows.add(main_window);
+    let palette_id = windows.add(palette_window);
+    for id in windows.get_ids().into_iter() {
+        windows.get_mut(id).unwrap().set_vertical_sync_enabled(true);
+    }
+
+    // The texture is created once and drawn in both windows
+    let mut image = Image::new_from_color(64, 64, &Color::white()).expect("Cannot create an image");
+    for x in range(0u, 64) {
+        for y in range(0u, 64) {
+            if (x / 8 + y / 8) % 2 == 0 {
+                image.set_pixel(x, y, &Color::new_RGB(160, 160, 160));
+            }
+        }
+    }
+    let texture = Texture::new_from_image(&image).expect("Cannot create a texture");
+
+    let mut brush = Sprite::new_with_texture(&texture).expect("Cannot create a sprite");
+    brush.set_scale(&Vector2f::new(4., 4.));
+    brush.set_position(&Vector2f::new(272., 172.));
+
+    let mut preview = Sprite::new_with_texture(&texture).expect("Cannot create a sprite");
+    preview.set_position(&Vector2f::new(28., 316.));
+
+    let colors = [Color::white(), Color::red(), Color::green(), Color::blue(),
+                  Color::yellow(), Color::magenta()];
+    let mut swatches = Vec::new();
+    for (i, color) in colors.iter().enumerate() {
+        let mut swatch = RectangleShape::new_init(&Vector2f::new(40., 40.)).expect("Cannot create a rectangle");
+        swatch.set_fill_color(color);
+        swatch.set_outline_color(&Color::black());
+        swatch.set_outline_thickness(2.);
+        swatch.set_position(&Vector2f::new(10. + (i % 2) as f32 * 60., 10. + (i / 2) as f32 * 60.));
+        swatches.push(swatch);
+    }
+
+    while windows.get(main_id).map_or(false, |window| window.is_open()) {
+        // Handle the events of the main window
+        {
+            let window = windows.get_mut(main_id).unwrap();
+            loop {
+                match window.poll_event() {
+                    event::Closed               => window.close(),
+                    event::KeyPressed{code, ..} => match code {
+                        Key::Escape => {window.close(); break},
+                        _           => {}
+                    },
+                    event::NoEvent              => break,
+                    _                           => {}
+                }
+            }
+        }
+
+        // Handle the events of the palette window: clicking a swatch
+        // changes the color of the brush
+        {
+            let window = windows.get_mut(palette_id).unwrap();
+            loop {
+                match window.poll_event() {
+                    event::MouseButtonPressed{button: MouseButton::MouseLeft, x, y} => {
+                        for (i, color) in colors.iter().enumerate() {
+                            let left = 10 + (i as i32 % 2) * 60;
+                            let top = 10 + (i as i32 / 2) * 60;
+                            if x >= left && x < left + 40 && y >= top && y < top + 40 {
+                                brush.set_color(color);
+                                preview.set_color(color);
+                            }
+                        }
+                    },
+                    event::NoEvent => break,
+                    _              => {}
+                }
+            }
+        }
+
+        // Draw each window after activating it
+        match windows.activate(main_id) {
+            Some(window) => {
+                window.clear(&Color::new_RGB(50, 50, 50));
+                window.draw(&brush);
+            },
+            None => {}
+        }
+        match windows.activate(palette_id) {
+            Some(window) => {
+                window.clear(&Color::new_RGB(200, 200, 200));
+                for swatch in swatches.iter() {
+                    window.draw(swatch);
+                }
+                window.draw(&preview);
+            },
+            None => {}
+        }
+        windows.display_all();
+    }
+    windows.close_all();
+}
@@ -34,6 +34,8 @@ pub mod listener {
         pub fn sfListener_getPosition() -> vector3::Vector3f;
         pub fn sfListener_setDirection(orientation: vector3::Vector3f) -> ();
         pub fn sfListener_getDirection() -> vector3::Vector3f;
+        pub fn sfListener_setUpVector(upVector: vector3::Vector3f) -> ();
+        pub fn sfListener_getUpVector() -> vector3::Vector3f;
     }
 }
 
@@ -43,6 +45,7 @@ pub mod music {
     use system::vector3::Vector3f;
 
     use ffi::system::time::sfTime;
+    use ffi::system::input_stream::sfInputStream;
     use ffi::audio::sound_status::sfSoundStatus;
     use ffi::sfml_types::SfBool;
 
@@ -52,14 +55,23 @@ pub mod music {
         this1: *mut c_void
     }
 
+    #[repr(C)]
+    #[derive(Copy)]
+    pub struct sfTimeSpan {
+        pub offset: sfTime,
+        pub length: sfTime
+    }
+
     extern "C" {
         pub fn sfMusic_createFromFile(filename: *const c_char) -> *mut sfMusic;
         pub fn sfMusic_createFromMemory(data: *const c_uchar, sizeInBytes: size_t) -> *mut sfMusic;
-        // sfMusic*mut  sfMusic_createFromStream(sfInputStream*mut  stream);
+        pub fn sfMusic_createFromStream(stream: *mut sfInputStream) -> *mut sfMusic;
         pub fn sfMusic_destroy(music: *mut sfMusic) -> ();
         pub fn sfMusic_setLoop(music: *mut sfMusic, lloop: SfBool) -> ();
         pub fn sfMusic_getLoop(music: *mut sfMusic) -> SfBool;
         pub fn sfMusic_getDuration(music: *mut sfMusic) -> sfTime;
+        pub fn sfMusic_getLoopPoints(music: *mut sfMusic) -> sfTimeSpan;
+        pub fn sfMusic_setLoopPoints(music: *mut sfMusic, timePoints: sfTimeSpan) -> ();
         pub fn sfMusic_play(music: *mut sfMusic) -> ();
         pub fn sfMusic_pause(music: *mut sfMusic) -> ();
         pub fn sfMusic_stop(music: *mut sfMusic) -> ();
@@ -133,6 +145,7 @@ pub mod sound_buffer {
     use libc::{size_t, c_void, c_uint, c_char};
 
     use ffi::system::time::sfTime;
+    use ffi::system::input_stream::sfInputStream;
     use ffi::sfml_types::SfBool;
 
     #[repr(C)]
@@ -142,10 +155,12 @@ pub mod sound_buffer {
 
     extern "C" {
         pub fn sfSoundBuffer_createFromFile(filename: *const c_char) -> *mut sfSoundBuffer;
+        pub fn sfSoundBuffer_createFromStream(stream: *mut sfInputStream) -> *mut sfSoundBuffer;
+        pub fn sfSoundBuffer_createFromSamples(samples: *const i16, sampleCount: u64, channelCount: c_uint, sampleRate: c_uint) -> *mut sfSoundBuffer;
         pub fn sfSoundBuffer_copy(soundBuffer: *mut sfSoundBuffer) -> *mut sfSoundBuffer;
         pub fn sfSoundBuffer_destroy(soundBuffer: *mut sfSoundBuffer) -> ();
         pub fn sfSoundBuffer_saveToFile(soundBuffer: *mut sfSoundBuffer, filename: *const c_char) -> SfBool;
-       // fn sfSoundBuffer_getSamples(soundBuffer: *mut sfSoundBuffer) -> *mut i16;
+        pub fn sfSoundBuffer_getSamples(soundBuffer: *mut sfSoundBuffer) -> *const i16;
         pub fn sfSoundBuffer_getSampleCount(soundBuffer: *mut sfSoundBuffer) -> size_t;
         pub fn sfSoundBuffer_getChannelCount(soundBuffer: *mut sfSoundBuffer) -> c_uint;
         pub fn sfSoundBuffer_getDuration(soundBuffer: *mut sfSoundBuffer) -> sfTime;
@@ -154,7 +169,7 @@ pub mod sound_buffer {
 }
 
 pub mod sound_buffer_recorder {
-    use libc::{c_uint, c_void};
+    use libc::{c_uint, c_void, c_char};
 
     use ffi::audio::sound_buffer::sfSoundBuffer;
     use ffi::sfml_types::SfBool;
@@ -171,7 +186,92 @@ pub mod sound_buffer_recorder {
         pub fn sfSoundBufferRecorder_stop(soundBufferRecorder: *mut sfSoundBufferRecorder) -> ();
         pub fn sfSoundBufferRecorder_getSampleRate(soundBufferRecorder: *mut sfSoundBufferRecorder) -> c_uint;
         pub fn sfSoundBufferRecorder_getBuffer(soundBufferRecorder: *mut sfSoundBufferRecorder) -> *mut sfSoundBuffer;
+        pub fn sfSoundBufferRecorder_setDevice(soundBufferRecorder: *mut sfSoundBufferRecorder, name: *const c_char) -> SfBool;
+        pub fn sfSoundBufferRecorder_getDevice(soundBufferRecorder: *mut sfSoundBufferRecorder) -> *const c_char;
+        pub fn sfSoundRecorder_isAvailable() -> SfBool;
+    }
+}
+
+pub mod sound_stream {
+    use libc::{c_void, c_uint, c_float};
+
+    use system::vector3::Vector3f;
+
+    use ffi::system::time::sfTime;
+    use ffi::audio::sound_status::sfSoundStatus;
+    use ffi::sfml_types::SfBool;
+
+    #[repr(C)]
+    pub struct sfSoundStream {
+        this: *mut c_void
+    }
+
+    #[repr(C)]
+    pub struct sfSoundStreamChunk {
+        pub samples: *mut i16,
+        pub sampleCount: c_uint
+    }
+
+    pub type sfSoundStreamGetDataCallback = extern "C" fn(chunk: *mut sfSoundStreamChunk, userData: *mut c_void) -> SfBool;
+    pub type sfSoundStreamSeekCallback = extern "C" fn(timeOffset: sfTime, userData: *mut c_void);
+
+    extern "C" {
+        pub fn sfSoundStream_create(onGetData: sfSoundStreamGetDataCallback, onSeek: sfSoundStreamSeekCallback, channelCount: c_uint, sampleRate: c_uint, userData: *mut c_void) -> *mut sfSoundStream;
+        pub fn sfSoundStream_destroy(soundStream: *mut sfSoundStream) -> ();
+        pub fn sfSoundStream_play(soundStream: *mut sfSoundStream) -> ();
+        pub fn sfSoundStream_pause(soundStream: *mut sfSoundStream) -> ();
+        pub fn sfSoundStream_stop(soundStream: *mut sfSoundStream) -> ();
+        pub fn sfSoundStream_getStatus(soundStream: *mut sfSoundStream) -> sfSoundStatus;
+        pub fn sfSoundStream_getChannelCount(soundStream: *mut sfSoundStream) -> c_uint;
+        pub fn sfSoundStream_getSampleRate(soundStream: *mut sfSoundStream) -> c_uint;
+        pub fn sfSoundStream_setPitch(soundStream: *mut sfSoundStream, pitch: c_float) -> ();
+        pub fn sfSoundStream_setVolume(soundStream: *mut sfSoundStream, volume: c_float) -> ();
+        pub fn sfSoundStream_setPosition(soundStream: *mut sfSoundStream, position: Vector3f) -> ();
+        pub fn sfSoundStream_setRelativeToListener(soundStream: *mut sfSoundStream, relative: SfBool) -> ();
+        pub fn sfSoundStream_setMinDistance(soundStream: *mut sfSoundStream, distance: c_float) -> ();
+        pub fn sfSoundStream_setAttenuation(soundStream: *mut sfSoundStream, attenuation: c_float) -> ();
+        pub fn sfSoundStream_setPlayingOffset(soundStream: *mut sfSoundStream, timeOffset: sfTime) -> ();
+        pub fn sfSoundStream_setLoop(soundStream: *mut sfSoundStream, lloop: SfBool) -> ();
+        pub fn sfSoundStream_getPitch(soundStream: *mut sfSoundStream) -> c_float;
+        pub fn sfSoundStream_getVolume(soundStream: *mut sfSoundStream) -> c_float;
+        pub fn sfSoundStream_getPosition(soundStream: *mut sfSoundStream) -> Vector3f;
+        pub fn sfSoundStream_isRelativeToListener(soundStream: *mut sfSoundStream) -> SfBool;
+        pub fn sfSoundStream_getMinDistance(soundStream: *mut sfSoundStream) -> c_float;
+        pub fn sfSoundStream_getAttenuation(soundStream: *mut sfSoundStream) -> c_float;
+        pub fn sfSoundStream_getLoop(soundStream: *mut sfSoundStream) -> SfBool;
+        pub fn sfSoundStream_getPlayingOffset(soundStream: *mut sfSoundStream) -> sfTime;
+    }
+}
+
+pub mod sound_recorder {
+    use libc::{c_void, c_uint, c_char, size_t};
+
+    use ffi::system::time::sfTime;
+    use ffi::sfml_types::SfBool;
+
+    #[repr(C)]
+    pub struct sfSoundRecorder {
+        this: *mut c_void
+    }
+
+    pub type sfSoundRecorderStartCallback = extern "C" fn(userData: *mut c_void) -> SfBool;
+    pub type sfSoundRecorderProcessCallback = extern "C" fn(samples: *const i16, sampleCount: size_t, userData: *mut c_void) -> SfBool;
+    pub type sfSoundRecorderStopCallback = extern "C" fn(userData: *mut c_void);
+
+    extern "C" {
+        pub fn sfSoundRecorder_create(onStart: sfSoundRecorderStartCallback, onProcess: sfSoundRecorderProcessCallback, onStop: sfSoundRecorderStopCallback, userData: *mut c_void) -> *mut sfSoundRecorder;
+        pub fn sfSoundRecorder_destroy(soundRecorder: *mut sfSoundRecorder) -> ();
+        pub fn sfSoundRecorder_start(soundRecorder: *mut sfSoundRecorder, sampleRate: c_uint) -> SfBool;
+        pub fn sfSoundRecorder_stop(soundRecorder: *mut sfSoundRecorder) -> ();
+        pub fn sfSoundRecorder_getSampleRate(soundRecorder: *mut sfSoundRecorder) -> c_uint;
         pub fn sfSoundRecorder_isAvailable() -> SfBool;
+        pub fn sfSoundRecorder_getAvailableDevices(count: *mut size_t) -> *const *const c_char;
+        pub fn sfSoundRecorder_getDefaultDevice() -> *const c_char;
+        pub fn sfSoundRecorder_setDevice(soundRecorder: *mut sfSoundRecorder, name: *const c_char) -> SfBool;
+        pub fn sfSoundRecorder_getDevice(soundRecorder: *mut sfSoundRecorder) -> *const c_char;
+        pub fn sfSoundRecorder_setChannelCount(soundRecorder: *mut sfSoundRecorder, channelCount: c_uint) -> ();
+        pub fn sfSoundRecorder_getChannelCount(soundRecorder: *mut sfSoundRecorder) -> c_uint;
+        pub fn sfSoundRecorder_setProcessingInterval(soundRecorder: *mut sfSoundRecorder, interval: sfTime) -> ();
     }
 }
 
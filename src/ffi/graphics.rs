@@ -25,7 +25,7 @@
 #![allow(non_snake_case)]
 
 pub mod render_window {
-    use libc::{c_uint, c_float, c_char};
+    use libc::{c_uint, c_float, c_char, size_t};
 
     use system::vector2::{Vector2f, Vector2i, Vector2u};
     use window::ContextSettings;
@@ -42,7 +42,7 @@ pub mod render_window {
     use ffi::graphics::image::sfImage;
     use ffi::graphics::shape::sfShape;
     use ffi::graphics::vertex_array::sfVertexArray;
-    use ffi::sfml_types::SfBool;
+    use ffi::sfml_types::{SfBool, sfWindowHandle};
 
     #[repr(C)]
     pub struct sfRenderWindow;
@@ -50,7 +50,7 @@ pub mod render_window {
     extern "C" {
         pub fn sfRenderWindow_create(mode: sfVideoMode, title: *const c_char, style: c_uint, settings: *const ContextSettings) -> *mut sfRenderWindow;
         pub fn sfRenderWindow_createUnicode(mode: sfVideoMode, title: *const u32, style: c_uint, settings: *const ContextSettings) -> *mut sfRenderWindow;
-        //fn sfRenderWindow_createFromHandle(handle: sfWindowHandle, settings: *mut sfContextSettings) -> *mut sfRenderWindow;
+        pub fn sfRenderWindow_createFromHandle(handle: sfWindowHandle, settings: *const ContextSettings) -> *mut sfRenderWindow;
         pub fn sfRenderWindow_destroy(renderWindow: *mut sfRenderWindow) -> ();
         pub fn sfRenderWindow_close(renderWindow: *mut sfRenderWindow) -> ();
         pub fn sfRenderWindow_isOpen(renderWindow: *mut sfRenderWindow) -> SfBool;
@@ -66,13 +66,17 @@ pub mod render_window {
         pub fn sfRenderWindow_setIcon(renderWindow: *mut sfRenderWindow, width: c_uint, height: c_uint, pixels: *const u8) -> ();
         pub fn sfRenderWindow_setVisible(renderWindow: *mut sfRenderWindow, visible: SfBool) -> ();
         pub fn sfRenderWindow_setMouseCursorVisible(renderWindow: *mut sfRenderWindow, show: SfBool) -> ();
+        pub fn sfRenderWindow_setMouseCursorGrabbed(renderWindow: *mut sfRenderWindow, grabbed: SfBool) -> ();
+        pub fn sfRenderWindow_requestFocus(renderWindow: *mut sfRenderWindow) -> ();
+        pub fn sfRenderWindow_hasFocus(renderWindow: *mut sfRenderWindow) -> SfBool;
+        pub fn sfRenderWindow_setMouseCursor(renderWindow: *mut sfRenderWindow, cursor: *const ::ffi::window::cursor::sfCursor) -> ();
         pub fn sfRenderWindow_setVerticalSyncEnabled(renderWindow: *mut sfRenderWindow, enabled: SfBool) -> ();
         pub fn sfRenderWindow_setKeyRepeatEnabled(renderWindow: *mut sfRenderWindow, enabled: SfBool) -> ();
         pub fn sfRenderWindow_setActive(renderWindow: *mut sfRenderWindow, active: SfBool) -> SfBool;
         pub fn sfRenderWindow_display(renderWindow: *mut sfRenderWindow) -> ();
         pub fn sfRenderWindow_setFramerateLimit(renderWindow: *mut sfRenderWindow, limit: c_uint) -> ();
         pub fn sfRenderWindow_setJoystickThreshold(renderWindow: *mut sfRenderWindow, treshold: c_float) -> ();
-        // fn sfRenderWindow_getSystemHandle(renderWindow: *mut sfRenderWindow) -> sfWindowHandle;
+        pub fn sfRenderWindow_getSystemHandle(renderWindow: *mut sfRenderWindow) -> sfWindowHandle;
         pub fn sfRenderWindow_clear(renderWindow: *mut sfRenderWindow, color: Color) -> ();
         pub fn sfRenderWindow_setView(renderWindow: *mut sfRenderWindow, view: *mut sfView) -> ();
         pub fn sfRenderWindow_getView(renderWindow: *mut sfRenderWindow) -> *mut sfView;
@@ -87,19 +91,20 @@ pub mod render_window {
         pub fn sfRenderWindow_drawConvexShape(renderWindow: *mut sfRenderWindow, object: *mut sfConvexShape, states: *mut sfRenderStates) -> ();
         pub fn sfRenderWindow_drawRectangleShape(renderWindow: *mut sfRenderWindow, object: *mut sfRectangleShape, states: *mut sfRenderStates) -> ();
         pub fn sfRenderWindow_drawVertexArray(renderWindow: *mut sfRenderWindow, object: *mut sfVertexArray, states: *mut sfRenderStates) -> ();
-        pub fn sfRenderWindow_drawPrimitives(renderWindow: *mut sfRenderWindow, vertices: *const Vertex, vertexCount: c_uint, ttype: PrimitiveType, states: *mut sfRenderStates) -> ();
+        pub fn sfRenderWindow_drawPrimitives(renderWindow: *mut sfRenderWindow, vertices: *const Vertex, vertexCount: size_t, ttype: PrimitiveType, states: *mut sfRenderStates) -> ();
         pub fn sfRenderWindow_pushGLStates(renderWindow: *mut sfRenderWindow) -> ();
         pub fn sfRenderWindow_popGLStates(renderWindow: *mut sfRenderWindow) -> ();
         pub fn sfRenderWindow_resetGLStates(renderWindow: *mut sfRenderWindow) -> ();
         pub fn sfRenderWindow_capture(renderWindow: *mut sfRenderWindow) -> *mut sfImage;
         pub fn sfMouse_getPositionRenderWindow(relativeTo: *mut sfRenderWindow) -> Vector2i;
         pub fn sfMouse_setPositionRenderWindow(position: Vector2i, relativeTo: *mut sfRenderWindow) -> ();
+        pub fn sfTouch_getPositionRenderWindow(finger: c_uint, relativeTo: *mut sfRenderWindow) -> Vector2i;
     }
 }
 
 pub mod circle_shape {
 
-    use libc::{c_void, c_float, c_uint};
+    use libc::{c_void, c_float, size_t};
 
     use system::vector2::Vector2f;
     use graphics::{Color, Transform, IntRect, FloatRect};
@@ -142,11 +147,11 @@ pub mod circle_shape {
         pub fn sfCircleShape_getFillColor(shape: *mut sfCircleShape) -> Color;
         pub fn sfCircleShape_getOutlineColor(shape: *mut sfCircleShape) -> Color;
         pub fn sfCircleShape_getOutlineThickness(shape: *mut sfCircleShape) -> c_float;
-        pub fn sfCircleShape_getPointCount(shape: *mut sfCircleShape) -> c_uint;
-        pub fn sfCircleShape_getPoint(shape: *mut sfCircleShape, index: c_uint) -> ();
+        pub fn sfCircleShape_getPointCount(shape: *mut sfCircleShape) -> size_t;
+        pub fn sfCircleShape_getPoint(shape: *mut sfCircleShape, index: size_t) -> Vector2f;
         pub fn sfCircleShape_setRadius(shape: *mut sfCircleShape, radius: c_float) -> ();
         pub fn sfCircleShape_getRadius(shape: *mut sfCircleShape) -> c_float;
-        pub fn sfCircleShape_setPointCount(shape: *mut sfCircleShape, count: c_uint) -> ();
+        pub fn sfCircleShape_setPointCount(shape: *mut sfCircleShape, count: size_t) -> ();
         pub fn sfCircleShape_getLocalBounds(shape: *mut sfCircleShape) -> FloatRect;
         pub fn sfCircleShape_getGlobalBounds(shape: *mut sfCircleShape) -> FloatRect;
     }
@@ -166,7 +171,7 @@ pub mod color {
 
 pub mod convex_shape {
 
-    use libc::{c_uint, c_void, c_float};
+    use libc::{c_void, c_float, size_t};
 
     use system::vector2::Vector2f;
     use graphics::{Color, Transform, FloatRect, IntRect};
@@ -209,21 +214,22 @@ pub mod convex_shape {
         pub fn sfConvexShape_getFillColor(shape: *mut sfConvexShape) -> Color;
         pub fn sfConvexShape_getOutlineColor(shape: *mut sfConvexShape) -> Color;
         pub fn sfConvexShape_getOutlineThickness(shape: *mut sfConvexShape) -> c_float;
-        pub fn sfConvexShape_getPointCount(shape: *mut sfConvexShape) -> c_uint;
-        pub fn sfConvexShape_getPoint(shape: *mut sfConvexShape, index: c_uint) -> Vector2f;
-        pub fn sfConvexShape_setPointCount(shape: *mut sfConvexShape, count: c_uint) -> ();
-        pub fn sfConvexShape_setPoint(shape: *mut sfConvexShape, index: c_uint, point: Vector2f) -> ();
+        pub fn sfConvexShape_getPointCount(shape: *mut sfConvexShape) -> size_t;
+        pub fn sfConvexShape_getPoint(shape: *mut sfConvexShape, index: size_t) -> Vector2f;
+        pub fn sfConvexShape_setPointCount(shape: *mut sfConvexShape, count: size_t) -> ();
+        pub fn sfConvexShape_setPoint(shape: *mut sfConvexShape, index: size_t, point: Vector2f) -> ();
         pub fn sfConvexShape_getLocalBounds(shape: *mut sfConvexShape) -> FloatRect;
         pub fn sfConvexShape_getGlobalBounds(shape: *mut sfConvexShape) -> FloatRect;
     }
 }
 
 pub mod font {
-    use libc::{c_void, c_uint, c_int, c_char, c_uchar, size_t};
+    use libc::{c_void, c_uint, c_float, c_char, c_uchar, size_t};
 
     use graphics::Glyph;
 
     use ffi::graphics::texture::sfTexture;
+    use ffi::system::input_stream::sfInputStream;
     use ffi::sfml_types::SfBool;
 
     #[repr(C)]
@@ -235,11 +241,14 @@ pub mod font {
         pub fn sfFont_createFromFile(filename: *const c_char) -> *mut sfFont;
         pub fn sfFont_copy(font: *mut sfFont) -> *mut sfFont;
         pub fn sfFont_createFromMemory(data: *const c_uchar, sizeInBytes: size_t) -> *mut sfFont;
-        // fn sfFont_createFromStream(stream: *mut sfInputStream) -> *mut sfFont;
+        pub fn sfFont_createFromStream(stream: *mut sfInputStream) -> *mut sfFont;
         pub fn sfFont_destroy(font: *mut sfFont) -> ();
-        pub fn sfFont_getGlyph(font: *mut sfFont, codepoint: u32, characterSize: c_uint, bold :SfBool) -> Glyph;
-        pub fn sfFont_getKerning(font: *mut sfFont, first: u32, second: u32, characterSize: c_uint) -> c_int;
-        pub fn sfFont_getLineSpacing(font: *mut sfFont, characterSize: c_uint) -> c_int;
+        pub fn sfFont_getGlyph(font: *mut sfFont, codepoint: u32, characterSize: c_uint, bold :SfBool, outlineThickness: c_float) -> Glyph;
+        pub fn sfFont_hasGlyph(font: *mut sfFont, codepoint: u32) -> SfBool;
+        pub fn sfFont_getKerning(font: *mut sfFont, first: u32, second: u32, characterSize: c_uint) -> c_float;
+        pub fn sfFont_getLineSpacing(font: *mut sfFont, characterSize: c_uint) -> c_float;
+        pub fn sfFont_getUnderlinePosition(font: *mut sfFont, characterSize: c_uint) -> c_float;
+        pub fn sfFont_getUnderlineThickness(font: *mut sfFont, characterSize: c_uint) -> c_float;
         pub fn sfFont_getTexture(font: *mut sfFont, characterSize: c_uint) -> *mut sfTexture;
     }
 }
@@ -250,6 +259,7 @@ pub mod image {
     use graphics::{Color, IntRect};
     use system::vector2::Vector2u;
 
+    use ffi::system::input_stream::sfInputStream;
     use ffi::sfml_types::SfBool;
 
     #[repr(C)]
@@ -263,7 +273,7 @@ pub mod image {
         pub fn sfImage_createFromPixels(width: c_uint, height: c_uint, pixels: *const u8) -> *mut sfImage;
         pub fn sfImage_createFromFile(filename: *const c_char) -> *mut sfImage;
         pub fn sfImage_createFromMemory(data: *const c_uchar, size: size_t) -> *mut sfImage;
-        //fn sfImage_createFromStream(stream: *mut sfInputStream) -> *mut sfImage;
+        pub fn sfImage_createFromStream(stream: *mut sfInputStream) -> *mut sfImage;
         pub fn sfImage_copy(image: *mut sfImage) -> *mut sfImage;
         pub fn sfImage_destroy(image: *mut sfImage) -> ();
         pub fn sfImage_saveToFile(image: *mut sfImage, filename: *const c_char) -> SfBool;
@@ -293,7 +303,7 @@ pub mod rect {
 }
 
 pub mod rectangle_shape {
-    use libc::{c_void, c_float, c_uint};
+    use libc::{c_void, c_float, size_t};
 
     use system::vector2::Vector2f;
     use graphics::{Color, Transform, FloatRect, IntRect};
@@ -336,8 +346,8 @@ pub mod rectangle_shape {
         pub fn sfRectangleShape_getFillColor(shape: *mut sfRectangleShape) -> Color;
         pub fn sfRectangleShape_getOutlineColor(shape: *mut sfRectangleShape) -> Color;
         pub fn sfRectangleShape_getOutlineThickness(shape: *mut sfRectangleShape) -> c_float;
-        pub fn sfRectangleShape_getPointCount(shape: *mut sfRectangleShape) -> c_uint;
-        pub fn sfRectangleShape_getPoint(shape: *mut sfRectangleShape, index: c_uint) -> Vector2f;
+        pub fn sfRectangleShape_getPointCount(shape: *mut sfRectangleShape) -> size_t;
+        pub fn sfRectangleShape_getPoint(shape: *mut sfRectangleShape, index: size_t) -> Vector2f;
         pub fn sfRectangleShape_setSize(shape: *mut sfRectangleShape, size: Vector2f) -> ();
         pub fn sfRectangleShape_getSize(shape: *mut sfRectangleShape) -> Vector2f;
         pub fn sfRectangleShape_getLocalBounds(shape: *mut sfRectangleShape) -> FloatRect;
@@ -346,14 +356,43 @@ pub mod rectangle_shape {
 }
 
 pub mod render_states {
+    use libc::c_int;
+
     use graphics::Transform;
 
     use ffi::graphics::shader::sfShader;
     use ffi::graphics::texture::sfTexture;
 
+    pub type sfBlendFactor = c_int;
+    pub const SFBLENDFACTORZERO:             sfBlendFactor = 0;
+    pub const SFBLENDFACTORONE:              sfBlendFactor = 1;
+    pub const SFBLENDFACTORSRCCOLOR:         sfBlendFactor = 2;
+    pub const SFBLENDFACTORONEMINUSSRCCOLOR: sfBlendFactor = 3;
+    pub const SFBLENDFACTORDSTCOLOR:         sfBlendFactor = 4;
+    pub const SFBLENDFACTORONEMINUSDSTCOLOR: sfBlendFactor = 5;
+    pub const SFBLENDFACTORSRCALPHA:         sfBlendFactor = 6;
+    pub const SFBLENDFACTORONEMINUSSRCALPHA: sfBlendFactor = 7;
+    pub const SFBLENDFACTORDSTALPHA:         sfBlendFactor = 8;
+    pub const SFBLENDFACTORONEMINUSDSTALPHA: sfBlendFactor = 9;
+
+    pub type sfBlendEquation = c_int;
+    pub const SFBLENDEQUATIONADD:             sfBlendEquation = 0;
+    pub const SFBLENDEQUATIONSUBTRACT:        sfBlendEquation = 1;
+    pub const SFBLENDEQUATIONREVERSESUBTRACT: sfBlendEquation = 2;
+
+    #[repr(C)]
+    pub struct sfBlendMode {
+        pub colorSrcFactor: sfBlendFactor,
+        pub colorDstFactor: sfBlendFactor,
+        pub colorEquation: sfBlendEquation,
+        pub alphaSrcFactor: sfBlendFactor,
+        pub alphaDstFactor: sfBlendFactor,
+        pub alphaEquation: sfBlendEquation
+    }
+
     #[repr(C)]
     pub struct sfRenderStates {
-        pub blendMode: i32,
+        pub blendMode: sfBlendMode,
         pub transform: Transform,
         pub texture: *mut sfTexture,
         pub shader: *mut sfShader
@@ -362,7 +401,7 @@ pub mod render_states {
 
 pub mod shader {
 
-    use libc::{c_void, c_float, c_char};
+    use libc::{c_void, c_float, c_char, c_uint};
 
     use graphics::{Transform, Color};
     use system::vector2::Vector2f;
@@ -377,9 +416,9 @@ pub mod shader {
     }
 
     extern "C" {
-        pub fn sfShader_createFromFile(vertexShaderFilename: *const c_char, fragmentShaderFilename: *const c_char) -> *mut sfShader;
-        pub fn sfShader_createFromMemory(vertexShader: *const c_char, fragmentShader: *const c_char) -> *mut sfShader;
-        //fn sfShader_createFromStream(vertexShaderStream: *mut sfInputStream, fragmentShaderStream: *mut sfInputStream) -> *mut sfShader;
+        pub fn sfShader_createFromFile(vertexShaderFilename: *const c_char, geometryShaderFilename: *const c_char, fragmentShaderFilename: *const c_char) -> *mut sfShader;
+        pub fn sfShader_createFromMemory(vertexShader: *const c_char, geometryShader: *const c_char, fragmentShader: *const c_char) -> *mut sfShader;
+        //fn sfShader_createFromStream(vertexShaderStream: *mut sfInputStream, geometryShaderStream: *mut sfInputStream, fragmentShaderStream: *mut sfInputStream) -> *mut sfShader;
         pub fn sfShader_destroy(shader: *mut sfShader)-> ();
         pub fn sfShader_setFloatParameter(shader: *mut sfShader, name: *const c_char, x: c_float) -> ();
         pub fn sfShader_setFloat2Parameter(shader: *mut sfShader, name: *const c_char, x: c_float, y: c_float) -> ();
@@ -392,13 +431,14 @@ pub mod shader {
         pub fn sfShader_setTextureParameter(shader: *mut sfShader, name: *const c_char, texture: *mut sfTexture) -> ();
         pub fn sfShader_setCurrentTextureParameter(shader: *mut sfShader, name: *const c_char) -> ();
         pub fn sfShader_bind(shader: *mut sfShader) -> ();
+        pub fn sfShader_getNativeHandle(shader: *const sfShader) -> c_uint;
         pub fn sfShader_isAvailable() -> SfBool;
    }
 }
 
 #[doc(hidden)]
 pub mod render_texture {
-    use libc::{c_void, c_uint};
+    use libc::{c_void, c_uint, size_t};
 
     use system::vector2::{Vector2f, Vector2i, Vector2u};
     use graphics::{Color, IntRect, Vertex, PrimitiveType};
@@ -443,19 +483,23 @@ pub mod render_texture {
         pub fn sfRenderTexture_drawConvexShape(renderTexture: *mut sfRenderTexture, object: *mut sfConvexShape, states: *mut sfRenderStates) -> ();
         pub fn sfRenderTexture_drawRectangleShape(renderTexture: *mut sfRenderTexture, object: *mut sfRectangleShape, states: *mut sfRenderStates) -> ();
         pub fn sfRenderTexture_drawVertexArray(renderTexture: *mut sfRenderTexture, object: *mut sfVertexArray, states: *mut sfRenderStates) -> ();
-        pub fn sfRenderTexture_drawPrimitives(renderTexture: *mut sfRenderTexture, vertices: *const Vertex, vertexCount: c_uint, ttype: PrimitiveType, states: *mut sfRenderStates) -> ();
+        pub fn sfRenderTexture_drawPrimitives(renderTexture: *mut sfRenderTexture, vertices: *const Vertex, vertexCount: size_t, ttype: PrimitiveType, states: *mut sfRenderStates) -> ();
         pub fn sfRenderTexture_pushGLStates(renderTexture: *mut sfRenderTexture) -> ();
         pub fn sfRenderTexture_popGLStates(renderTexture: *mut sfRenderTexture) -> ();
         pub fn sfRenderTexture_resetGLStates(renderTexture: *mut sfRenderTexture) -> ();
         pub fn sfRenderTexture_getTexture(renderTexture: *mut sfRenderTexture) -> *mut sfTexture;
         pub fn sfRenderTexture_setSmooth(renderTexture: *mut sfRenderTexture, smooth: SfBool) -> ();
         pub fn sfRenderTexture_isSmooth(renderTexture: *mut sfRenderTexture) -> SfBool;
+        pub fn sfRenderTexture_setRepeated(renderTexture: *mut sfRenderTexture, repeated: SfBool) -> ();
+        pub fn sfRenderTexture_isRepeated(renderTexture: *mut sfRenderTexture) -> SfBool;
+        pub fn sfRenderTexture_isSrgb(renderTexture: *mut sfRenderTexture) -> SfBool;
+        pub fn sfRenderTexture_generateMipmap(renderTexture: *mut sfRenderTexture) -> SfBool;
     }
 }
 
 pub mod shape {
 
-    use libc::{c_void, c_float, c_uint};
+    use libc::{c_void, c_float, size_t};
 
     use graphics::{Color, Transform, IntRect, FloatRect};
     use system::vector2::Vector2f;
@@ -472,7 +516,7 @@ pub mod shape {
     }
 
     extern "C" {
-        pub fn sfShape_create(getPointCount: extern "C" fn(*mut c_void) -> u32, getPoint: extern "C" fn(u32, *mut c_void) -> Vector2f, userData: *mut c_void) -> *mut sfShape;
+        pub fn sfShape_create(getPointCount: extern "C" fn(*mut c_void) -> size_t, getPoint: extern "C" fn(size_t, *mut c_void) -> Vector2f, userData: *mut c_void) -> *mut sfShape;
         pub fn sfShape_destroy(shape: *mut sfShape) -> ();
         pub fn sfShape_setPosition(shape: *mut sfShape, position: Vector2f) -> ();
         pub fn sfShape_setRotation(shape: *mut sfShape, angle: c_float) -> ();
@@ -497,8 +541,8 @@ pub mod shape {
         pub fn sfShape_getFillColor(shape: *mut sfShape) -> Color;
         pub fn sfShape_getOutlineColor(shape: *mut sfShape) -> Color;
         pub fn sfShape_getOutlineThickness(shape: *mut sfShape) -> c_float;
-        pub fn sfShape_getPointCount(shape: *mut sfShape) -> c_uint;
-        pub fn sfShape_getPoint(shape: *mut sfShape, index: c_uint) -> Vector2f;
+        pub fn sfShape_getPointCount(shape: *mut sfShape) -> size_t;
+        pub fn sfShape_getPoint(shape: *mut sfShape, index: size_t) -> Vector2f;
         pub fn sfShape_getLocalBounds(shape: *mut sfShape) -> FloatRect;
         pub fn sfShape_getGlobalBounds(shape: *mut sfShape) -> FloatRect;
         pub fn sfShape_update(shape: *mut sfShape) -> ();
@@ -596,6 +640,14 @@ pub mod text {
         pub fn sfText_getCharacterSize(text: *mut sfText) -> c_uint;
         pub fn sfText_getStyle(text: *mut sfText) -> u32;
         pub fn sfText_getColor(text: *mut sfText) -> Color;
+        pub fn sfText_setOutlineColor(text: *mut sfText, color: Color) -> ();
+        pub fn sfText_setOutlineThickness(text: *mut sfText, thickness: c_float) -> ();
+        pub fn sfText_getOutlineColor(text: *mut sfText) -> Color;
+        pub fn sfText_getOutlineThickness(text: *mut sfText) -> c_float;
+        pub fn sfText_setLetterSpacing(text: *mut sfText, spacingFactor: c_float) -> ();
+        pub fn sfText_setLineSpacing(text: *mut sfText, spacingFactor: c_float) -> ();
+        pub fn sfText_getLetterSpacing(text: *mut sfText) -> c_float;
+        pub fn sfText_getLineSpacing(text: *mut sfText) -> c_float;
         pub fn sfText_findCharacterPos(text: *mut sfText, index: size_t) -> Vector2f;
         pub fn sfText_getLocalBounds(text: *mut sfText) -> FloatRect;
         pub fn sfText_getGlobalBounds(text: *mut sfText) -> FloatRect;
@@ -612,6 +664,7 @@ pub mod texture {
     use ffi::graphics::render_window::sfRenderWindow;
     use ffi::graphics::image::sfImage;
     use ffi::window::window::sfWindow;
+    use ffi::system::input_stream::sfInputStream;
     use ffi::sfml_types::SfBool;
 
     #[repr(C)]
@@ -623,13 +676,15 @@ pub mod texture {
         pub fn sfTexture_create(width: c_uint, height: c_uint) -> *mut sfTexture;
         pub fn sfTexture_createFromFile(filename: *mut c_char, area: *const IntRect) -> *mut sfTexture;
         pub fn sfTexture_createFromMemory(data: *const c_uchar, sizeInBytes: size_t , area: *const IntRect) -> *mut sfTexture;
-        //fn sfTexture_createFromStream(strea;: *mut sfInputStream, area: *mut sfIntRect) -> *mut sfTexture;
+        pub fn sfTexture_createFromStream(stream: *mut sfInputStream, area: *const IntRect) -> *mut sfTexture;
         pub fn sfTexture_createFromImage(image :*mut sfImage, area: *const IntRect) -> *mut sfTexture;
         pub fn sfTexture_copy(texture: *mut sfTexture) -> *mut sfTexture;
         pub fn sfTexture_destroy(texture: *mut sfTexture) -> ();
+        pub fn sfTexture_swap(left: *mut sfTexture, right: *mut sfTexture) -> ();
         pub fn sfTexture_getSize(texture: *mut sfTexture) -> Vector2u;
         pub fn sfTexture_copyToImage(texture: *mut sfTexture) -> *mut sfImage;
         pub fn sfTexture_updateFromPixels(texture: *mut sfTexture, pixels: *const u8, width: c_uint, height: c_uint, x: c_uint, y: c_uint) -> ();
+        pub fn sfTexture_updateFromTexture(destination: *mut sfTexture, source: *mut sfTexture, x: c_uint, y: c_uint) -> ();
         pub fn sfTexture_updateFromImage(texture: *mut sfTexture, image: *mut sfImage, x: c_uint, y: c_uint) -> ();
         pub fn sfTexture_updateFromWindow(texture: *mut sfTexture, window: *mut sfWindow, x: c_uint, y: c_uint) -> ();
         pub fn sfTexture_updateFromRenderWindow(texture: *mut sfTexture, renderWindow: *mut sfRenderWindow, x: c_uint, y: c_uint) -> ();
@@ -637,7 +692,11 @@ pub mod texture {
         pub fn sfTexture_isSmooth(texture: *mut sfTexture) -> SfBool;
         pub fn sfTexture_setRepeated(texture: *mut sfTexture, repeated: SfBool);
         pub fn sfTexture_isRepeated(texture: *mut sfTexture) -> SfBool;
+        pub fn sfTexture_setSrgb(texture: *mut sfTexture, sRgb: SfBool) -> ();
+        pub fn sfTexture_isSrgb(texture: *mut sfTexture) -> SfBool;
+        pub fn sfTexture_generateMipmap(texture: *mut sfTexture) -> SfBool;
         pub fn sfTexture_bind(texture: *mut sfTexture) -> ();
+        pub fn sfTexture_getNativeHandle(texture: *const sfTexture) -> c_uint;
         pub fn sfTexture_getMaximumSize() -> c_uint;
     }
 }
@@ -651,11 +710,11 @@ pub mod transform {
 
     extern "C" {
         pub fn sfTransform_fromMatrix(a01: f32, a02: f32, a03: f32, b01: f32, b02: f32, b03: f32, c01: f32, c02: f32, c03: f32) -> Transform;
-        pub fn sfTransform_getMatrix(tranform: *mut Transform, matrix: *mut f32) -> ();
-        pub fn sfTransform_getInverse(transform: *mut Transform) -> Transform;
-        pub fn sfTransform_transformPoint(transform: *mut Transform, point: Vector2f) -> Vector2f;
-        pub fn sfTransform_transformRect(transform: *mut Transform, rectangle: FloatRect) -> FloatRect;
-        pub fn sfTransform_combine(transform: *mut Transform, other: *mut Transform) -> ();
+        pub fn sfTransform_getMatrix(tranform: *const Transform, matrix: *mut f32) -> ();
+        pub fn sfTransform_getInverse(transform: *const Transform) -> Transform;
+        pub fn sfTransform_transformPoint(transform: *const Transform, point: Vector2f) -> Vector2f;
+        pub fn sfTransform_transformRect(transform: *const Transform, rectangle: FloatRect) -> FloatRect;
+        pub fn sfTransform_combine(transform: *mut Transform, other: *const Transform) -> ();
         pub fn sfTransform_translate(transform: *mut Transform, x: c_float, y: c_float) -> ();
         pub fn sfTransform_rotate(transform: *mut Transform, angle: c_float) -> ();
         pub fn sfTransform_rotateWithCenter(transform: *mut Transform, angle: c_float, center_x: c_float, center_y: c_float) -> ();
@@ -700,7 +759,7 @@ pub mod transformable {
 
 pub mod vertex_array {
 
-    use libc::{c_uint, c_void};
+    use libc::{c_uint, c_void, size_t};
 
     use graphics::{FloatRect, Vertex};
 
@@ -722,10 +781,10 @@ pub mod vertex_array {
         pub fn sfVertexArray_create() -> *mut sfVertexArray;
         pub fn sfVertexArray_copy(vertexArray: *mut sfVertexArray) -> *mut sfVertexArray;
         pub fn sfVertexArray_destroy(vertexArray: *mut sfVertexArray) -> ();
-        pub fn sfVertexArray_getVertexCount(vertexArray: *mut sfVertexArray) -> c_uint;
-        pub fn sfVertexArray_getVertex(vertexArray: *mut sfVertexArray, index: c_uint) -> *mut Vertex;
+        pub fn sfVertexArray_getVertexCount(vertexArray: *mut sfVertexArray) -> size_t;
+        pub fn sfVertexArray_getVertex(vertexArray: *mut sfVertexArray, index: size_t) -> *mut Vertex;
         pub fn sfVertexArray_clear(vertexArray: *mut sfVertexArray) -> ();
-        pub fn sfVertexArray_resize(vertexArray: *mut sfVertexArray, vertexCount: c_uint) -> ();
+        pub fn sfVertexArray_resize(vertexArray: *mut sfVertexArray, vertexCount: size_t) -> ();
         pub fn sfVertexArray_append(vertexArray: *mut sfVertexArray, vertex: Vertex) -> ();
         pub fn sfVertexArray_setPrimitiveType(vertexArray: *mut sfVertexArray, stype: sfPrimitiveType) -> ();
         pub fn sfVertexArray_getPrimitiveType(vertexArray: *mut sfVertexArray) -> sfPrimitiveType;
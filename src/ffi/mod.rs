@@ -27,4 +27,5 @@ pub mod audio;
 pub mod window;
 pub mod network;
 pub mod system;
-pub mod sfml_types;
\ No newline at end of file
+pub mod sfml_types;
+pub mod native;
\ No newline at end of file
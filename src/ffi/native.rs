@@ -0,0 +1,324 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Platform specific calls on native window handles, for the features
+//! CSFML doesn't provide.
+
+#![allow(non_snake_case)]
+
+pub use self::platform::{set_opacity, set_always_on_top, get_scale_factor};
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+mod platform {
+    use libc::{c_void, c_int, c_long, c_ulong, c_char};
+    use std::ptr;
+    use std::num::Float;
+    use std::ffi::{CString, c_str_to_bytes};
+
+    use ffi::sfml_types::sfWindowHandle;
+
+    const XA_CARDINAL: c_ulong = 6;
+    const PROP_MODE_REPLACE: c_int = 0;
+    const CLIENT_MESSAGE: c_int = 33;
+    const SUBSTRUCTURE_NOTIFY_MASK: c_long = 1 << 19;
+    const SUBSTRUCTURE_REDIRECT_MASK: c_long = 1 << 20;
+
+    #[repr(C)]
+    struct XClientMessageEvent {
+        type_: c_int,
+        serial: c_ulong,
+        send_event: c_int,
+        display: *mut c_void,
+        window: c_ulong,
+        message_type: c_ulong,
+        format: c_int,
+        data: [c_long; 5],
+        // XSendEvent reads a whole XEvent
+        padding: [c_long; 24]
+    }
+
+    #[link(name = "X11")]
+    extern "C" {
+        fn XOpenDisplay(name: *const c_char) -> *mut c_void;
+        fn XCloseDisplay(display: *mut c_void) -> c_int;
+        fn XInternAtom(display: *mut c_void, name: *const c_char, only_if_exists: c_int) -> c_ulong;
+        fn XChangeProperty(display: *mut c_void, window: c_ulong, property: c_ulong, type_: c_ulong, format: c_int, mode: c_int, data: *const u8, count: c_int) -> c_int;
+        fn XDeleteProperty(display: *mut c_void, window: c_ulong, property: c_ulong) -> c_int;
+        fn XFlush(display: *mut c_void) -> c_int;
+        fn XDefaultRootWindow(display: *mut c_void) -> c_ulong;
+        fn XSendEvent(display: *mut c_void, window: c_ulong, propagate: c_int, mask: c_long, event: *mut XClientMessageEvent) -> c_int;
+        fn XGetDefault(display: *mut c_void, program: *const c_char, option: *const c_char) -> *const c_char;
+    }
+
+    unsafe fn atom(display: *mut c_void, name: &str) -> c_ulong {
+        XInternAtom(display, CString::from_slice(name.as_bytes()).as_ptr(), 0)
+    }
+
+    pub fn set_opacity(handle: sfWindowHandle, opacity: f32) -> bool {
+        unsafe {
+            let display = XOpenDisplay(ptr::null());
+            if display.is_null() {
+                return false;
+            }
+            let property = atom(display, "_NET_WM_WINDOW_OPACITY");
+            if opacity >= 1. {
+                XDeleteProperty(display, handle, property);
+            } else {
+                let value = (opacity.max(0.) as f64 * 0xFFFFFFFFu32 as f64) as c_ulong;
+                XChangeProperty(display, handle, property, XA_CARDINAL, 32,
+                                PROP_MODE_REPLACE, &value as *const c_ulong as *const u8, 1);
+            }
+            XFlush(display);
+            XCloseDisplay(display);
+        }
+        true
+    }
+
+    pub fn set_always_on_top(handle: sfWindowHandle, on_top: bool) -> bool {
+        unsafe {
+            let display = XOpenDisplay(ptr::null());
+            if display.is_null() {
+                return false;
+            }
+            let mut event = XClientMessageEvent {
+                type_: CLIENT_MESSAGE,
+                serial: 0,
+                send_event: 1,
+                display: display,
+                window: handle,
+                message_type: atom(display, "_NET_WM_STATE"),
+                format: 32,
+                // 1 adds the state, 0 removes it, the last one tells
+                // the request comes from a normal application
+                data: [if on_top { 1 } else { 0 },
+                       atom(display, "_NET_WM_STATE_ABOVE") as c_long,
+                       0, 1, 0],
+                padding: [0; 24]
+            };
+            let sent = XSendEvent(display, XDefaultRootWindow(display), 0,
+                                  SUBSTRUCTURE_REDIRECT_MASK | SUBSTRUCTURE_NOTIFY_MASK,
+                                  &mut event);
+            XFlush(display);
+            XCloseDisplay(display);
+            sent != 0
+        }
+    }
+
+    pub fn get_scale_factor(_handle: sfWindowHandle) -> f32 {
+        unsafe {
+            let display = XOpenDisplay(ptr::null());
+            if display.is_null() {
+                return 1.;
+            }
+            // X11 has no per window scale, desktops publish the font DPI
+            // they scale with in the Xft.dpi resource
+            let program = CString::from_slice(b"Xft");
+            let option = CString::from_slice(b"dpi");
+            let dpi = XGetDefault(display, program.as_ptr(), option.as_ptr());
+            let factor = if dpi.is_null() {
+                1.
+            } else {
+                String::from_utf8_lossy(c_str_to_bytes(&dpi)).trim().parse::<f32>()
+                    .map_or(1., |dpi| dpi / 96.)
+            };
+            XCloseDisplay(display);
+            factor
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use libc::{c_void, c_int, c_long, c_char};
+    use std::mem;
+    use std::num::Float;
+
+    use ffi::sfml_types::sfWindowHandle;
+
+    const GWL_EXSTYLE: c_int = -20;
+    const WS_EX_LAYERED: c_long = 0x80000;
+    const LWA_ALPHA: u32 = 2;
+    const HWND_TOPMOST: int = -1;
+    const HWND_NOTOPMOST: int = -2;
+    const SWP_NOSIZE: u32 = 0x1;
+    const SWP_NOMOVE: u32 = 0x2;
+    const SWP_NOACTIVATE: u32 = 0x10;
+    const LOGPIXELSX: c_int = 88;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn GetWindowLongW(hwnd: *mut c_void, index: c_int) -> c_long;
+        fn SetWindowLongW(hwnd: *mut c_void, index: c_int, value: c_long) -> c_long;
+        fn SetLayeredWindowAttributes(hwnd: *mut c_void, key: u32, alpha: u8, flags: u32) -> c_int;
+        fn SetWindowPos(hwnd: *mut c_void, insert_after: *mut c_void, x: c_int, y: c_int, cx: c_int, cy: c_int, flags: u32) -> c_int;
+        fn GetDC(hwnd: *mut c_void) -> *mut c_void;
+        fn ReleaseDC(hwnd: *mut c_void, hdc: *mut c_void) -> c_int;
+    }
+
+    #[link(name = "gdi32")]
+    extern "system" {
+        fn GetDeviceCaps(hdc: *mut c_void, index: c_int) -> c_int;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetModuleHandleA(name: *const c_char) -> *mut c_void;
+        fn GetProcAddress(module: *mut c_void, name: *const c_char) -> *mut c_void;
+    }
+
+    pub fn set_opacity(handle: sfWindowHandle, opacity: f32) -> bool {
+        unsafe {
+            let style = GetWindowLongW(handle, GWL_EXSTYLE);
+            SetWindowLongW(handle, GWL_EXSTYLE, style | WS_EX_LAYERED);
+            let alpha = (opacity.max(0.).min(1.) * 255.) as u8;
+            SetLayeredWindowAttributes(handle, 0, alpha, LWA_ALPHA) != 0
+        }
+    }
+
+    pub fn set_always_on_top(handle: sfWindowHandle, on_top: bool) -> bool {
+        let insert_after = if on_top { HWND_TOPMOST } else { HWND_NOTOPMOST };
+        unsafe {
+            SetWindowPos(handle, insert_after as *mut c_void, 0, 0, 0, 0,
+                         SWP_NOSIZE | SWP_NOMOVE | SWP_NOACTIVATE) != 0
+        }
+    }
+
+    // GetDpiForWindow only exists since Windows 10, look it up at runtime
+    // so the bindings still load on older systems
+    unsafe fn dpi_for_window(handle: sfWindowHandle) -> u32 {
+        let user32 = GetModuleHandleA(b"user32.dll\0".as_ptr() as *const c_char);
+        if user32.is_null() {
+            return 0;
+        }
+        let proc_ = GetProcAddress(user32, b"GetDpiForWindow\0".as_ptr() as *const c_char);
+        if proc_.is_null() {
+            return 0;
+        }
+        let get_dpi: extern "system" fn(*mut c_void) -> u32 = mem::transmute(proc_);
+        get_dpi(handle)
+    }
+
+    // The system wide DPI, used when the per window one is unavailable
+    unsafe fn dpi_for_device(handle: sfWindowHandle) -> u32 {
+        let hdc = GetDC(handle);
+        if hdc.is_null() {
+            return 0;
+        }
+        let dpi = GetDeviceCaps(hdc, LOGPIXELSX);
+        ReleaseDC(handle, hdc);
+        if dpi > 0 { dpi as u32 } else { 0 }
+    }
+
+    pub fn get_scale_factor(handle: sfWindowHandle) -> f32 {
+        let dpi = unsafe {
+            match dpi_for_window(handle) {
+                0   => dpi_for_device(handle),
+                dpi => dpi
+            }
+        };
+        match dpi {
+            0   => 1.,
+            dpi => dpi as f32 / 96.
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use libc::{c_void, c_char, c_long};
+    use std::mem;
+    use std::num::Float;
+    use std::ffi::CString;
+
+    use ffi::sfml_types::sfWindowHandle;
+
+    const NS_NORMAL_WINDOW_LEVEL: c_long = 0;
+    const NS_FLOATING_WINDOW_LEVEL: c_long = 3;
+
+    #[link(name = "objc")]
+    extern "C" {
+        fn sel_registerName(name: *const c_char) -> *mut c_void;
+        fn objc_getClass(name: *const c_char) -> *mut c_void;
+        fn objc_msgSend();
+    }
+
+    unsafe fn selector(name: &str) -> *mut c_void {
+        sel_registerName(CString::from_slice(name.as_bytes()).as_ptr())
+    }
+
+    // The handle is an NSWindow for the windows SFML creates, but an NSView
+    // for the ones created from an existing view, which belong to its window
+    unsafe fn ns_window(handle: sfWindowHandle) -> *mut c_void {
+        let is_kind_of: extern "C" fn(*mut c_void, *mut c_void, *mut c_void) -> i8 =
+            mem::transmute(objc_msgSend);
+        let ns_view = objc_getClass(CString::from_slice(b"NSView").as_ptr());
+        if is_kind_of(handle, selector("isKindOfClass:"), ns_view) != 0 {
+            let send: extern "C" fn(*mut c_void, *mut c_void) -> *mut c_void =
+                mem::transmute(objc_msgSend);
+            send(handle, selector("window"))
+        } else {
+            handle
+        }
+    }
+
+    pub fn set_opacity(handle: sfWindowHandle, opacity: f32) -> bool {
+        unsafe {
+            let window = ns_window(handle);
+            if window.is_null() {
+                return false;
+            }
+            let send: extern "C" fn(*mut c_void, *mut c_void, f64) =
+                mem::transmute(objc_msgSend);
+            send(window, selector("setAlphaValue:"), opacity.max(0.).min(1.) as f64);
+        }
+        true
+    }
+
+    pub fn set_always_on_top(handle: sfWindowHandle, on_top: bool) -> bool {
+        let level = if on_top { NS_FLOATING_WINDOW_LEVEL } else { NS_NORMAL_WINDOW_LEVEL };
+        unsafe {
+            let window = ns_window(handle);
+            if window.is_null() {
+                return false;
+            }
+            let send: extern "C" fn(*mut c_void, *mut c_void, c_long) =
+                mem::transmute(objc_msgSend);
+            send(window, selector("setLevel:"), level);
+        }
+        true
+    }
+
+    pub fn get_scale_factor(handle: sfWindowHandle) -> f32 {
+        unsafe {
+            let window = ns_window(handle);
+            if window.is_null() {
+                return 1.;
+            }
+            let send: extern "C" fn(*mut c_void, *mut c_void) -> f64 =
+                mem::transmute(objc_msgSend);
+            send(window, selector("backingScaleFactor")) as f32
+        }
+    }
+}
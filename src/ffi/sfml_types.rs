@@ -24,6 +24,19 @@
 
 pub use self::SfBool::{SFFALSE, SFTRUE};
 
+use libc::{c_void, c_ulong};
+
+/// Native window handle (HWND)
+#[cfg(target_os = "windows")]
+pub type sfWindowHandle = *mut c_void;
+/// Native window handle (X11 Window)
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+pub type sfWindowHandle = c_ulong;
+/// Native window handle (NSWindow, or NSView for a window created from
+/// a handle)
+#[cfg(target_os = "macos")]
+pub type sfWindowHandle = *mut c_void;
+
 #[repr(C)]
 #[derive(PartialEq, Eq, Copy)]
 pub enum SfBool {
@@ -66,3 +66,21 @@ pub mod time {
         pub fn sfMicroseconds(amount: c_longlong) -> sfTime;
     }
 }
+
+pub mod input_stream {
+    use libc::c_void;
+
+    pub type sfInputStreamReadFunc = extern "C" fn(data: *mut c_void, size: i64, userData: *mut c_void) -> i64;
+    pub type sfInputStreamSeekFunc = extern "C" fn(position: i64, userData: *mut c_void) -> i64;
+    pub type sfInputStreamTellFunc = extern "C" fn(userData: *mut c_void) -> i64;
+    pub type sfInputStreamGetSizeFunc = extern "C" fn(userData: *mut c_void) -> i64;
+
+    #[repr(C)]
+    pub struct sfInputStream {
+        pub read: sfInputStreamReadFunc,
+        pub seek: sfInputStreamSeekFunc,
+        pub tell: sfInputStreamTellFunc,
+        pub getSize: sfInputStreamGetSizeFunc,
+        pub userData: *mut c_void
+    }
+}
@@ -29,7 +29,7 @@ pub mod window {
     use system::vector2::{Vector2i, Vector2u};
 
     use ffi::window::video_mode::sfVideoMode;
-    use ffi::sfml_types::SfBool;
+    use ffi::sfml_types::{SfBool, sfWindowHandle};
 
     #[repr(C)]
     pub struct sfWindow {
@@ -39,7 +39,7 @@ pub mod window {
     extern "C" {
         pub fn sfWindow_create(mode: sfVideoMode, title: *const c_char, style: c_uint, settings: *const ContextSettings) -> *mut sfWindow;
         pub fn sfWindow_createUnicode(mode: sfVideoMode, title: *const u32, style: c_uint, setting: *const ContextSettings) -> *mut sfWindow;
-        //fn sfWindow_createFromHandle(handle: sfWindowHandle, settings: *mut sfContextSettings) -> *mut sfWindow;
+        pub fn sfWindow_createFromHandle(handle: sfWindowHandle, settings: *const ContextSettings) -> *mut sfWindow;
         pub fn sfWindow_close(window: *mut sfWindow) -> ();
         pub fn sfWindow_destroy(window: *mut sfWindow) -> ();
         pub fn sfWindow_isOpen(window: *mut sfWindow) -> SfBool;
@@ -49,6 +49,10 @@ pub mod window {
         pub fn sfWindow_setIcon(window: *mut sfWindow, width: c_uint, height: c_uint, pixel: *const u8) -> ();
         pub fn sfWindow_setVisible(window: *mut sfWindow, visible: SfBool) -> ();
         pub fn sfWindow_setMouseCursorVisible(window: *mut sfWindow, visible: SfBool) -> ();
+        pub fn sfWindow_setMouseCursorGrabbed(window: *mut sfWindow, grabbed: SfBool) -> ();
+        pub fn sfWindow_requestFocus(window: *mut sfWindow) -> ();
+        pub fn sfWindow_hasFocus(window: *mut sfWindow) -> SfBool;
+        pub fn sfWindow_setMouseCursor(window: *mut sfWindow, cursor: *const ::ffi::window::cursor::sfCursor) -> ();
         pub fn sfWindow_setVerticalSyncEnabled(window: *mut sfWindow, enabled: SfBool) -> ();
         pub fn sfWindow_setKeyRepeatEnabled(window: *mut sfWindow, enabled: SfBool) -> ();
         pub fn sfWindow_setActive(window: *mut sfWindow, active: SfBool) -> SfBool;
@@ -63,7 +67,8 @@ pub mod window {
         pub fn sfWindow_waitEvent(window: *mut sfWindow, event: *mut ::window::event::raw::sfEvent) -> SfBool;
         pub fn sfMouse_getPosition(relativeTo: *mut sfWindow) -> Vector2i;
         pub fn sfMouse_setPosition(position: Vector2i, relativeTo: *mut sfWindow) -> ();
-        //fn sfWindow_getSystemHandle(window: *mut sfWindow) -> sfWindowHandle;
+        pub fn sfTouch_getPosition(finger: c_uint, relativeTo: *mut sfWindow) -> Vector2i;
+        pub fn sfWindow_getSystemHandle(window: *mut sfWindow) -> sfWindowHandle;
     }
 }
 
@@ -71,6 +76,7 @@ pub mod context {
     use libc::c_void;
 
     use ffi::sfml_types::SfBool;
+    use window::ContextSettings;
 
     #[repr(C)]
     pub struct sfContext {
@@ -80,32 +86,48 @@ pub mod context {
     extern "C" {
         pub fn sfContext_create() -> *mut sfContext;
         pub fn sfContext_destroy(context: *mut sfContext) -> ();
-        pub fn sfContext_setActive(context: *mut sfContext, active: SfBool) -> ();
+        pub fn sfContext_setActive(context: *mut sfContext, active: SfBool) -> SfBool;
+        pub fn sfContext_getSettings(context: *const sfContext) -> ContextSettings;
+        pub fn sfContext_getActiveContextId() -> u64;
     }
 }
 
 pub mod joystick {
-    use libc::{c_float, c_uint};
+    use libc::{c_float, c_uint, c_char};
 
     use ffi::sfml_types::SfBool;
 
+    #[repr(C)]
+    #[derive(Copy)]
+    pub struct sfJoystickIdentification {
+        pub name: *const c_char,
+        pub vendorId: c_uint,
+        pub productId: c_uint
+    }
+
     extern "C" {
         pub fn sfJoystick_isConnected(joystick: c_uint) -> SfBool;
         pub fn sfJoystick_getButtonCount(joystick: c_uint) -> c_uint;
         pub fn sfJoystick_hasAxis(joystick: c_uint, axis: c_uint) -> SfBool;
         pub fn sfJoystick_isButtonPressed(joystick: c_uint, button: c_uint) -> SfBool;
         pub fn sfJoystick_getAxisPosition(joystick: c_uint, axis: c_uint) -> c_float;
+        pub fn sfJoystick_getIdentification(joystick: c_uint) -> sfJoystickIdentification;
         pub fn sfJoystick_update() -> ();
     }
 }
 
 pub mod keyboard {
-    pub use libc::c_int;
+    pub use libc::{c_int, c_char};
 
     use ffi::sfml_types::SfBool;
 
     extern "C" {
         pub fn sfKeyboard_isKeyPressed(key: c_int) -> SfBool;
+        pub fn sfKeyboard_isScancodePressed(code: c_int) -> SfBool;
+        pub fn sfKeyboard_localize(code: c_int) -> c_int;
+        pub fn sfKeyboard_delocalize(key: c_int) -> c_int;
+        pub fn sfKeyboard_getDescription(code: c_int) -> *const c_char;
+        pub fn sfKeyboard_setVirtualKeyboardVisible(visible: SfBool) -> ();
     }
 }
 
@@ -120,6 +142,57 @@ pub mod mouse {
     }
 }
 
+pub mod touch {
+    use libc::c_uint;
+
+    use ffi::sfml_types::SfBool;
+
+    extern "C" {
+        pub fn sfTouch_isDown(finger: c_uint) -> SfBool;
+    }
+}
+
+pub mod sensor {
+    use libc::c_uint;
+
+    use system::vector3::Vector3f;
+    use ffi::sfml_types::SfBool;
+
+    extern "C" {
+        pub fn sfSensor_isAvailable(sensor: c_uint) -> SfBool;
+        pub fn sfSensor_setEnabled(sensor: c_uint, enabled: SfBool) -> ();
+        pub fn sfSensor_getValue(sensor: c_uint) -> Vector3f;
+    }
+}
+
+pub mod clipboard {
+    use libc::c_char;
+
+    extern "C" {
+        pub fn sfClipboard_getString() -> *const c_char;
+        pub fn sfClipboard_getUnicodeString() -> *const u32;
+        pub fn sfClipboard_setString(text: *const c_char) -> ();
+        pub fn sfClipboard_setUnicodeString(text: *const u32) -> ();
+    }
+}
+
+pub mod cursor {
+    use libc::{c_void, c_uint};
+
+    use system::vector2::Vector2u;
+
+    #[repr(C)]
+    pub struct sfCursor {
+        this: *mut c_void
+    }
+
+    extern "C" {
+        pub fn sfCursor_createFromPixels(pixels: *const u8, size: Vector2u, hotspot: Vector2u) -> *mut sfCursor;
+        pub fn sfCursor_createFromSystem(cursor_type: c_uint) -> *mut sfCursor;
+        pub fn sfCursor_destroy(cursor: *mut sfCursor) -> ();
+    }
+}
+
 pub mod video_mode {
     use libc::{c_uint, size_t};
 
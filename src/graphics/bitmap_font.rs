@@ -0,0 +1,560 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Fonts made of pre-rendered glyph images
+//!
+//! BitmapFont loads glyphs from a texture, either laid out as a regular
+//! grid of characters or described by an AngelCode BMFont text file,
+//! and BitmapText draws strings with it. This is the usual choice for
+//! pixel-art games which don't want TrueType rasterization.
+
+use std::collections::HashMap;
+use std::num::Float;
+use std::io::File;
+
+use traits::Drawable;
+use graphics::{RenderTarget, RenderStates, Color, Texture, Vertex, IntRect,
+               FloatRect, Quads, Image};
+use system::vector2::Vector2f;
+
+/// Glyphs of the built-in font, 5 columns of 7 pixels per character from
+/// ' ' to '~', the lowest bit of each column being its top pixel.
+static BUILTIN_GLYPHS: [u8; 475] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, // space
+    0x00, 0x00, 0x5F, 0x00, 0x00, // !
+    0x00, 0x07, 0x00, 0x07, 0x00, // "
+    0x14, 0x7F, 0x14, 0x7F, 0x14, // #
+    0x24, 0x2A, 0x7F, 0x2A, 0x12, // $
+    0x23, 0x13, 0x08, 0x64, 0x62, // %
+    0x36, 0x49, 0x55, 0x22, 0x50, // &
+    0x00, 0x05, 0x03, 0x00, 0x00, // '
+    0x00, 0x1C, 0x22, 0x41, 0x00, // (
+    0x00, 0x41, 0x22, 0x1C, 0x00, // )
+    0x08, 0x2A, 0x1C, 0x2A, 0x08, // *
+    0x08, 0x08, 0x3E, 0x08, 0x08, // +
+    0x00, 0x50, 0x30, 0x00, 0x00, // ,
+    0x08, 0x08, 0x08, 0x08, 0x08, // -
+    0x00, 0x60, 0x60, 0x00, 0x00, // .
+    0x20, 0x10, 0x08, 0x04, 0x02, // /
+    0x3E, 0x51, 0x49, 0x45, 0x3E, // 0
+    0x00, 0x42, 0x7F, 0x40, 0x00, // 1
+    0x42, 0x61, 0x51, 0x49, 0x46, // 2
+    0x21, 0x41, 0x45, 0x4B, 0x31, // 3
+    0x18, 0x14, 0x12, 0x7F, 0x10, // 4
+    0x27, 0x45, 0x45, 0x45, 0x39, // 5
+    0x3C, 0x4A, 0x49, 0x49, 0x30, // 6
+    0x01, 0x71, 0x09, 0x05, 0x03, // 7
+    0x36, 0x49, 0x49, 0x49, 0x36, // 8
+    0x06, 0x49, 0x49, 0x29, 0x1E, // 9
+    0x00, 0x36, 0x36, 0x00, 0x00, // :
+    0x00, 0x56, 0x36, 0x00, 0x00, // ;
+    0x08, 0x14, 0x22, 0x41, 0x00, // <
+    0x14, 0x14, 0x14, 0x14, 0x14, // =
+    0x00, 0x41, 0x22, 0x14, 0x08, // >
+    0x02, 0x01, 0x51, 0x09, 0x06, // ?
+    0x32, 0x49, 0x79, 0x41, 0x3E, // @
+    0x7E, 0x11, 0x11, 0x11, 0x7E, // A
+    0x7F, 0x49, 0x49, 0x49, 0x36, // B
+    0x3E, 0x41, 0x41, 0x41, 0x22, // C
+    0x7F, 0x41, 0x41, 0x22, 0x1C, // D
+    0x7F, 0x49, 0x49, 0x49, 0x41, // E
+    0x7F, 0x09, 0x09, 0x09, 0x01, // F
+    0x3E, 0x41, 0x49, 0x49, 0x7A, // G
+    0x7F, 0x08, 0x08, 0x08, 0x7F, // H
+    0x00, 0x41, 0x7F, 0x41, 0x00, // I
+    0x20, 0x40, 0x41, 0x3F, 0x01, // J
+    0x7F, 0x08, 0x14, 0x22, 0x41, // K
+    0x7F, 0x40, 0x40, 0x40, 0x40, // L
+    0x7F, 0x02, 0x0C, 0x02, 0x7F, // M
+    0x7F, 0x04, 0x08, 0x10, 0x7F, // N
+    0x3E, 0x41, 0x41, 0x41, 0x3E, // O
+    0x7F, 0x09, 0x09, 0x09, 0x06, // P
+    0x3E, 0x41, 0x51, 0x21, 0x5E, // Q
+    0x7F, 0x09, 0x19, 0x29, 0x46, // R
+    0x46, 0x49, 0x49, 0x49, 0x31, // S
+    0x01, 0x01, 0x7F, 0x01, 0x01, // T
+    0x3F, 0x40, 0x40, 0x40, 0x3F, // U
+    0x1F, 0x20, 0x40, 0x20, 0x1F, // V
+    0x3F, 0x40, 0x38, 0x40, 0x3F, // W
+    0x63, 0x14, 0x08, 0x14, 0x63, // X
+    0x07, 0x08, 0x70, 0x08, 0x07, // Y
+    0x61, 0x51, 0x49, 0x45, 0x43, // Z
+    0x00, 0x7F, 0x41, 0x41, 0x00, // [
+    0x02, 0x04, 0x08, 0x10, 0x20, // backslash
+    0x00, 0x41, 0x41, 0x7F, 0x00, // ]
+    0x04, 0x02, 0x01, 0x02, 0x04, // ^
+    0x40, 0x40, 0x40, 0x40, 0x40, // _
+    0x00, 0x01, 0x02, 0x04, 0x00, // `
+    0x20, 0x54, 0x54, 0x54, 0x78, // a
+    0x7F, 0x48, 0x44, 0x44, 0x38, // b
+    0x38, 0x44, 0x44, 0x44, 0x20, // c
+    0x38, 0x44, 0x44, 0x48, 0x7F, // d
+    0x38, 0x54, 0x54, 0x54, 0x18, // e
+    0x08, 0x7E, 0x09, 0x01, 0x02, // f
+    0x0C, 0x52, 0x52, 0x52, 0x3E, // g
+    0x7F, 0x08, 0x04, 0x04, 0x78, // h
+    0x00, 0x44, 0x7D, 0x40, 0x00, // i
+    0x20, 0x40, 0x44, 0x3D, 0x00, // j
+    0x7F, 0x10, 0x28, 0x44, 0x00, // k
+    0x00, 0x41, 0x7F, 0x40, 0x00, // l
+    0x7C, 0x04, 0x18, 0x04, 0x78, // m
+    0x7C, 0x08, 0x04, 0x04, 0x78, // n
+    0x38, 0x44, 0x44, 0x44, 0x38, // o
+    0x7C, 0x14, 0x14, 0x14, 0x08, // p
+    0x08, 0x14, 0x14, 0x18, 0x7C, // q
+    0x7C, 0x08, 0x04, 0x04, 0x08, // r
+    0x48, 0x54, 0x54, 0x54, 0x20, // s
+    0x04, 0x3F, 0x44, 0x40, 0x20, // t
+    0x3C, 0x40, 0x40, 0x20, 0x7C, // u
+    0x1C, 0x20, 0x40, 0x20, 0x1C, // v
+    0x3C, 0x40, 0x30, 0x40, 0x3C, // w
+    0x44, 0x28, 0x10, 0x28, 0x44, // x
+    0x0C, 0x50, 0x50, 0x50, 0x3C, // y
+    0x44, 0x64, 0x54, 0x4C, 0x44, // z
+    0x00, 0x08, 0x36, 0x41, 0x00, // {
+    0x00, 0x00, 0x7F, 0x00, 0x00, // |
+    0x00, 0x41, 0x36, 0x08, 0x00, // }
+    0x02, 0x01, 0x02, 0x04, 0x02, // ~
+];
+
+/// A glyph of a BitmapFont
+#[derive(Clone, PartialEq, Show, Copy)]
+pub struct BitmapGlyph {
+    /// Offset to move horizontally to the next character
+    pub advance: f32,
+    /// Offset of the glyph image from the top of the line
+    pub offset: Vector2f,
+    /// Coordinates of the glyph inside the font texture
+    pub texture_rect: IntRect
+}
+
+/// Font made of pre-rendered glyph images
+pub struct BitmapFont {
+    #[doc(hidden)]
+    texture: Texture,
+    #[doc(hidden)]
+    glyphs: HashMap<u32, BitmapGlyph>,
+    #[doc(hidden)]
+    kernings: HashMap<(u32, u32), f32>,
+    #[doc(hidden)]
+    line_height: f32
+}
+
+impl BitmapFont {
+    /// Create the built-in BitmapFont
+    ///
+    /// The built-in font is a 5x7 pixels font covering the printable ASCII
+    /// characters, available without loading any file. It is meant for
+    /// debugging text, use BitmapText::set_scale to make it bigger.
+    ///
+    /// Return Some(BitmapFont) or None
+    pub fn new_builtin() -> Option<BitmapFont> {
+        let mut image = match Image::new_from_color(16 * 6, 6 * 8, &Color::transparent()) {
+            Some(image) => image,
+            None => return None
+        };
+        for (i, columns) in BUILTIN_GLYPHS.chunks(5).enumerate() {
+            let cell_x = (i % 16) * 6;
+            let cell_y = (i / 16) * 8;
+            for (x, column) in columns.iter().enumerate() {
+                for y in range(0u, 7) {
+                    if *column & (1u8 << y) != 0 {
+                        image.set_pixel(cell_x + x, cell_y + y, &Color::white());
+                    }
+                }
+            }
+        }
+        match Texture::new_from_image(&image) {
+            Some(texture) => BitmapFont::new_from_grid(texture, ' ', 6, 8),
+            None => None
+        }
+    }
+
+    /// Create a BitmapFont from a grid of characters
+    ///
+    /// The characters are laid out row by row, starting with first_char
+    /// in the top left cell and following the unicode order.
+    ///
+    /// # Arguments
+    /// * texture - The texture containing the grid
+    /// * first_char - The character of the top left cell
+    /// * cell_width - Width of a cell, in pixels
+    /// * cell_height - Height of a cell, in pixels
+    ///
+    /// Return Some(BitmapFont) or None if a cell size is 0
+    pub fn new_from_grid(texture: Texture,
+                         first_char: char,
+                         cell_width: uint,
+                         cell_height: uint) -> Option<BitmapFont> {
+        if cell_width == 0 || cell_height == 0 {
+            return None
+        }
+        let size = texture.get_size();
+        let columns = size.x as uint / cell_width;
+        let rows = size.y as uint / cell_height;
+        let mut glyphs = HashMap::new();
+        for row in range(0u, rows) {
+            for col in range(0u, columns) {
+                let codepoint = first_char as u32 + (row * columns + col) as u32;
+                glyphs.insert(codepoint, BitmapGlyph {
+                    advance: cell_width as f32,
+                    offset: Vector2f::new(0., 0.),
+                    texture_rect: IntRect::new((col * cell_width) as i32,
+                                               (row * cell_height) as i32,
+                                               cell_width as i32,
+                                               cell_height as i32)
+                });
+            }
+        }
+        Some(BitmapFont {
+            texture: texture,
+            glyphs: glyphs,
+            kernings: HashMap::new(),
+            line_height: cell_height as f32
+        })
+    }
+
+    /// Create a BitmapFont from a BMFont description file
+    ///
+    /// Only the text format of BMFont with a single page is supported.
+    /// The page texture is loaded relative to the description file.
+    ///
+    /// # Arguments
+    /// * filename - Path of the .fnt file to load
+    ///
+    /// Return Some(BitmapFont) or None
+    pub fn new_from_fnt_file(filename: &str) -> Option<BitmapFont> {
+        let path = Path::new(filename);
+        let description = match File::open(&path).read_to_string() {
+            Ok(description) => description,
+            Err(_)          => return None
+        };
+        let page = match parse_page_file(description.as_slice()) {
+            Some(page) => page,
+            None       => return None
+        };
+        let texture_path = path.dir_path().join(page);
+        let texture = match texture_path.as_str().and_then(|p| Texture::new_from_file(p)) {
+            Some(texture) => texture,
+            None          => return None
+        };
+        BitmapFont::new_from_fnt(description.as_slice(), texture)
+    }
+
+    /// Create a BitmapFont from a BMFont description and its texture
+    ///
+    /// # Arguments
+    /// * description - Content of the .fnt file, in text format
+    /// * texture - The texture of the font page
+    ///
+    /// Return Some(BitmapFont) or None if the description is invalid
+    pub fn new_from_fnt(description: &str, texture: Texture) -> Option<BitmapFont> {
+        let mut glyphs = HashMap::new();
+        let mut kernings = HashMap::new();
+        let mut line_height = None;
+        for line in description.lines() {
+            let mut words = line.words();
+            let tag = match words.next() {
+                Some(tag) => tag,
+                None      => continue
+            };
+            let attributes: HashMap<&str, &str> = words.filter_map(|word| {
+                let mut parts = word.splitn(1, '=');
+                match (parts.next(), parts.next()) {
+                    (Some(key), Some(value)) => Some((key, value)),
+                    _                        => None
+                }
+            }).collect();
+            let get = |&: key: &str| -> Option<i32> {
+                attributes.get(key).and_then(|value| value.parse::<i32>())
+            };
+            match tag {
+                "common" => line_height = get("lineHeight"),
+                "char"   => {
+                    match (get("id"), get("x"), get("y"), get("width"), get("height"),
+                           get("xoffset"), get("yoffset"), get("xadvance")) {
+                        (Some(id), Some(x), Some(y), Some(w), Some(h),
+                         Some(xoffset), Some(yoffset), Some(xadvance)) => {
+                            glyphs.insert(id as u32, BitmapGlyph {
+                                advance: xadvance as f32,
+                                offset: Vector2f::new(xoffset as f32, yoffset as f32),
+                                texture_rect: IntRect::new(x, y, w, h)
+                            });
+                        },
+                        _ => return None
+                    }
+                },
+                "kerning" => {
+                    match (get("first"), get("second"), get("amount")) {
+                        (Some(first), Some(second), Some(amount)) => {
+                            kernings.insert((first as u32, second as u32), amount as f32);
+                        },
+                        _ => return None
+                    }
+                },
+                _ => {}
+            }
+        }
+        match line_height {
+            Some(line_height) => Some(BitmapFont {
+                texture: texture,
+                glyphs: glyphs,
+                kernings: kernings,
+                line_height: line_height as f32
+            }),
+            None => None
+        }
+    }
+
+    /// Get a glyph of a BitmapFont
+    ///
+    /// # Arguments
+    /// * codepoint - Unicode code point of the character
+    ///
+    /// Return Some(BitmapGlyph) or None if the font has no such character
+    pub fn get_glyph(&self, codepoint: u32) -> Option<BitmapGlyph> {
+        self.glyphs.get(&codepoint).map(|glyph| *glyph)
+    }
+
+    /// Get the kerning offset between two characters
+    ///
+    /// # Arguments
+    /// * first - Unicode code point of the first character
+    /// * second - Unicode code point of the second character
+    ///
+    /// Return the kerning offset, in pixels
+    pub fn get_kerning(&self, first: u32, second: u32) -> f32 {
+        match self.kernings.get(&(first, second)) {
+            Some(amount) => *amount,
+            None         => 0.
+        }
+    }
+
+    /// Get the distance between two lines, in pixels
+    pub fn get_line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Get the texture of a BitmapFont
+    pub fn get_texture(&self) -> &Texture {
+        &self.texture
+    }
+}
+
+// Find the file of the first page of a BMFont description
+#[doc(hidden)]
+fn parse_page_file(description: &str) -> Option<&str> {
+    for line in description.lines() {
+        if line.starts_with("page ") {
+            match line.find_str("file=\"") {
+                Some(start) => {
+                    let rest = line.slice_from(start + 6);
+                    return rest.find('"').map(|end| rest.slice_to(end))
+                },
+                None => return None
+            }
+        }
+    }
+    None
+}
+
+/// Text drawn with a BitmapFont
+pub struct BitmapText<'s> {
+    #[doc(hidden)]
+    font: &'s BitmapFont,
+    #[doc(hidden)]
+    string: String,
+    #[doc(hidden)]
+    scale: f32,
+    #[doc(hidden)]
+    color: Color,
+    #[doc(hidden)]
+    position: Vector2f,
+    #[doc(hidden)]
+    size: Vector2f,
+    #[doc(hidden)]
+    vertices: Vec<Vertex>
+}
+
+impl<'s> BitmapText<'s> {
+    /// Create a new BitmapText
+    ///
+    /// # Arguments
+    /// * string - The string to display
+    /// * font - The bitmap font
+    ///
+    /// Return a new BitmapText
+    pub fn new(string: &str, font: &'s BitmapFont) -> BitmapText<'s> {
+        let mut text = BitmapText {
+            font: font,
+            string: string.to_string(),
+            scale: 1.,
+            color: Color::white(),
+            position: Vector2f::new(0., 0.),
+            size: Vector2f::new(0., 0.),
+            vertices: Vec::new()
+        };
+        text.update_vertices();
+        text
+    }
+
+    /// Set the string of a BitmapText
+    ///
+    /// # Arguments
+    /// * string - New string
+    pub fn set_string(&mut self, string: &str) -> () {
+        self.string = string.to_string();
+        self.update_vertices();
+    }
+
+    /// Get the string of a BitmapText
+    pub fn get_string(&self) -> &str {
+        self.string.as_slice()
+    }
+
+    /// Set the scale factor of a BitmapText
+    ///
+    /// Integer factors keep pixel-art glyphs sharp.
+    ///
+    /// # Arguments
+    /// * scale - New scale factor
+    pub fn set_scale(&mut self, scale: f32) -> () {
+        self.scale = scale;
+        self.update_vertices();
+    }
+
+    /// Get the scale factor of a BitmapText
+    pub fn get_scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Set the color of a BitmapText
+    ///
+    /// # Arguments
+    /// * color - New color of the text
+    pub fn set_color(&mut self, color: &Color) -> () {
+        self.color = *color;
+        self.update_vertices();
+    }
+
+    /// Get the color of a BitmapText
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    /// Set the position of a BitmapText
+    ///
+    /// # Arguments
+    /// * position - New position of the top left corner
+    pub fn set_position(&mut self, position: &Vector2f) -> () {
+        self.position = *position;
+        self.update_vertices();
+    }
+
+    /// Set the position of a BitmapText
+    ///
+    /// # Arguments
+    /// * x - X coordinate of the new position
+    /// * y - Y coordinate of the new position
+    pub fn set_position2f(&mut self, x: f32, y: f32) -> () {
+        self.set_position(&Vector2f::new(x, y))
+    }
+
+    /// Get the position of a BitmapText
+    pub fn get_position(&self) -> Vector2f {
+        self.position
+    }
+
+    /// Get the global bounding rectangle of a BitmapText
+    pub fn get_global_bounds(&self) -> FloatRect {
+        FloatRect::new(self.position.x, self.position.y,
+                       self.size.x, self.size.y)
+    }
+
+    #[doc(hidden)]
+    fn update_vertices(&mut self) -> () {
+        self.vertices.clear();
+        let mut x = 0.;
+        let mut y = 0.;
+        let mut width: f32 = 0.;
+        let mut previous: Option<u32> = None;
+        for c in self.string.chars() {
+            if c == '\n' {
+                x = 0.;
+                y += self.font.line_height;
+                previous = None;
+                continue
+            }
+            let codepoint = c as u32;
+            match previous {
+                Some(p) => x += self.font.get_kerning(p, codepoint),
+                None    => {}
+            }
+            previous = Some(codepoint);
+            let glyph = match self.font.get_glyph(codepoint) {
+                Some(glyph) => glyph,
+                None        => continue
+            };
+            let tex = glyph.texture_rect;
+            let left = self.position.x + (x + glyph.offset.x) * self.scale;
+            let top = self.position.y + (y + glyph.offset.y) * self.scale;
+            let right = left + tex.width as f32 * self.scale;
+            let bottom = top + tex.height as f32 * self.scale;
+            let u1 = tex.left as f32;
+            let v1 = tex.top as f32;
+            let u2 = (tex.left + tex.width) as f32;
+            let v2 = (tex.top + tex.height) as f32;
+            self.vertices.push(Vertex::new(&Vector2f::new(left, top), &self.color, &Vector2f::new(u1, v1)));
+            self.vertices.push(Vertex::new(&Vector2f::new(right, top), &self.color, &Vector2f::new(u2, v1)));
+            self.vertices.push(Vertex::new(&Vector2f::new(right, bottom), &self.color, &Vector2f::new(u2, v2)));
+            self.vertices.push(Vertex::new(&Vector2f::new(left, bottom), &self.color, &Vector2f::new(u1, v2)));
+            x += glyph.advance;
+            width = width.max(x);
+        }
+        self.size = Vector2f::new(width * self.scale,
+                                  (y + self.font.line_height) * self.scale);
+    }
+}
+
+impl<'s> Drawable for BitmapText<'s> {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        if !self.vertices.is_empty() {
+            let mut rs = RenderStates::default();
+            rs.texture = Some(&self.font.texture);
+            render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+        }
+    }
+
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 render_states: &mut RenderStates) -> () {
+        if !self.vertices.is_empty() {
+            let mut rs = RenderStates::new(render_states.blendMode,
+                                           render_states.transform,
+                                           Some(&self.font.texture),
+                                           render_states.shader);
+            render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+        }
+    }
+}
@@ -26,6 +26,8 @@
 
 pub use self::BlendMode::{BlendAlpha, BlendAdd, BlendMultiply, BlendNone};
 
+use ffi::graphics::render_states as ffi;
+
 ///Available Blending modes for drawing.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
 pub enum BlendMode {
@@ -37,4 +39,30 @@ pub enum BlendMode {
     BlendMultiply = 2,
     /// Pixel = Source.
     BlendNone = 3
-}
\ No newline at end of file
+}
+
+impl BlendMode {
+    // Internal rsfml use only
+    // CSFML describes a blend mode by its factors and equations
+    #[doc(hidden)]
+    pub fn unwrap(&self) -> ffi::sfBlendMode {
+        let (color_src, color_dst, alpha_src, alpha_dst) = match *self {
+            BlendAlpha    => (ffi::SFBLENDFACTORSRCALPHA, ffi::SFBLENDFACTORONEMINUSSRCALPHA,
+                              ffi::SFBLENDFACTORONE, ffi::SFBLENDFACTORONEMINUSSRCALPHA),
+            BlendAdd      => (ffi::SFBLENDFACTORSRCALPHA, ffi::SFBLENDFACTORONE,
+                              ffi::SFBLENDFACTORONE, ffi::SFBLENDFACTORONE),
+            BlendMultiply => (ffi::SFBLENDFACTORDSTCOLOR, ffi::SFBLENDFACTORZERO,
+                              ffi::SFBLENDFACTORDSTCOLOR, ffi::SFBLENDFACTORZERO),
+            BlendNone     => (ffi::SFBLENDFACTORONE, ffi::SFBLENDFACTORZERO,
+                              ffi::SFBLENDFACTORONE, ffi::SFBLENDFACTORZERO)
+        };
+        ffi::sfBlendMode {
+            colorSrcFactor: color_src,
+            colorDstFactor: color_dst,
+            colorEquation: ffi::SFBLENDEQUATIONADD,
+            alphaSrcFactor: alpha_src,
+            alphaDstFactor: alpha_dst,
+            alphaEquation: ffi::SFBLENDEQUATIONADD
+        }
+    }
+}
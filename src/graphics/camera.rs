@@ -0,0 +1,234 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Smooth 2D camera following a target
+//!
+//! Camera drives a View: it follows a target position with an optional
+//! smoothing, lets the target move freely inside a dead zone and keeps
+//! the displayed zone inside the bounds of the world.
+
+use std::num::Float;
+
+use graphics::{FloatRect, View};
+use system::vector2::Vector2f;
+use system::Time;
+
+/// Smooth 2D camera following a target
+///
+/// Call update once per frame with the time elapsed since the last frame,
+/// then set the view of the camera on the render target.
+pub struct Camera {
+    #[doc(hidden)]
+    view: View,
+    #[doc(hidden)]
+    target: Vector2f,
+    #[doc(hidden)]
+    smoothing: f32,
+    #[doc(hidden)]
+    dead_zone: Vector2f,
+    #[doc(hidden)]
+    bounds: Option<FloatRect>
+}
+
+impl Camera {
+    /// Create a new Camera
+    ///
+    /// The camera starts centered on its target, without smoothing,
+    /// dead zone or bounds.
+    ///
+    /// # Arguments
+    /// * center - The initial center of the camera and its target
+    /// * size - The size of the zone displayed
+    ///
+    /// Return Some(Camera) or None
+    pub fn new(center: &Vector2f, size: &Vector2f) -> Option<Camera> {
+        match View::new_init(center, size) {
+            Some(view) => Some(Camera::new_from_view(view)),
+            None => None
+        }
+    }
+
+    /// Create a new Camera driving an existing view
+    ///
+    /// # Arguments
+    /// * view - The view to drive, its center is used as the initial target
+    ///
+    /// Return a new Camera
+    pub fn new_from_view(view: View) -> Camera {
+        let center = view.get_center();
+        Camera {
+            view: view,
+            target: center,
+            smoothing: 0.,
+            dead_zone: Vector2f::new(0., 0.),
+            bounds: None
+        }
+    }
+
+    /// Set the position followed by the camera
+    ///
+    /// # Arguments
+    /// * target - The new target, in world coordinates
+    pub fn set_target(&mut self, target: &Vector2f) -> () {
+        self.target = *target
+    }
+
+    /// Get the position followed by the camera
+    ///
+    /// Return the target, in world coordinates
+    pub fn get_target(&self) -> Vector2f {
+        self.target
+    }
+
+    /// Set the smoothing of the camera
+    ///
+    /// The smoothing is the rate at which the camera catches up with
+    /// its target, per second: the higher, the faster. A smoothing of 0
+    /// makes the camera stick to its target.
+    ///
+    /// # Arguments
+    /// * smoothing - The new smoothing
+    pub fn set_smoothing(&mut self, smoothing: f32) -> () {
+        self.smoothing = smoothing
+    }
+
+    /// Get the smoothing of the camera
+    ///
+    /// Return the smoothing
+    pub fn get_smoothing(&self) -> f32 {
+        self.smoothing
+    }
+
+    /// Set the dead zone of the camera
+    ///
+    /// The dead zone is a rectangle centered on the camera inside which
+    /// the target can move without the camera following it.
+    ///
+    /// # Arguments
+    /// * size - The size of the dead zone, in world units
+    pub fn set_dead_zone(&mut self, size: &Vector2f) -> () {
+        self.dead_zone = *size
+    }
+
+    /// Get the dead zone of the camera
+    ///
+    /// Return the size of the dead zone
+    pub fn get_dead_zone(&self) -> Vector2f {
+        self.dead_zone
+    }
+
+    /// Set the bounds of the world
+    ///
+    /// The camera never displays anything outside of the bounds. If the
+    /// bounds are smaller than the displayed zone, the camera is centered
+    /// on them.
+    ///
+    /// # Arguments
+    /// * bounds - The bounds of the world, or None to disable clamping
+    pub fn set_bounds(&mut self, bounds: Option<FloatRect>) -> () {
+        self.bounds = bounds
+    }
+
+    /// Get the bounds of the world
+    ///
+    /// Return Some(bounds) or None if clamping is disabled
+    pub fn get_bounds(&self) -> Option<FloatRect> {
+        self.bounds
+    }
+
+    /// Move the camera toward its target
+    ///
+    /// # Arguments
+    /// * elapsed - Time elapsed since the last update
+    pub fn update(&mut self, elapsed: &Time) -> () {
+        let center = self.view.get_center();
+        let desired = Vector2f::new(
+            follow(center.x, self.target.x, self.dead_zone.x / 2.),
+            follow(center.y, self.target.y, self.dead_zone.y / 2.));
+
+        let new_center = if self.smoothing > 0. {
+            let t = 1. - (-self.smoothing * elapsed.as_seconds()).exp();
+            center + (desired - center) * t
+        } else {
+            desired
+        };
+        let clamped = self.clamp(new_center);
+        self.view.set_center(&clamped)
+    }
+
+    /// Move the camera on its target immediately
+    ///
+    /// The dead zone and the smoothing are ignored, the bounds are not.
+    pub fn snap(&mut self) -> () {
+        let clamped = self.clamp(self.target);
+        self.view.set_center(&clamped)
+    }
+
+    #[doc(hidden)]
+    fn clamp(&self, center: Vector2f) -> Vector2f {
+        match self.bounds {
+            Some(bounds) => {
+                let size = self.view.get_size();
+                Vector2f::new(clamp_axis(center.x, size.x, bounds.left, bounds.width),
+                              clamp_axis(center.y, size.y, bounds.top, bounds.height))
+            },
+            None => center
+        }
+    }
+
+    /// Get the view driven by the camera
+    ///
+    /// Return a reference to the view
+    pub fn get_view(&self) -> &View {
+        &self.view
+    }
+
+    /// Get the view driven by the camera, to zoom or rotate it
+    ///
+    /// Its center is overwritten by the next update.
+    ///
+    /// Return a mutable reference to the view
+    pub fn get_view_mut(&mut self) -> &mut View {
+        &mut self.view
+    }
+}
+
+fn follow(center: f32, target: f32, half_dead_zone: f32) -> f32 {
+    if target > center + half_dead_zone {
+        target - half_dead_zone
+    } else if target < center - half_dead_zone {
+        target + half_dead_zone
+    } else {
+        center
+    }
+}
+
+fn clamp_axis(center: f32, size: f32, start: f32, length: f32) -> f32 {
+    if length <= size {
+        start + length / 2.
+    } else {
+        center.max(start + size / 2.).min(start + length - size / 2.)
+    }
+}
@@ -24,7 +24,7 @@
 
 //! Specialized shape representing a circle.
 
-use libc::{c_float, c_uint};
+use libc::{c_float, size_t};
 use std::ptr;
 
 use traits::{Drawable, Wrappable};
@@ -99,7 +99,7 @@ impl<'s> CircleShape<'s> {
         } else {
             unsafe {
                 ffi::sfCircleShape_setRadius(circle, radius as c_float);
-                ffi::sfCircleShape_setPointCount(circle, point_count as c_uint);
+                ffi::sfCircleShape_setPointCount(circle, point_count as size_t);
             }
             Some(CircleShape {
                     circle_shape: circle,
@@ -328,9 +328,9 @@ impl<'s> CircleShape<'s> {
     /// * index - Index of the point to get, in range [0 .. getPointCount() - 1]
     ///
     /// Return the index-th point of the shape
-    pub fn get_point(&self, index: uint) -> () {
+    pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
-            ffi::sfCircleShape_getPoint(self.circle_shape, index as c_uint)
+            ffi::sfCircleShape_getPoint(self.circle_shape, index as size_t)
         }
     }
 
@@ -359,7 +359,7 @@ impl<'s> CircleShape<'s> {
     /// * count - New number of points of the circle
     pub fn set_point_count(&mut self, count: uint) -> () {
         unsafe {
-            ffi::sfCircleShape_setPointCount(self.circle_shape, count as c_uint)
+            ffi::sfCircleShape_setPointCount(self.circle_shape, count as size_t)
         }
     }
 
@@ -621,6 +621,8 @@ impl<'s> Wrappable<*mut ffi::sfCircleShape> for CircleShape<'s> {
     }
 }
 
+impl_transformable!(CircleShape<'s>, 's);
+
 impl<'s> Drawable for CircleShape<'s> {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_circle_shape(self)
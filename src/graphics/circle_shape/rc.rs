@@ -26,7 +26,7 @@
 
 use std::rc::Rc;
 use std::cell::RefCell;
-use libc::{c_float, c_uint};
+use libc::{c_float, size_t};
 use std::ptr;
 
 use traits::{Drawable, Wrappable};
@@ -100,7 +100,7 @@ impl CircleShape {
         } else {
             unsafe {
                 ffi::sfCircleShape_setRadius(circle, radius as c_float);
-                ffi::sfCircleShape_setPointCount(circle, point_count as c_uint);
+                ffi::sfCircleShape_setPointCount(circle, point_count as size_t);
             }
             Some(CircleShape {
                     circle_shape: circle,
@@ -331,9 +331,9 @@ impl CircleShape {
     /// * index- Index of the point to get, in range [0 .. getPointCount() - 1]
     ///
     /// Return the index-th point of the shape
-    pub fn get_point(&self, index: uint) -> () {
+    pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
-            ffi::sfCircleShape_getPoint(self.circle_shape, index as c_uint)
+            ffi::sfCircleShape_getPoint(self.circle_shape, index as size_t)
         }
     }
 
@@ -362,7 +362,7 @@ impl CircleShape {
     /// * count - New number of points of the circle
     pub fn set_point_count(&mut self, count: uint) -> () {
         unsafe {
-            ffi::sfCircleShape_setPointCount(self.circle_shape, count as c_uint)
+            ffi::sfCircleShape_setPointCount(self.circle_shape, count as size_t)
         }
     }
 
@@ -624,6 +624,8 @@ impl Wrappable<*mut ffi::sfCircleShape> for CircleShape {
     }
 }
 
+impl_transformable!(CircleShape);
+
 impl Drawable for CircleShape {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_circle_shape_rc(self)
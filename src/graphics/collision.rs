@@ -0,0 +1,259 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Collision tests between sprites
+//!
+//! Cheap tests on axis aligned (AABB) and oriented (OBB) bounding boxes,
+//! and pixel perfect tests on bit masks built from the alpha channel of
+//! images. The bounding box tests are meant to be used first to skip
+//! the pixel perfect test when the sprites are far apart.
+
+use std::num::Float;
+
+use graphics::{FloatRect, IntRect, Image, Sprite, Texture, Transform};
+use system::vector2::Vector2f;
+
+/// Solid pixels of an image
+///
+/// A pixel is solid if its alpha is greater than a threshold.
+#[derive(Clone, PartialEq, Show)]
+pub struct BitMask {
+    #[doc(hidden)]
+    width: uint,
+    #[doc(hidden)]
+    height: uint,
+    #[doc(hidden)]
+    bits: Vec<bool>
+}
+
+impl BitMask {
+    /// Build a BitMask from the alpha channel of an image
+    ///
+    /// # Arguments
+    /// * image - The image
+    /// * alpha_threshold - Pixels with an alpha greater than this value are solid
+    ///
+    /// Return a new BitMask
+    pub fn new_from_image(image: &Image, alpha_threshold: u8) -> BitMask {
+        let size = image.get_size();
+        let (width, height) = (size.x as uint, size.y as uint);
+        let pixels = image.get_pixels();
+        let mut bits = Vec::with_capacity(width * height);
+        for i in range(0u, width * height) {
+            bits.push(pixels[i * 4 + 3] > alpha_threshold);
+        }
+        BitMask {
+            width: width,
+            height: height,
+            bits: bits
+        }
+    }
+
+    /// Build a BitMask from the alpha channel of a texture
+    ///
+    /// The texture is copied back from the graphics card, so this is slow:
+    /// build the masks once when the textures are loaded.
+    ///
+    /// # Arguments
+    /// * texture - The texture
+    /// * alpha_threshold - Pixels with an alpha greater than this value are solid
+    ///
+    /// Return Some(BitMask) or None if the texture can't be copied
+    pub fn new_from_texture(texture: &Texture, alpha_threshold: u8) -> Option<BitMask> {
+        texture.copy_to_image().map(|image| BitMask::new_from_image(&image, alpha_threshold))
+    }
+
+    /// Get the width of the mask
+    ///
+    /// Return the width, in pixels
+    pub fn get_width(&self) -> uint {
+        self.width
+    }
+
+    /// Get the height of the mask
+    ///
+    /// Return the height, in pixels
+    pub fn get_height(&self) -> uint {
+        self.height
+    }
+
+    /// Tell whether a pixel is solid
+    ///
+    /// # Arguments
+    /// * x - X coordinate of the pixel
+    /// * y - Y coordinate of the pixel
+    ///
+    /// Return true if the pixel is solid, false if it isn't or is out of the mask
+    pub fn is_solid(&self, x: int, y: int) -> bool {
+        if x < 0 || y < 0 || x as uint >= self.width || y as uint >= self.height {
+            false
+        } else {
+            self.bits[y as uint * self.width + x as uint]
+        }
+    }
+}
+
+/// Compute the intersection of two rectangles
+///
+/// # Arguments
+/// * a - First rectangle
+/// * b - Second rectangle
+///
+/// Return Some(intersection) or None if the rectangles don't overlap
+pub fn intersection(a: &FloatRect, b: &FloatRect) -> Option<FloatRect> {
+    let left = a.left.max(b.left);
+    let top = a.top.max(b.top);
+    let right = (a.left + a.width).min(b.left + b.width);
+    let bottom = (a.top + a.height).min(b.top + b.height);
+    if left < right && top < bottom {
+        Some(FloatRect::new(left, top, right - left, bottom - top))
+    } else {
+        None
+    }
+}
+
+/// Test two sprites for overlapping axis aligned bounding boxes
+///
+/// # Arguments
+/// * a - First sprite
+/// * b - Second sprite
+///
+/// Return true if the global bounds of the sprites overlap
+pub fn aabb_test(a: &Sprite, b: &Sprite) -> bool {
+    intersection(&a.get_global_bounds(), &b.get_global_bounds()).is_some()
+}
+
+/// Test two rectangles, each with a transform, for overlapping
+///
+/// The separating axis theorem is used, so the rectangles can be
+/// rotated and scaled.
+///
+/// # Arguments
+/// * rect_a - First rectangle, in local coordinates
+/// * transform_a - Transform of the first rectangle
+/// * rect_b - Second rectangle, in local coordinates
+/// * transform_b - Transform of the second rectangle
+///
+/// Return true if the transformed rectangles overlap
+pub fn obb_test_rects(rect_a: &FloatRect,
+                      transform_a: &Transform,
+                      rect_b: &FloatRect,
+                      transform_b: &Transform) -> bool {
+    let a = corners(rect_a, transform_a);
+    let b = corners(rect_b, transform_b);
+    let axes = [a[1] - a[0], a[3] - a[0], b[1] - b[0], b[3] - b[0]];
+    for axis in axes.iter() {
+        let (min_a, max_a) = project(&a, axis);
+        let (min_b, max_b) = project(&b, axis);
+        if max_a < min_b || max_b < min_a {
+            return false;
+        }
+    }
+    true
+}
+
+/// Test two sprites for overlapping oriented bounding boxes
+///
+/// # Arguments
+/// * a - First sprite
+/// * b - Second sprite
+///
+/// Return true if the oriented bounding boxes of the sprites overlap
+pub fn obb_test(a: &Sprite, b: &Sprite) -> bool {
+    obb_test_rects(&a.get_local_bounds(), &a.get_transform(),
+                   &b.get_local_bounds(), &b.get_transform())
+}
+
+/// Test two sprites for pixel perfect overlapping
+///
+/// The masks must be built from the textures of the sprites, the texture
+/// rectangles of the sprites are taken into account. The bounding boxes
+/// are tested first, so there is no need to call aabb_test before.
+///
+/// # Arguments
+/// * a - First sprite
+/// * mask_a - Mask of the texture of the first sprite
+/// * b - Second sprite
+/// * mask_b - Mask of the texture of the second sprite
+///
+/// Return true if a solid pixel of each sprite overlap
+pub fn pixel_perfect_test(a: &Sprite,
+                          mask_a: &BitMask,
+                          b: &Sprite,
+                          mask_b: &BitMask) -> bool {
+    let area = match intersection(&a.get_global_bounds(), &b.get_global_bounds()) {
+        Some(area) => area,
+        None => return false
+    };
+    let inverse_a = a.get_inverse_transform();
+    let inverse_b = b.get_inverse_transform();
+    let rect_a = a.get_texture_rect();
+    let rect_b = b.get_texture_rect();
+
+    let mut y = area.top.floor();
+    while y < area.top + area.height {
+        let mut x = area.left.floor();
+        while x < area.left + area.width {
+            // Test the center of the pixel
+            let point = Vector2f::new(x + 0.5, y + 0.5);
+            let local_a = inverse_a.transform_point(&point);
+            let local_b = inverse_b.transform_point(&point);
+            if solid_in_rect(mask_a, &rect_a, &local_a) &&
+               solid_in_rect(mask_b, &rect_b, &local_b) {
+                return true;
+            }
+            x += 1.;
+        }
+        y += 1.;
+    }
+    false
+}
+
+fn solid_in_rect(mask: &BitMask, rect: &IntRect, local: &Vector2f) -> bool {
+    let x = local.x.floor() as int;
+    let y = local.y.floor() as int;
+    if x < 0 || y < 0 || x >= rect.width.abs() as int || y >= rect.height.abs() as int {
+        return false;
+    }
+    mask.is_solid(rect.left as int + x, rect.top as int + y)
+}
+
+fn corners(rect: &FloatRect, transform: &Transform) -> [Vector2f; 4] {
+    [transform.transform_point(&Vector2f::new(rect.left, rect.top)),
+     transform.transform_point(&Vector2f::new(rect.left + rect.width, rect.top)),
+     transform.transform_point(&Vector2f::new(rect.left + rect.width, rect.top + rect.height)),
+     transform.transform_point(&Vector2f::new(rect.left, rect.top + rect.height))]
+}
+
+fn project(points: &[Vector2f; 4], axis: &Vector2f) -> (f32, f32) {
+    let mut min = points[0].x * axis.x + points[0].y * axis.y;
+    let mut max = min;
+    for point in points.iter().skip(1) {
+        let d = point.x * axis.x + point.y * axis.y;
+        min = min.min(d);
+        max = max.max(d);
+    }
+    (min, max)
+}
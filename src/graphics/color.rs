@@ -28,7 +28,8 @@
 //!
 //! Color is a simple color class composed of 4 components: Red, Green, Blue, Alpha
 
-use std::ops::{Add, Mul};
+use std::ops::{Add, Sub, Mul};
+use std::num::Float;
 
 use ffi::graphics::color as ffi;
 
@@ -107,6 +108,102 @@ impl Color {
         unsafe {ffi::sfColor_modulate(color1, color2)}
     }
 
+    /// Subtract two colors
+    ///
+    /// # Arguments
+    /// * color1 - The first color
+    /// * color2 - The second color
+    ///
+    /// Return the component-wise saturated subtraction of the two colors
+    pub fn subtract(color1: Color, color2: Color) -> Color {
+        color1 - color2
+    }
+
+    /// Construct a color from its HSV components
+    ///
+    /// # Arguments
+    /// * hue - Hue, in degrees (0 .. 360)
+    /// * saturation - Saturation (0 .. 1)
+    /// * value - Value (0 .. 1)
+    ///
+    /// Return Color object constructed from the components, fully opaque
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let hue = ((hue % 360.) + 360.) % 360.;
+        let saturation = saturation.max(0.).min(1.);
+        let value = value.max(0.).min(1.);
+
+        let chroma = value * saturation;
+        let x = chroma * (1. - ((hue / 60.) % 2. - 1.).abs());
+        let m = value - chroma;
+        let (r, g, b) = match (hue / 60.) as uint {
+            0 => (chroma, x, 0.),
+            1 => (x, chroma, 0.),
+            2 => (0., chroma, x),
+            3 => (0., x, chroma),
+            4 => (x, 0., chroma),
+            _ => (chroma, 0., x)
+        };
+        Color::new_RGB(((r + m) * 255.).round() as u8,
+                       ((g + m) * 255.).round() as u8,
+                       ((b + m) * 255.).round() as u8)
+    }
+
+    /// Convert a color to its HSV components
+    ///
+    /// The alpha component is ignored.
+    ///
+    /// Return a tuple (hue, saturation, value), with hue in degrees (0 .. 360)
+    /// and saturation and value between 0 and 1
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let r = self.red as f32 / 255.;
+        let g = self.green as f32 / 255.;
+        let b = self.blue as f32 / 255.;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0. {
+            0.
+        } else if max == r {
+            60. * (((g - b) / delta) % 6.)
+        } else if max == g {
+            60. * ((b - r) / delta + 2.)
+        } else {
+            60. * ((r - g) / delta + 4.)
+        };
+        let saturation = if max == 0. { 0. } else { delta / max };
+        (if hue < 0. { hue + 360. } else { hue }, saturation, max)
+    }
+
+    /// Linearly interpolate between two colors
+    ///
+    /// # Arguments
+    /// * color1 - The color for t = 0
+    /// * color2 - The color for t = 1
+    /// * t - Interpolation factor, clamped between 0 and 1
+    ///
+    /// Return the interpolated color, alpha included
+    pub fn lerp(color1: Color, color2: Color, t: f32) -> Color {
+        let t = t.max(0.).min(1.);
+        let mix = |&: a: u8, b: u8| -> u8 {
+            (a as f32 + (b as f32 - a as f32) * t).round() as u8
+        };
+        Color::new_RGBA(mix(color1.red, color2.red),
+                        mix(color1.green, color2.green),
+                        mix(color1.blue, color2.blue),
+                        mix(color1.alpha, color2.alpha))
+    }
+
+    /// Copy a color with another alpha component
+    ///
+    /// # Arguments
+    /// * alpha - The new alpha component (0 .. 255)
+    ///
+    /// Return the color with its alpha component replaced
+    pub fn with_alpha(&self, alpha: u8) -> Color {
+        Color::new_RGBA(self.red, self.green, self.blue, alpha)
+    }
+
     /// Black predefined color
     pub fn black() -> Color {
         Color::new_RGB(0, 0, 0)
@@ -171,19 +268,36 @@ impl Add for Color {
     }
 }
 
+impl Sub for Color {
+    type Output = Color;
+
+    fn sub(self, other: Color) -> Color {
+        let r: i32 = self.red as i32 - other.red as i32;
+        let g: i32 = self.green as i32 - other.green as i32;
+        let b: i32 = self.blue as i32 - other.blue as i32;
+        let a: i32 = self.alpha as i32 - other.alpha as i32;
+        Color {
+            red: if r < 0 {0} else {r as u8},
+            green: if g < 0 {0} else {g as u8},
+            blue: if b < 0 {0} else {b as u8},
+            alpha: if a < 0 {0} else {a as u8}
+        }
+    }
+}
+
 impl Mul for Color {
     type Output = Color;
 
     fn mul(self, other: Color) -> Color {
-        let r: i32 = self.red as i32 * (other.red as i32);
-        let g: i32 = self.green as i32 * (other.green as i32);
-        let b: i32 = self.blue as i32 * (other.blue as i32);
-        let a: i32 = self.alpha as i32 * (other.alpha as i32);
+        let r: i32 = self.red as i32 * (other.red as i32) / 255;
+        let g: i32 = self.green as i32 * (other.green as i32) / 255;
+        let b: i32 = self.blue as i32 * (other.blue as i32) / 255;
+        let a: i32 = self.alpha as i32 * (other.alpha as i32) / 255;
         Color {
-            red: if r > 255 {255} else {r as u8},
-            green: if g > 255 {255} else {g as u8},
-            blue: if b > 255 {255} else {b as u8},
-            alpha: if a > 255 {255} else {a as u8}
+            red: r as u8,
+            green: g as u8,
+            blue: b as u8,
+            alpha: a as u8
         }
     }
 }
@@ -31,7 +31,7 @@
 //! Moreover, the points must be defined in order; using a random
 //! order would result in an incorrect shape.
 
-use libc::{c_float, c_uint};
+use libc::{c_float, size_t};
 use std::ptr;
 
 use traits::{Wrappable, Drawable};
@@ -77,7 +77,7 @@ impl<'s> ConvexShape<'s> {
             None
         } else {
             unsafe {
-                ffi::sfConvexShape_setPointCount(shape, points_count as c_uint);
+                ffi::sfConvexShape_setPointCount(shape, points_count as size_t);
             }
             Some(ConvexShape {
                     convex_shape: shape,
@@ -101,7 +101,7 @@ impl<'s> ConvexShape<'s> {
         } else {
             unsafe {
                 ffi::sfConvexShape_setTexture(shape, texture.unwrap(), SFTRUE);
-                ffi::sfConvexShape_setPointCount(shape, points_count as c_uint)
+                ffi::sfConvexShape_setPointCount(shape, points_count as size_t)
             }
             Some(ConvexShape {
                     convex_shape: shape,
@@ -287,7 +287,7 @@ impl<'s> ConvexShape<'s> {
     pub fn set_point(&mut self, index: uint, point: &Vector2f) -> () {
         unsafe {
             ffi::sfConvexShape_setPoint(self.convex_shape,
-                                        index as c_uint, *point)
+                                        index as size_t, *point)
         }
     }
 
@@ -328,7 +328,7 @@ impl<'s> ConvexShape<'s> {
     /// Return the index-th point of the shape
     pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
-            ffi::sfConvexShape_getPoint(self.convex_shape, index as c_uint)
+            ffi::sfConvexShape_getPoint(self.convex_shape, index as size_t)
         }
     }
 
@@ -340,7 +340,7 @@ impl<'s> ConvexShape<'s> {
     ///
     /// # Arguments
     /// * rotation - New rotation
-    pub fn set_rotation(&self, angle: f32) -> () {
+    pub fn set_rotation(&mut self, angle: f32) -> () {
         unsafe {
             ffi::sfConvexShape_setRotation(self.convex_shape, angle as c_float)
         }
@@ -509,7 +509,7 @@ impl<'s> ConvexShape<'s> {
     /// * count - New number of points of the convex
     pub fn set_point_count(&mut self, count: uint) -> () {
         unsafe {
-            ffi::sfConvexShape_setPointCount(self.convex_shape, count as c_uint)
+            ffi::sfConvexShape_setPointCount(self.convex_shape, count as size_t)
         }
     }
 
@@ -615,7 +615,7 @@ impl Iterator for ConvexShapePoints {
             self.pos += 1;
             unsafe {
                 Some(ffi::sfConvexShape_getPoint(self.convex_shape,
-                                                 self.pos as c_uint))
+                                                 self.pos as size_t))
             }
         }
     }
@@ -637,6 +637,8 @@ impl<'s> Wrappable<*mut ffi::sfConvexShape> for ConvexShape<'s> {
     }
 }
 
+impl_transformable!(ConvexShape<'s>, 's);
+
 impl<'s> Drawable for ConvexShape<'s> {
     fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_convex_shape(self)
@@ -33,7 +33,7 @@
 
 use std::rc::Rc;
 use std::cell::RefCell;
-use libc::{c_float, c_uint};
+use libc::{c_float, size_t};
 use std::ptr;
 
 use traits::{Wrappable, Drawable};
@@ -78,7 +78,7 @@ impl ConvexShape {
         }
         else {
             unsafe {
-                ffi::sfConvexShape_setPointCount(shape, points_count as c_uint);
+                ffi::sfConvexShape_setPointCount(shape, points_count as size_t);
             }
             Some(ConvexShape {
                 convex_shape: shape,
@@ -102,7 +102,7 @@ impl ConvexShape {
         } else {
             unsafe {
                 ffi::sfConvexShape_setTexture(shape, (*texture).borrow().unwrap(), SFTRUE);
-                ffi::sfConvexShape_setPointCount(shape, points_count as c_uint)
+                ffi::sfConvexShape_setPointCount(shape, points_count as size_t)
             }
             Some(ConvexShape {
                     convex_shape: shape,
@@ -288,7 +288,7 @@ impl ConvexShape {
     pub fn set_point(&mut self, index: uint, point: &Vector2f) -> () {
         unsafe {
             ffi::sfConvexShape_setPoint(self.convex_shape,
-                                        index as c_uint, *point)
+                                        index as size_t, *point)
         }
     }
 
@@ -329,7 +329,7 @@ impl ConvexShape {
     /// Return the index-th point of the shape
     pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
-            ffi::sfConvexShape_getPoint(self.convex_shape, index as c_uint)
+            ffi::sfConvexShape_getPoint(self.convex_shape, index as size_t)
         }
     }
 
@@ -341,7 +341,7 @@ impl ConvexShape {
     ///
     /// # Arguments
     /// * rotation - New rotation
-    pub fn set_rotation(&self, angle: f32) -> () {
+    pub fn set_rotation(&mut self, angle: f32) -> () {
         unsafe {
             ffi::sfConvexShape_setRotation(self.convex_shape, angle as c_float)
         }
@@ -508,7 +508,7 @@ impl ConvexShape {
     /// * count - New number of points of the convex
     pub fn set_point_count(&mut self, count: uint) -> () {
         unsafe {
-            ffi::sfConvexShape_setPointCount(self.convex_shape, count as c_uint)
+            ffi::sfConvexShape_setPointCount(self.convex_shape, count as size_t)
         }
     }
 
@@ -615,7 +615,7 @@ impl Iterator for ConvexShapePoints {
             self.pos += 1;
             unsafe {
                 Some(ffi::sfConvexShape_getPoint(self.convex_shape,
-                                                 self.pos as c_uint))
+                                                 self.pos as size_t))
             }
         }
     }
@@ -637,6 +637,8 @@ impl Wrappable<*mut ffi::sfConvexShape> for ConvexShape {
     }
 }
 
+impl_transformable!(ConvexShape);
+
 impl Drawable for ConvexShape {
     fn draw<RT: RenderTarget>(&self, render_target: &mut RT) {
         render_target.draw_convex_shape_rc(self)
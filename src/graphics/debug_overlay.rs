@@ -0,0 +1,294 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! On screen diagnostics
+//!
+//! DebugOverlay draws the frame rate, the frame time, a graph of the last
+//! frame times, the number of draw calls and custom lines, with the
+//! built-in BitmapFont so no font file is needed.
+
+use std::num::Float;
+
+use traits::Drawable;
+use graphics::{BitmapFont, BitmapText, Color, RenderTarget, RenderStates,
+               Vertex, Quads};
+use system::vector2::Vector2f;
+use system::Time;
+
+/// Number of frame times kept for the graph
+static GRAPH_SAMPLES: uint = 120;
+
+/// Frame time at the top of the graph, in milliseconds
+static GRAPH_MAX_MS: f32 = 50.;
+
+/// On screen diagnostics
+///
+/// Call update once per frame, then draw the overlay last so it shows
+/// on top of the scene. The overlay is drawn in window coordinates,
+/// whatever the current view of the target.
+pub struct DebugOverlay {
+    #[doc(hidden)]
+    font: BitmapFont,
+    #[doc(hidden)]
+    visible: bool,
+    #[doc(hidden)]
+    position: Vector2f,
+    #[doc(hidden)]
+    scale: f32,
+    #[doc(hidden)]
+    frame_times: Vec<f32>,
+    #[doc(hidden)]
+    draw_calls: Option<uint>,
+    #[doc(hidden)]
+    lines: Vec<(String, String)>
+}
+
+impl DebugOverlay {
+    /// Create a new DebugOverlay
+    ///
+    /// The overlay is visible, at the top left corner of the window.
+    ///
+    /// Return Some(DebugOverlay) or None if the built-in font can't be created
+    pub fn new() -> Option<DebugOverlay> {
+        match BitmapFont::new_builtin() {
+            Some(font) => Some(DebugOverlay {
+                font: font,
+                visible: true,
+                position: Vector2f::new(4., 4.),
+                scale: 2.,
+                frame_times: Vec::with_capacity(GRAPH_SAMPLES),
+                draw_calls: None,
+                lines: Vec::new()
+            }),
+            None => None
+        }
+    }
+
+    /// Show the overlay if it is hidden, hide it otherwise
+    pub fn toggle(&mut self) -> () {
+        self.visible = !self.visible
+    }
+
+    /// Show or hide the overlay
+    ///
+    /// # Arguments
+    /// * visible - true to show the overlay, false to hide it
+    pub fn set_visible(&mut self, visible: bool) -> () {
+        self.visible = visible
+    }
+
+    /// Tell whether the overlay is visible
+    ///
+    /// Return true if the overlay is visible, false otherwise
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Set the position of the overlay, in window coordinates
+    ///
+    /// # Arguments
+    /// * position - The position of the top left corner
+    pub fn set_position(&mut self, position: &Vector2f) -> () {
+        self.position = *position
+    }
+
+    /// Get the position of the overlay
+    ///
+    /// Return the position of the top left corner
+    pub fn get_position(&self) -> Vector2f {
+        self.position
+    }
+
+    /// Set the scale of the text
+    ///
+    /// # Arguments
+    /// * scale - The scale of the built-in font, 2 by default
+    pub fn set_scale(&mut self, scale: f32) -> () {
+        self.scale = scale
+    }
+
+    /// Get the scale of the text
+    ///
+    /// Return the scale
+    pub fn get_scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Record the duration of a frame
+    ///
+    /// # Arguments
+    /// * frame_time - Time elapsed since the last update
+    pub fn update(&mut self, frame_time: &Time) -> () {
+        if self.frame_times.len() == GRAPH_SAMPLES {
+            self.frame_times.remove(0);
+        }
+        self.frame_times.push(frame_time.as_seconds() * 1000.)
+    }
+
+    /// Set the number of draw calls of the frame
+    ///
+    /// The line is only shown once a count has been given.
+    ///
+    /// # Arguments
+    /// * draw_calls - The number of draw calls
+    pub fn set_draw_calls(&mut self, draw_calls: uint) -> () {
+        self.draw_calls = Some(draw_calls)
+    }
+
+    /// Set a custom line
+    ///
+    /// The line is shown as "key: value", setting an existing key
+    /// replaces its value.
+    ///
+    /// # Arguments
+    /// * key - The name of the value
+    /// * value - The value
+    pub fn set_line(&mut self, key: &str, value: &str) -> () {
+        for &mut (ref name, ref mut old) in self.lines.iter_mut() {
+            if name.as_slice() == key {
+                *old = value.to_string();
+                return;
+            }
+        }
+        self.lines.push((key.to_string(), value.to_string()))
+    }
+
+    /// Remove a custom line
+    ///
+    /// # Arguments
+    /// * key - The name of the value
+    pub fn remove_line(&mut self, key: &str) -> () {
+        self.lines.retain(|&(ref name, _)| name.as_slice() != key)
+    }
+
+    /// Remove all the custom lines
+    pub fn clear_lines(&mut self) -> () {
+        self.lines.clear()
+    }
+
+    /// Get the average frame time of the recorded frames
+    ///
+    /// Return the average frame time, in milliseconds
+    pub fn get_average_frame_time(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            0.
+        } else {
+            self.frame_times.iter().fold(0., |sum, t| sum + *t) / self.frame_times.len() as f32
+        }
+    }
+
+    /// Get the average frame rate of the recorded frames
+    ///
+    /// Return the number of frames per second
+    pub fn get_fps(&self) -> f32 {
+        let average = self.get_average_frame_time();
+        if average > 0. { 1000. / average } else { 0. }
+    }
+
+    #[doc(hidden)]
+    fn text_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        lines.push(format!("FPS: {:.1}", self.get_fps()));
+        lines.push(format!("Frame: {:.2} ms", self.get_average_frame_time()));
+        match self.draw_calls {
+            Some(count) => lines.push(format!("Draw calls: {}", count)),
+            None => ()
+        }
+        for &(ref key, ref value) in self.lines.iter() {
+            lines.push(format!("{}: {}", key, value));
+        }
+        lines
+    }
+}
+
+fn push_rect(vertices: &mut Vec<Vertex>,
+             left: f32,
+             top: f32,
+             width: f32,
+             height: f32,
+             color: &Color) -> () {
+    vertices.push(Vertex::new_with_pos_color(&Vector2f::new(left, top), color));
+    vertices.push(Vertex::new_with_pos_color(&Vector2f::new(left + width, top), color));
+    vertices.push(Vertex::new_with_pos_color(&Vector2f::new(left + width, top + height), color));
+    vertices.push(Vertex::new_with_pos_color(&Vector2f::new(left, top + height), color));
+}
+
+impl Drawable for DebugOverlay {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        if !self.visible {
+            return;
+        }
+        let previous = render_target.get_view().clone();
+        let default = render_target.get_default_view();
+        render_target.set_view(&default);
+
+        let line_height = self.font.get_line_height() * self.scale;
+        let mut texts = Vec::new();
+        let mut width = GRAPH_SAMPLES as f32;
+        let mut y = self.position.y + 4.;
+        for line in self.text_lines().iter() {
+            let mut text = BitmapText::new(line.as_slice(), &self.font);
+            text.set_scale(self.scale);
+            text.set_position2f(self.position.x + 4., y);
+            width = width.max(text.get_global_bounds().width);
+            y += line_height;
+            texts.push(text);
+        }
+        let graph_height = 3. * line_height;
+        let graph_top = y + 4.;
+
+        let mut vertices = Vec::new();
+        push_rect(&mut vertices,
+                  self.position.x,
+                  self.position.y,
+                  width + 8.,
+                  graph_top + graph_height + 4. - self.position.y,
+                  &Color::new_RGBA(0, 0, 0, 160));
+        let bar_width = width / GRAPH_SAMPLES as f32;
+        for (i, ms) in self.frame_times.iter().enumerate() {
+            let height = graph_height * ms.min(GRAPH_MAX_MS) / GRAPH_MAX_MS;
+            let color = if *ms > 1000. / 30. {
+                Color::red()
+            } else if *ms > 1000. / 60. {
+                Color::yellow()
+            } else {
+                Color::green()
+            };
+            push_rect(&mut vertices,
+                      self.position.x + 4. + i as f32 * bar_width,
+                      graph_top + graph_height - height,
+                      bar_width,
+                      height,
+                      &color);
+        }
+        let mut rs = RenderStates::default();
+        render_target.draw_primitives_rs(vertices.as_slice(), Quads, &mut rs);
+        for text in texts.iter() {
+            render_target.draw(text);
+        }
+
+        render_target.set_view(&previous)
+    }
+}
@@ -25,12 +25,15 @@
 
 //! Class for loading and manipulating character fonts
 
-use libc::{c_uint, size_t};
+use libc::{c_uint, c_float, size_t};
 use std::ptr;
+use std::rc::Rc;
 use std::ffi::CString;
 
 use traits::Wrappable;
 use graphics::{Texture, Glyph};
+use system::InputStream;
+use system::input_stream::OwnedInputStream;
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::graphics::font as ffi;
@@ -40,7 +43,11 @@ pub struct Font {
     #[doc(hidden)]
     font: *mut ffi::sfFont,
     #[doc(hidden)]
-    dropable: bool
+    dropable: bool,
+    #[doc(hidden)]
+    stream: Option<Rc<OwnedInputStream>>,
+    #[doc(hidden)]
+    memory: Option<Rc<Vec<u8>>>
 }
 
 impl Font {
@@ -61,27 +68,59 @@ impl Font {
         } else {
             Some(Font {
                     font: fnt,
-                    dropable: true
+                    dropable: true,
+                    stream: None,
+                    memory: None
                 })
         }
     }
 
     /// Create a new font from memory
     ///
+    /// The font keeps reading its data while it is used, so the data
+    /// is copied and kept alive by the font (and shared with its copies).
+    ///
     /// # Arguments
     /// * memory -  The in-memory font file
     ///
     /// Return Some(Font) or None
     pub fn new_from_memory(memory: &[u8]) -> Option<Font> {
+        let memory = Rc::new(memory.to_vec());
         let fnt = unsafe {
-            ffi::sfFont_createFromMemory(&memory[0], memory.len() as size_t)
+            ffi::sfFont_createFromMemory(memory.as_ptr(), memory.len() as size_t)
         };
         if fnt.is_null() {
             None
         } else {
             Some(Font {
                     font: fnt,
-                    dropable: true
+                    dropable: true,
+                    stream: None,
+                    memory: Some(memory)
+                })
+        }
+    }
+
+    /// Create a new font from a custom stream
+    ///
+    /// The font keeps reading from the stream while it is used, so
+    /// the stream is owned by the font (and shared with its copies).
+    ///
+    /// # Arguments
+    /// * stream - Source stream to read from
+    ///
+    /// Return Some(Font) or None
+    pub fn new_from_stream(stream: Box<InputStream + 'static>) -> Option<Font> {
+        let mut stream = OwnedInputStream::new(stream);
+        let fnt = unsafe { ffi::sfFont_createFromStream(stream.unwrap()) };
+        if fnt.is_null() {
+            None
+        } else {
+            Some(Font {
+                    font: fnt,
+                    dropable: true,
+                    stream: Some(Rc::new(stream)),
+                    memory: None
                 })
         }
     }
@@ -99,11 +138,26 @@ impl Font {
         } else {
             Some(Font {
                     font: fnt,
-                    dropable: true
+                    dropable: true,
+                    stream: self.stream.clone(),
+                    memory: self.memory.clone()
                 })
         }
     }
 
+    /// Determine if a font has a glyph for the given code point
+    ///
+    /// # Arguments
+    /// * codepoint - Unicode code point of the character to check
+    ///
+    /// Return true if the font has a glyph for the given code point
+    pub fn has_glyph(&self, codepoint: u32) -> bool {
+        match unsafe { ffi::sfFont_hasGlyph(self.font, codepoint) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
     /// Get the kerning value corresponding to a given pair of characters in a font
     ///
     /// # Arguments
@@ -115,12 +169,12 @@ impl Font {
     pub fn get_kerning(&self,
                        first: u32,
                        second: u32,
-                       character_size: uint) -> int {
+                       character_size: uint) -> f32 {
         unsafe {
             ffi::sfFont_getKerning(self.font,
                                    first,
                                    second,
-                                   character_size as c_uint) as int
+                                   character_size as c_uint) as f32
         }
     }
 
@@ -130,10 +184,41 @@ impl Font {
     /// * characterSize - Character size, in pixels
     ///
     /// Return the line spacing, in pixels
-    pub fn get_line_spacing(&self, character_size: uint) -> int {
+    pub fn get_line_spacing(&self, character_size: uint) -> f32 {
         unsafe {
             ffi::sfFont_getLineSpacing(self.font,
-                                       character_size as c_uint) as int
+                                       character_size as c_uint) as f32
+        }
+    }
+
+    /// Get the position of the underline
+    ///
+    /// Underline position is the vertical offset to apply between the
+    /// baseline and the underline.
+    ///
+    /// # Arguments
+    /// * characterSize - Character size, in pixels
+    ///
+    /// Return the underline position, in pixels
+    pub fn get_underline_position(&self, character_size: uint) -> f32 {
+        unsafe {
+            ffi::sfFont_getUnderlinePosition(self.font,
+                                             character_size as c_uint) as f32
+        }
+    }
+
+    /// Get the thickness of the underline
+    ///
+    /// Underline thickness is the vertical size of the underline.
+    ///
+    /// # Arguments
+    /// * characterSize - Character size, in pixels
+    ///
+    /// Return the underline thickness, in pixels
+    pub fn get_underline_thickness(&self, character_size: uint) -> f32 {
+        unsafe {
+            ffi::sfFont_getUnderlineThickness(self.font,
+                                              character_size as c_uint) as f32
         }
     }
 
@@ -159,22 +244,26 @@ impl Font {
     /// * codePoint - Unicode code point of the character to get
     /// * characterSize - Character size, in pixels
     /// * bold - Retrieve the bold version or the regular one?
+    /// * outline_thickness - Thickness of outline (when != 0 the glyph will not be filled)
     ///
     /// Return the corresponding glyph
     pub fn get_glyph(&self,
                      codepoint: u32,
                      character_size: uint,
-                     bold: bool) -> Glyph {
+                     bold: bool,
+                     outline_thickness: f32) -> Glyph {
         unsafe {
             match bold {
                 true        => ffi::sfFont_getGlyph(self.font,
                                                     codepoint,
                                                     character_size as c_uint,
-                                                    SFFALSE),
+                                                    SFTRUE,
+                                                    outline_thickness as c_float),
                 false       => ffi::sfFont_getGlyph(self.font,
                                                     codepoint,
                                                     character_size as c_uint,
-                                                    SFTRUE)
+                                                    SFFALSE,
+                                                    outline_thickness as c_float)
             }
         }
     }
@@ -189,7 +278,9 @@ impl Clone for Font {
         } else {
             Font {
                 font: fnt,
-                dropable: true
+                dropable: true,
+                stream: self.stream.clone(),
+                memory: self.memory.clone()
             }
         }
     }
@@ -199,7 +290,9 @@ impl Wrappable<*mut ffi::sfFont> for Font {
     fn wrap(font: *mut ffi::sfFont) -> Font {
         Font {
             font: font,
-            dropable: false
+            dropable: false,
+            stream: None,
+            memory: None
         }
     }
     fn unwrap(&self) -> *mut ffi::sfFont {
@@ -0,0 +1,129 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Chain of fonts used to resolve missing glyphs
+//!
+//! A FontFallback holds a primary font followed by secondary fonts
+//! (for example an emoji or a CJK font). Each character is resolved
+//! from the first font of the chain which has a glyph for it.
+
+use graphics::{Font, Glyph};
+
+/// Chain of fonts used to resolve missing glyphs
+///
+/// As a Text can only use one font, a string is split into runs of
+/// characters resolved from the same font with split_runs, and each
+/// run can then be drawn with its own Text.
+pub struct FontFallback<'f> {
+    #[doc(hidden)]
+    fonts: Vec<&'f Font>
+}
+
+impl<'f> FontFallback<'f> {
+    /// Create a new FontFallback
+    ///
+    /// # Arguments
+    /// * primary - The font used whenever it has the requested glyph
+    ///
+    /// Return a new FontFallback
+    pub fn new(primary: &'f Font) -> FontFallback<'f> {
+        FontFallback {
+            fonts: vec![primary]
+        }
+    }
+
+    /// Add a font at the end of the fallback chain
+    ///
+    /// # Arguments
+    /// * font - Font to search when the previous ones miss a glyph
+    pub fn push_fallback(&mut self, font: &'f Font) -> () {
+        self.fonts.push(font)
+    }
+
+    /// Get the fonts of the chain, the primary font first
+    pub fn get_fonts(&self) -> &[&'f Font] {
+        self.fonts.as_slice()
+    }
+
+    /// Find the font used to draw a character
+    ///
+    /// If no font of the chain has a glyph for the character,
+    /// the primary font is returned.
+    ///
+    /// # Arguments
+    /// * codepoint - Unicode code point of the character
+    ///
+    /// Return the font providing the glyph
+    pub fn find_font(&self, codepoint: u32) -> &'f Font {
+        match self.fonts.iter().find(|font| font.has_glyph(codepoint)) {
+            Some(font) => *font,
+            None       => self.fonts[0]
+        }
+    }
+
+    /// Get a glyph, resolved through the fallback chain
+    ///
+    /// # Arguments
+    /// * codepoint - Unicode code point of the character to get
+    /// * character_size - Character size, in pixels
+    /// * bold - Retrieve the bold version or the regular one?
+    /// * outline_thickness - Thickness of outline
+    ///
+    /// Return the corresponding glyph
+    pub fn get_glyph(&self,
+                     codepoint: u32,
+                     character_size: uint,
+                     bold: bool,
+                     outline_thickness: f32) -> Glyph {
+        self.find_font(codepoint).get_glyph(codepoint,
+                                            character_size,
+                                            bold,
+                                            outline_thickness)
+    }
+
+    /// Split a string into runs of characters using the same font
+    ///
+    /// # Arguments
+    /// * string - The string to split
+    ///
+    /// Return the runs, in order, with the font to draw each of them
+    pub fn split_runs(&self, string: &str) -> Vec<(&'f Font, String)> {
+        let mut runs: Vec<(&'f Font, String)> = Vec::new();
+        for c in string.chars() {
+            let font = self.find_font(c as u32);
+            let same_font = match runs.last() {
+                Some(&(last, _)) => last as *const Font == font as *const Font,
+                None             => false
+            };
+            if same_font {
+                runs.last_mut().unwrap().1.push(c);
+            } else {
+                let mut run = String::new();
+                run.push(c);
+                runs.push((font, run));
+            }
+        }
+        runs
+    }
+}
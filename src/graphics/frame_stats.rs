@@ -0,0 +1,144 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Rendering statistics of a frame
+//!
+//! The render targets count what is submitted to them between two calls
+//! to display, so performance regressions can be detected from the code.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use traits::Wrappable;
+use graphics::{Texture, Shader, rc};
+
+/// Rendering statistics of a frame
+#[derive(Clone, PartialEq, Eq, Show, Copy)]
+pub struct FrameStats {
+    /// Number of draw calls
+    pub draw_calls: uint,
+    /// Number of vertices submitted, estimated for texts and shapes
+    pub vertices: uint,
+    /// Number of times the texture changed between two draw calls
+    pub texture_switches: uint,
+    /// Number of times a shader was bound
+    pub shader_binds: uint
+}
+
+impl FrameStats {
+    /// Create empty statistics
+    ///
+    /// Return a new FrameStats with every counter set to 0
+    pub fn new() -> FrameStats {
+        FrameStats {
+            draw_calls: 0,
+            vertices: 0,
+            texture_switches: 0,
+            shader_binds: 0
+        }
+    }
+}
+
+/// Accumulate the statistics of the frame being drawn
+///
+/// The drawing functions of the render targets take &self, the counters
+/// are thus kept in cells.
+#[doc(hidden)]
+pub struct StatsRecorder {
+    current: Cell<FrameStats>,
+    last: Cell<FrameStats>,
+    texture: Cell<uint>,
+    shader: Cell<uint>
+}
+
+impl StatsRecorder {
+    #[doc(hidden)]
+    pub fn new() -> StatsRecorder {
+        StatsRecorder {
+            current: Cell::new(FrameStats::new()),
+            last: Cell::new(FrameStats::new()),
+            texture: Cell::new(0),
+            shader: Cell::new(0)
+        }
+    }
+
+    /// Count a draw call, the texture and the shader being identified by
+    /// the address of their CSFML object, 0 for none
+    #[doc(hidden)]
+    pub fn record(&self, vertices: uint, texture: uint, shader: uint) -> () {
+        let mut stats = self.current.get();
+        stats.draw_calls += 1;
+        stats.vertices += vertices;
+        if texture != self.texture.get() {
+            stats.texture_switches += 1;
+            self.texture.set(texture);
+        }
+        if shader != 0 && shader != self.shader.get() {
+            stats.shader_binds += 1;
+        }
+        self.shader.set(shader);
+        self.current.set(stats)
+    }
+
+    /// Close the current frame
+    #[doc(hidden)]
+    pub fn end_frame(&self) -> () {
+        self.last.set(self.current.get());
+        self.current.set(FrameStats::new());
+        self.texture.set(0);
+        self.shader.set(0)
+    }
+
+    #[doc(hidden)]
+    pub fn get_current(&self) -> FrameStats {
+        self.current.get()
+    }
+
+    #[doc(hidden)]
+    pub fn get_last(&self) -> FrameStats {
+        self.last.get()
+    }
+}
+
+#[doc(hidden)]
+pub fn texture_key(texture: Option<&Texture>) -> uint {
+    texture.map_or(0, |texture| texture.unwrap() as uint)
+}
+
+#[doc(hidden)]
+pub fn texture_key_rc(texture: &Option<Rc<RefCell<Texture>>>) -> uint {
+    texture.as_ref().map_or(0, |texture| texture.borrow().unwrap() as uint)
+}
+
+#[doc(hidden)]
+pub fn shader_key(shader: Option<&Shader>) -> uint {
+    shader.map_or(0, |shader| shader.unwrap() as uint)
+}
+
+#[doc(hidden)]
+pub fn shader_key_rc(shader: &Option<Rc<RefCell<rc::Shader>>>) -> uint {
+    shader.as_ref().map_or(0, |shader| shader.borrow().unwrap() as uint)
+}
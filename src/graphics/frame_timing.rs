@@ -0,0 +1,171 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Timing statistics of the displayed frames
+//!
+//! FrameTiming measures the time between two calls to display and keeps
+//! the durations of the last frames, so a game can lower its quality
+//! settings when it can't keep up.
+
+use std::collections::RingBuf;
+use std::num::Float;
+use std::vec::Vec;
+
+use system::{Clock, Time};
+
+/// Usual refresh rates of monitors, in Hz
+static REFRESH_RATES: [f32; 10] = [30., 50., 60., 75., 90., 100., 120., 144., 165., 240.];
+
+/// Timing statistics of the displayed frames
+///
+/// Use RenderWindow::set_frame_timing_enabled to have a window measure
+/// its frames, or call tick once per frame.
+pub struct FrameTiming {
+    #[doc(hidden)]
+    clock: Clock,
+    #[doc(hidden)]
+    started: bool,
+    #[doc(hidden)]
+    capacity: uint,
+    #[doc(hidden)]
+    samples: RingBuf<f32>
+}
+
+impl FrameTiming {
+    /// Create a new FrameTiming
+    ///
+    /// # Arguments
+    /// * capacity - Number of frames the statistics are computed on
+    ///
+    /// Return a new FrameTiming without any frame measured
+    pub fn new(capacity: uint) -> FrameTiming {
+        FrameTiming {
+            clock: Clock::new(),
+            started: false,
+            capacity: if capacity == 0 { 1 } else { capacity },
+            samples: RingBuf::new()
+        }
+    }
+
+    /// Mark the end of a frame
+    ///
+    /// The first call only starts the measure.
+    pub fn tick(&mut self) -> () {
+        let elapsed = self.clock.restart().as_seconds();
+        if !self.started {
+            self.started = true;
+            return;
+        }
+        // A garbage measure would poison the statistics, and NaN can't be sorted
+        if !elapsed.is_finite() {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(elapsed)
+    }
+
+    /// Forget the measured frames
+    pub fn reset(&mut self) -> () {
+        self.started = false;
+        self.samples.clear()
+    }
+
+    /// Get the number of frames the statistics are computed on
+    pub fn get_sample_count(&self) -> uint {
+        self.samples.len()
+    }
+
+    /// Get the duration of the last frame
+    ///
+    /// Return the duration, zero if no frame was measured yet
+    pub fn get_last_frame_time(&self) -> Time {
+        Time::with_seconds(self.samples.back().map_or(0., |&sample| sample))
+    }
+
+    /// Get the average duration of the measured frames
+    ///
+    /// Return the average duration, zero if no frame was measured yet
+    pub fn get_average_frame_time(&self) -> Time {
+        Time::with_seconds(self.average())
+    }
+
+    /// Get the average framerate of the measured frames
+    ///
+    /// Return the number of frames per second, 0 if no frame was measured yet
+    pub fn get_average_fps(&self) -> f32 {
+        let average = self.average();
+        if average > 0. { 1. / average } else { 0. }
+    }
+
+    /// Get the framerate of the slowest 1% of the measured frames
+    ///
+    /// The "1% lows" show stutter an average hides.
+    ///
+    /// Return the number of frames per second, 0 if no frame was measured yet
+    pub fn get_one_percent_low_fps(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.;
+        }
+        let mut sorted: Vec<f32> = self.samples.iter().map(|&sample| sample).collect();
+        sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let count = (sorted.len() + 99) / 100;
+        let average = sorted.slice_to(count).iter().fold(0., |acc, &sample| acc + sample) /
+            count as f32;
+        if average > 0. { 1. / average } else { 0. }
+    }
+
+    /// Tell whether the frames seem to be synchronized with the monitor
+    ///
+    /// This is a guess: the frames are considered synchronized when their
+    /// durations barely vary and the framerate is close to a usual refresh
+    /// rate. A framerate limit set to such a rate looks the same.
+    ///
+    /// Return true if vertical synchronization looks active
+    pub fn is_vsync_likely(&self) -> bool {
+        if self.samples.len() < 10 {
+            return false;
+        }
+        let average = self.average();
+        let variance = self.samples.iter().fold(0., |acc, &sample| {
+            acc + (sample - average) * (sample - average)
+        }) / self.samples.len() as f32;
+        if variance.sqrt() > average * 0.05 {
+            return false;
+        }
+        let fps = 1. / average;
+        REFRESH_RATES.iter().any(|&rate| (fps - rate).abs() < rate * 0.03)
+    }
+
+    /// Average duration of the frames, in seconds
+    fn average(&self) -> f32 {
+        if self.samples.is_empty() {
+            0.
+        } else {
+            self.samples.iter().fold(0., |acc, &sample| acc + sample) / self.samples.len() as f32
+        }
+    }
+}
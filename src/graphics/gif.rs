@@ -0,0 +1,521 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Animated GIF loading
+//!
+//! Decode an animated GIF into one texture per frame along with the
+//! delay of each frame. GifAnimation implements tween::Animation for
+//! rc::Sprite so it can be played on its own or inside a Sequence.
+//!
+//! This module is only available with the "gif" feature.
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::io::File;
+
+use graphics::{Texture, Image};
+use graphics::rc::Sprite;
+use graphics::tween::Animation;
+use system::Time;
+
+/// Delay used for frames which don't specify one, like web browsers do
+static DEFAULT_DELAY: i32 = 100;
+
+/// Largest canvas or frame decoded, in pixels, as the sizes come from untrusted files
+static MAX_PIXELS: uint = 4096 * 4096;
+
+/// Largest total size of the decoded frames, in bytes
+static MAX_DECODED_SIZE: uint = 512 * 1024 * 1024;
+
+/// The frames of an animated GIF
+pub struct GifAnimation {
+    #[doc(hidden)]
+    frames: Vec<Rc<RefCell<Texture>>>,
+    #[doc(hidden)]
+    delays: Vec<Time>,
+    #[doc(hidden)]
+    width: uint,
+    #[doc(hidden)]
+    height: uint,
+    #[doc(hidden)]
+    current: uint,
+    #[doc(hidden)]
+    elapsed: f32,
+    #[doc(hidden)]
+    looping: bool,
+    #[doc(hidden)]
+    finished: bool
+}
+
+impl GifAnimation {
+    /// Load an animated GIF from a file
+    ///
+    /// # Arguments
+    /// * filename - Path of the GIF file to load
+    ///
+    /// Return Some(GifAnimation) or None
+    pub fn new_from_file(filename: &str) -> Option<GifAnimation> {
+        match File::open(&Path::new(filename)).read_to_end() {
+            Ok(data) => GifAnimation::new_from_memory(data.as_slice()),
+            Err(_)   => None
+        }
+    }
+
+    /// Load an animated GIF from a file in memory
+    ///
+    /// # Arguments
+    /// * mem - The file data in memory
+    ///
+    /// Return Some(GifAnimation) or None
+    pub fn new_from_memory(mem: &[u8]) -> Option<GifAnimation> {
+        let decoded = match decode(mem) {
+            Some(decoded) => decoded,
+            None          => return None
+        };
+        let mut frames = Vec::new();
+        let mut delays = Vec::new();
+        for &(ref pixels, delay) in decoded.frames.iter() {
+            let image = match Image::create_from_pixels(decoded.width,
+                                                        decoded.height,
+                                                        pixels.as_slice()) {
+                Some(image) => image,
+                None        => return None
+            };
+            match Texture::new_from_image(&image) {
+                Some(texture) => frames.push(Rc::new(RefCell::new(texture))),
+                None          => return None
+            }
+            delays.push(delay);
+        }
+        if frames.is_empty() {
+            return None;
+        }
+        Some(GifAnimation {
+                frames: frames,
+                delays: delays,
+                width: decoded.width,
+                height: decoded.height,
+                current: 0,
+                elapsed: 0.,
+                looping: true,
+                finished: false
+            })
+    }
+
+    /// Get the number of frames
+    pub fn get_frame_count(&self) -> uint {
+        self.frames.len()
+    }
+
+    /// Get the size of the frames
+    ///
+    /// Return (width, height) in pixels
+    pub fn get_size(&self) -> (uint, uint) {
+        (self.width, self.height)
+    }
+
+    /// Get the texture of a frame
+    ///
+    /// # Arguments
+    /// * index - Index of the frame
+    ///
+    /// Return Some(texture) or None if index is out of range
+    pub fn get_texture(&self, index: uint) -> Option<Rc<RefCell<Texture>>> {
+        self.frames.get(index).map(|texture| texture.clone())
+    }
+
+    /// Get the delay of a frame
+    ///
+    /// # Arguments
+    /// * index - Index of the frame
+    ///
+    /// Return Some(delay) or None if index is out of range
+    pub fn get_delay(&self, index: uint) -> Option<Time> {
+        self.delays.get(index).map(|delay| *delay)
+    }
+
+    /// Get the index of the frame currently displayed
+    pub fn get_current_frame(&self) -> uint {
+        self.current
+    }
+
+    /// Set whether the animation restarts once finished
+    ///
+    /// The animation loops by default.
+    ///
+    /// # Arguments
+    /// * looping - true to loop, false to stop on the last frame
+    pub fn set_loop(&mut self, looping: bool) -> () {
+        self.looping = looping
+    }
+
+    /// Tell whether the animation restarts once finished
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+}
+
+impl Animation<Sprite> for GifAnimation {
+    fn advance(&mut self, target: &mut Sprite, seconds: f32) -> f32 {
+        let mut left = 0.;
+        if !self.finished {
+            self.elapsed += seconds;
+            loop {
+                let delay = self.delays[self.current].as_seconds();
+                if self.elapsed < delay {
+                    break;
+                }
+                if self.current + 1 < self.frames.len() {
+                    self.elapsed -= delay;
+                    self.current += 1;
+                } else if self.looping {
+                    self.elapsed -= delay;
+                    self.current = 0;
+                } else {
+                    left = self.elapsed - delay;
+                    self.elapsed = delay;
+                    self.finished = true;
+                    break;
+                }
+            }
+        }
+        target.set_texture(self.frames[self.current].clone(), false);
+        left
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn reset(&mut self) -> () {
+        self.current = 0;
+        self.elapsed = 0.;
+        self.finished = false;
+    }
+}
+
+/// Frames composited to the full size of the GIF
+struct DecodedGif {
+    width: uint,
+    height: uint,
+    frames: Vec<(Vec<u8>, Time)>
+}
+
+/// Read a little endian u16
+fn read_u16(data: &[u8], pos: uint) -> Option<uint> {
+    if pos + 2 > data.len() {
+        None
+    } else {
+        Some(data[pos] as uint | (data[pos + 1] as uint << 8))
+    }
+}
+
+/// Concatenate the data sub-blocks starting at pos
+///
+/// Return the data and the position after the block terminator
+fn read_sub_blocks(data: &[u8], mut pos: uint) -> Option<(Vec<u8>, uint)> {
+    let mut out = Vec::new();
+    loop {
+        if pos >= data.len() {
+            return None;
+        }
+        let size = data[pos] as uint;
+        pos += 1;
+        if size == 0 {
+            return Some((out, pos));
+        }
+        if pos + size > data.len() {
+            return None;
+        }
+        out.push_all(data.slice(pos, pos + size));
+        pos += size;
+    }
+}
+
+/// Decode a whole GIF file
+fn decode(data: &[u8]) -> Option<DecodedGif> {
+    if data.len() < 13 || (!data.starts_with(b"GIF87a") && !data.starts_with(b"GIF89a")) {
+        return None;
+    }
+    let width = read_u16(data, 6).unwrap();
+    let height = read_u16(data, 8).unwrap();
+    if width == 0 || height == 0 || width * height > MAX_PIXELS {
+        return None;
+    }
+    let flags = data[10];
+    let mut pos = 13;
+    let mut global_table = Vec::new();
+    if flags & 0x80 != 0 {
+        let size = 3 * (1 << ((flags & 7) + 1) as uint);
+        if pos + size > data.len() {
+            return None;
+        }
+        global_table.push_all(data.slice(pos, pos + size));
+        pos += size;
+    }
+
+    let mut canvas = Vec::from_elem(width * height * 4, 0u8);
+    let mut frames = Vec::new();
+    let mut delay = 0;
+    let mut disposal = 0;
+    let mut transparent = None;
+    loop {
+        if pos >= data.len() {
+            break;
+        }
+        match data[pos] {
+            // Extension
+            0x21 => {
+                if pos + 1 >= data.len() {
+                    return None;
+                }
+                let label = data[pos + 1];
+                let (block, next) = match read_sub_blocks(data, pos + 2) {
+                    Some(result) => result,
+                    None         => return None
+                };
+                // Graphic control extension
+                if label == 0xF9 && block.len() >= 4 {
+                    disposal = (block[0] >> 2) & 7;
+                    delay = block[1] as i32 | (block[2] as i32 << 8);
+                    transparent = if block[0] & 1 != 0 { Some(block[3]) } else { None };
+                }
+                pos = next;
+            },
+            // Image descriptor
+            0x2C => {
+                if pos + 10 > data.len() {
+                    return None;
+                }
+                let left = read_u16(data, pos + 1).unwrap();
+                let top = read_u16(data, pos + 3).unwrap();
+                let frame_width = read_u16(data, pos + 5).unwrap();
+                let frame_height = read_u16(data, pos + 7).unwrap();
+                let frame_flags = data[pos + 9];
+                pos += 10;
+                if frame_width * frame_height > MAX_PIXELS {
+                    return None;
+                }
+                if (frames.len() + 1) * width * height * 4 > MAX_DECODED_SIZE {
+                    return None;
+                }
+                let mut local_table = Vec::new();
+                if frame_flags & 0x80 != 0 {
+                    let size = 3 * (1 << ((frame_flags & 7) + 1) as uint);
+                    if pos + size > data.len() {
+                        return None;
+                    }
+                    local_table.push_all(data.slice(pos, pos + size));
+                    pos += size;
+                }
+                if pos >= data.len() {
+                    return None;
+                }
+                let min_code_size = data[pos] as uint;
+                let (block, next) = match read_sub_blocks(data, pos + 1) {
+                    Some(result) => result,
+                    None         => return None
+                };
+                pos = next;
+                let indices = match decode_lzw(block.as_slice(),
+                                               min_code_size,
+                                               frame_width * frame_height) {
+                    Some(indices) => indices,
+                    None          => return None
+                };
+                let table = if local_table.is_empty() { &global_table } else { &local_table };
+
+                let previous = if disposal == 3 { Some(canvas.clone()) } else { None };
+                let rows = interlaced_rows(frame_height, frame_flags & 0x40 != 0);
+                for (i, &row) in rows.iter().enumerate() {
+                    let y = top + row;
+                    for column in range(0, frame_width) {
+                        let x = left + column;
+                        let index = indices[i * frame_width + column];
+                        if x >= width || y >= height || Some(index) == transparent {
+                            continue;
+                        }
+                        let entry = index as uint * 3;
+                        if entry + 2 >= table.len() {
+                            continue;
+                        }
+                        let pixel = (y * width + x) * 4;
+                        canvas[pixel] = table[entry];
+                        canvas[pixel + 1] = table[entry + 1];
+                        canvas[pixel + 2] = table[entry + 2];
+                        canvas[pixel + 3] = 255;
+                    }
+                }
+
+                let milliseconds = if delay <= 1 { DEFAULT_DELAY } else { delay * 10 };
+                frames.push((canvas.clone(), Time::with_milliseconds(milliseconds)));
+
+                match disposal {
+                    // Restore to background, which is transparent here
+                    2 => for y in range(top, (top + frame_height).min(height)) {
+                        for x in range(left, (left + frame_width).min(width)) {
+                            let pixel = (y * width + x) * 4;
+                            for c in range(0, 4) {
+                                canvas[pixel + c] = 0;
+                            }
+                        }
+                    },
+                    // Restore to previous
+                    3 => match previous {
+                        Some(previous) => canvas = previous,
+                        None           => ()
+                    },
+                    _ => ()
+                }
+                delay = 0;
+                disposal = 0;
+                transparent = None;
+            },
+            // Trailer
+            0x3B => break,
+            _ => return None
+        }
+    }
+
+    if frames.is_empty() {
+        None
+    } else {
+        Some(DecodedGif {
+                width: width,
+                height: height,
+                frames: frames
+            })
+    }
+}
+
+/// Get the order in which the rows of a frame are stored
+fn interlaced_rows(height: uint, interlaced: bool) -> Vec<uint> {
+    if !interlaced {
+        return range(0, height).collect();
+    }
+    let mut rows = Vec::with_capacity(height);
+    for &(start, step) in [(0u, 8u), (4, 8), (2, 4), (1, 2)].iter() {
+        let mut row = start;
+        while row < height {
+            rows.push(row);
+            row += step;
+        }
+    }
+    rows
+}
+
+/// Decode the LZW compressed color indices of a frame
+///
+/// The result always holds pixel_count indices, missing ones are zero.
+fn decode_lzw(data: &[u8], min_code_size: uint, pixel_count: uint) -> Option<Vec<u8>> {
+    if min_code_size < 1 || min_code_size > 8 {
+        return None;
+    }
+    let clear = 1u << min_code_size;
+    let end = clear + 1;
+    let mut prefix = Vec::from_elem(4096, 0u16);
+    let mut suffix = Vec::from_elem(4096, 0u8);
+    let mut code_size = min_code_size + 1;
+    let mut next = end + 1;
+    let mut previous: Option<uint> = None;
+    let mut bits = 0u32;
+    let mut bit_count = 0u;
+    let mut stack = Vec::new();
+    let mut out = Vec::with_capacity(pixel_count);
+
+    'outer: for &byte in data.iter() {
+        bits |= (byte as u32) << bit_count;
+        bit_count += 8;
+        while bit_count >= code_size {
+            let code = (bits & ((1 << code_size) - 1)) as uint;
+            bits >>= code_size;
+            bit_count -= code_size;
+
+            if code == clear {
+                code_size = min_code_size + 1;
+                next = end + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                break 'outer;
+            }
+            let prev = match previous {
+                Some(prev) => prev,
+                None => {
+                    if code >= clear {
+                        return None;
+                    }
+                    out.push(code as u8);
+                    previous = Some(code);
+                    continue;
+                }
+            };
+
+            let in_table = code < next;
+            let mut current = if in_table {
+                code
+            } else if code == next {
+                prev
+            } else {
+                return None;
+            };
+            while current > end {
+                stack.push(suffix[current]);
+                current = prefix[current] as uint;
+            }
+            let first = current as u8;
+            out.push(first);
+            loop {
+                match stack.pop() {
+                    Some(index) => out.push(index),
+                    None        => break
+                }
+            }
+            if !in_table {
+                out.push(first);
+            }
+
+            if next < 4096 {
+                prefix[next] = prev as u16;
+                suffix[next] = first;
+                next += 1;
+                if next == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            previous = Some(code);
+            if out.len() >= pixel_count {
+                break 'outer;
+            }
+        }
+    }
+
+    out.truncate(pixel_count);
+    while out.len() < pixel_count {
+        out.push(0);
+    }
+    Some(out)
+}
@@ -26,9 +26,13 @@
 
 use libc::{c_uint, size_t};
 use std::ffi::CString;
+use std::mem;
+use core::raw;
 
 use traits::Wrappable;
 use system::vector2::Vector2u;
+use system::InputStream;
+use system::input_stream::InputStreamBridge;
 use graphics::{Color, IntRect};
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
@@ -62,16 +66,38 @@ impl Image {
         }
     }
 
-    /// Create an image from memory
+    /// Create an image from a file in memory
     ///
-    /// This image is filled with black pixels.
+    /// The supported image formats are the same as new_from_file.
+    /// This is useful to load images embedded in the executable
+    /// with include_bytes! or extracted from an archive.
     ///
     /// # Arguments
-    /// * mem - Pointer to the file data in memory
+    /// * mem - The file data in memory
     ///
     /// Return Some(Image) or None
     pub fn new_from_memory(mem: &[u8]) -> Option<Image> {
-        let image = unsafe { ffi::sfImage_createFromMemory(&mem[0], mem.len() as size_t) };
+        let image = unsafe { ffi::sfImage_createFromMemory(mem.as_ptr(), mem.len() as size_t) };
+        if image.is_null() {
+            None
+        } else {
+            Some(Image {
+                    image: image
+                })
+        }
+    }
+
+    /// Create an image from a custom stream
+    ///
+    /// The supported image formats are the same as new_from_file.
+    ///
+    /// # Arguments
+    /// * stream - Source stream to read from
+    ///
+    /// Return Some(Image) or None
+    pub fn new_from_stream(stream: &mut InputStream) -> Option<Image> {
+        let mut bridge = InputStreamBridge::new(stream);
+        let image = unsafe { ffi::sfImage_createFromStream(bridge.unwrap()) };
         if image.is_null() {
             None
         } else {
@@ -212,7 +238,7 @@ impl Image {
     /// # Arguments
     /// * color - Color to make transparent
     /// * alpha - Alpha value to assign to transparent pixels
-    pub fn create_mask_from_color(&self, color: &Color, alpha: u8) -> () {
+    pub fn create_mask_from_color(&mut self, color: &Color, alpha: u8) -> () {
         unsafe {
             ffi::sfImage_createMaskFromColor(self.image, *color, alpha)
         }
@@ -251,6 +277,66 @@ impl Image {
         }
     }
 
+    /// Get the color of a pixel in an image, checking the coordinates
+    ///
+    /// # Arguments
+    /// * x - X coordinate of pixel to get
+    /// * y - Y coordinate of pixel to get
+    ///
+    /// Return Some(Color) or None if the coordinates are out of the image
+    pub fn get_pixel_checked(&self, x: uint, y: uint) -> Option<Color> {
+        let size = self.get_size();
+        if x >= size.x as uint || y >= size.y as uint {
+            None
+        } else {
+            Some(self.get_pixel(x, y))
+        }
+    }
+
+    /// Get a read-only slice of the pixels of an image
+    ///
+    /// The pixels are stored as 32-bits RGBA values, row by row,
+    /// so the slice contains width * height * 4 bytes.
+    ///
+    /// Return the pixels of the image
+    pub fn get_pixels(&self) -> &[u8] {
+        let size = self.get_size();
+        unsafe {
+            let pixels = ffi::sfImage_getPixelsPtr(self.image) as *const u8;
+            // An empty image has no pixel buffer, but a slice needs a non null pointer
+            if pixels.is_null() {
+                mem::transmute(raw::Slice { data: 1 as *const u8, len: 0 })
+            } else {
+                mem::transmute(raw::Slice {
+                    data: pixels,
+                    len: size.x as uint * size.y as uint * 4
+                })
+            }
+        }
+    }
+
+    /// Get a mutable slice of the pixels of an image
+    ///
+    /// The pixels are stored as 32-bits RGBA values, row by row,
+    /// so the slice contains width * height * 4 bytes.
+    ///
+    /// Return the pixels of the image
+    pub fn get_pixels_mut(&mut self) -> &mut [u8] {
+        let size = self.get_size();
+        unsafe {
+            let pixels = ffi::sfImage_getPixelsPtr(self.image) as *const u8;
+            // An empty image has no pixel buffer, but a slice needs a non null pointer
+            if pixels.is_null() {
+                mem::transmute(raw::Slice { data: 1 as *const u8, len: 0 })
+            } else {
+                mem::transmute(raw::Slice {
+                    data: pixels,
+                    len: size.x as uint * size.y as uint * 4
+                })
+            }
+        }
+    }
+
     /// Flip an image horizontally (left <-> right)
     pub fn flip_horizontally(&mut self) -> () {
         unsafe {
@@ -279,10 +365,10 @@ impl Image {
     ///
     /// # Arguments
     /// * source - Source image to copy
-    /// * destX - X coordinate of the destination position
-    /// * destY - Y coordinate of the destination position
-    /// * sourceRect - Sub-rectangle of the source image to copy
-    /// * applyAlpha - Should the copy take in account the source transparency?
+    /// * dest_x - X coordinate of the destination position
+    /// * dest_y - Y coordinate of the destination position
+    /// * source_rect - Sub-rectangle of the source image to copy
+    /// * apply_alpha - Should the copy take in account the source transparency?
     pub fn copy_image(&mut self,
                       source: &Image,
                       dest_x: uint,
@@ -296,13 +382,13 @@ impl Image {
                                                        dest_x as c_uint,
                                                        dest_y as c_uint,
                                                        *source_rect,
-                                                       SFFALSE),
+                                                       SFTRUE),
                 false       =>  ffi::sfImage_copyImage(self.image,
                                                        source.unwrap(),
                                                        dest_x as c_uint,
                                                        dest_y as c_uint,
                                                        *source_rect,
-                                                       SFTRUE)
+                                                       SFFALSE)
             }
         }
     }
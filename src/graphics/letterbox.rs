@@ -0,0 +1,157 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Keep a fixed logical resolution when the window is resized
+//!
+//! Letterbox owns a View showing a fixed size zone of the world and
+//! recomputes its viewport each time the window is resized, so the
+//! scene keeps its aspect ratio and the remaining space of the window is
+//! left as black bars.
+
+use graphics::{FloatRect, View};
+use system::vector2::Vector2f;
+use window::event;
+
+/// Keep a fixed logical resolution when the window is resized
+///
+/// Clear the window with black before drawing through the view to get
+/// the black bars.
+pub struct Letterbox {
+    #[doc(hidden)]
+    width: f32,
+    #[doc(hidden)]
+    height: f32,
+    #[doc(hidden)]
+    view: View
+}
+
+impl Letterbox {
+    /// Create a new Letterbox
+    ///
+    /// # Arguments
+    /// * width - Width of the logical resolution
+    /// * height - Height of the logical resolution
+    /// * window_width - Current width of the window
+    /// * window_height - Current height of the window
+    ///
+    /// Return Some(Letterbox) or None
+    pub fn new(width: f32,
+               height: f32,
+               window_width: u32,
+               window_height: u32) -> Option<Letterbox> {
+        match View::new_from_rect(&FloatRect::new(0., 0., width, height)) {
+            Some(view) => {
+                let mut letterbox = Letterbox {
+                    width: width,
+                    height: height,
+                    view: view
+                };
+                letterbox.resize(window_width, window_height);
+                Some(letterbox)
+            },
+            None => None
+        }
+    }
+
+    /// Recompute the viewport for a new size of the window
+    ///
+    /// # Arguments
+    /// * window_width - New width of the window
+    /// * window_height - New height of the window
+    pub fn resize(&mut self, window_width: u32, window_height: u32) -> () {
+        if window_width == 0 || window_height == 0 {
+            return;
+        }
+        let window_ratio = window_width as f32 / window_height as f32;
+        let view_ratio = self.width / self.height;
+
+        let viewport = if window_ratio > view_ratio {
+            // Window is wider than the logical resolution: bars on the sides
+            let width = view_ratio / window_ratio;
+            FloatRect::new((1. - width) / 2., 0., width, 1.)
+        } else {
+            // Window is taller than the logical resolution: bars on top and bottom
+            let height = window_ratio / view_ratio;
+            FloatRect::new(0., (1. - height) / 2., 1., height)
+        };
+        self.view.set_viewport(&viewport)
+    }
+
+    /// Handle an event of the window
+    ///
+    /// Only the Resized events are used, the others are ignored.
+    ///
+    /// # Arguments
+    /// * event - The event to handle
+    ///
+    /// Return true if the viewport was recomputed, false otherwise
+    pub fn handle_event(&mut self, event: &event::Event) -> bool {
+        match *event {
+            event::Resized { width, height } => {
+                self.resize(width, height);
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Change the logical resolution
+    ///
+    /// The zone displayed is reset to (0, 0, width, height), call
+    /// resize afterwards to update the viewport.
+    ///
+    /// # Arguments
+    /// * width - New width of the logical resolution
+    /// * height - New height of the logical resolution
+    pub fn set_resolution(&mut self, width: f32, height: f32) -> () {
+        self.width = width;
+        self.height = height;
+        self.view.reset(&FloatRect::new(0., 0., width, height))
+    }
+
+    /// Get the logical resolution
+    ///
+    /// Return the size of the logical resolution
+    pub fn get_resolution(&self) -> Vector2f {
+        Vector2f::new(self.width, self.height)
+    }
+
+    /// Get the view to draw the scene with
+    ///
+    /// Return a reference to the view
+    pub fn get_view(&self) -> &View {
+        &self.view
+    }
+
+    /// Get the view to draw the scene with, to move or zoom it
+    ///
+    /// Changing the viewport of the returned view is overwritten
+    /// by the next resize.
+    ///
+    /// Return a mutable reference to the view
+    pub fn get_view_mut(&mut self) -> &mut View {
+        &mut self.view
+    }
+}
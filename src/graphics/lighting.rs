@@ -0,0 +1,402 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! 2D dynamic lighting and shadows
+//!
+//! The lights are rendered additively into a light map, a RenderTexture
+//! cleared with the ambient color, each light being clipped by the
+//! occluders so they cast shadows. The light map is then drawn over the
+//! scene with a multiplicative blending.
+//!
+//! A typical frame draws the scene, calls LightSystem::update with the view
+//! of the scene, then draws the LightSystem on the window.
+
+use std::num::Float;
+use std::f32::consts::PI;
+
+use traits::Drawable;
+use graphics::{Color, FloatRect, RenderTarget, RenderStates, RenderTexture,
+               Sprite, Transform, Vertex, View, TrianglesFan, BlendAdd,
+               BlendMultiply};
+use system::vector2::Vector2f;
+
+/// Number of rays cast along the circle of a light, besides the ones
+/// cast toward the corners of the occluders.
+static CIRCLE_RAYS: uint = 64;
+
+/// A point or spot light
+#[derive(Clone, PartialEq, Show, Copy)]
+pub struct Light {
+    /// Position of the light, in world coordinates
+    pub position: Vector2f,
+    /// Color of the light, at its center
+    pub color: Color,
+    /// Distance at which the light fades out completely
+    pub radius: f32,
+    /// Direction of a spot light, in degrees
+    pub direction: f32,
+    /// Angle of the cone of a spot light, in degrees, 360 for a point light
+    pub spread: f32,
+    /// Disabled lights are not rendered
+    pub enabled: bool
+}
+
+impl Light {
+    /// Create a point light, lighting in every direction
+    ///
+    /// # Arguments
+    /// * position - Position of the light
+    /// * radius - Distance at which the light fades out
+    /// * color - Color of the light
+    ///
+    /// Return a new Light
+    pub fn new_point(position: &Vector2f, radius: f32, color: &Color) -> Light {
+        Light {
+            position: *position,
+            color: *color,
+            radius: radius,
+            direction: 0.,
+            spread: 360.,
+            enabled: true
+        }
+    }
+
+    /// Create a spot light, lighting inside a cone
+    ///
+    /// # Arguments
+    /// * position - Position of the light
+    /// * radius - Distance at which the light fades out
+    /// * color - Color of the light
+    /// * direction - Direction of the cone, in degrees
+    /// * spread - Angle of the cone, in degrees
+    ///
+    /// Return a new Light
+    pub fn new_spot(position: &Vector2f,
+                    radius: f32,
+                    color: &Color,
+                    direction: f32,
+                    spread: f32) -> Light {
+        Light {
+            position: *position,
+            color: *color,
+            radius: radius,
+            direction: direction,
+            spread: spread.max(0.).min(360.),
+            enabled: true
+        }
+    }
+}
+
+/// A polygon blocking the lights
+#[derive(Clone, PartialEq, Show)]
+pub struct Occluder {
+    #[doc(hidden)]
+    points: Vec<Vector2f>
+}
+
+impl Occluder {
+    /// Create an occluder from the points of a polygon
+    ///
+    /// The polygon is closed, the last point being linked to the first one.
+    ///
+    /// # Arguments
+    /// * points - The points of the polygon, in world coordinates
+    ///
+    /// Return a new Occluder
+    pub fn new(points: &[Vector2f]) -> Occluder {
+        Occluder {
+            points: points.to_vec()
+        }
+    }
+
+    /// Create a rectangular occluder
+    ///
+    /// # Arguments
+    /// * rect - The rectangle, in world coordinates
+    ///
+    /// Return a new Occluder
+    pub fn new_from_rect(rect: &FloatRect) -> Occluder {
+        Occluder::new(&[Vector2f::new(rect.left, rect.top),
+                        Vector2f::new(rect.left + rect.width, rect.top),
+                        Vector2f::new(rect.left + rect.width, rect.top + rect.height),
+                        Vector2f::new(rect.left, rect.top + rect.height)])
+    }
+
+    /// Get the points of the polygon
+    ///
+    /// Return a slice of the points
+    pub fn get_points(&self) -> &[Vector2f] {
+        self.points.as_slice()
+    }
+}
+
+/// Render lights and shadows into a light map
+pub struct LightSystem {
+    #[doc(hidden)]
+    light_map: RenderTexture,
+    #[doc(hidden)]
+    ambient: Color,
+    #[doc(hidden)]
+    lights: Vec<Light>,
+    #[doc(hidden)]
+    occluders: Vec<Occluder>
+}
+
+impl LightSystem {
+    /// Create a new LightSystem
+    ///
+    /// The light map must have the size of the target the LightSystem
+    /// is drawn on.
+    ///
+    /// # Arguments
+    /// * width - Width of the light map
+    /// * height - Height of the light map
+    ///
+    /// Return Some(LightSystem) or None
+    pub fn new(width: uint, height: uint) -> Option<LightSystem> {
+        match RenderTexture::new(width, height, false) {
+            Some(light_map) => Some(LightSystem {
+                light_map: light_map,
+                ambient: Color::new_RGB(40, 40, 40),
+                lights: Vec::new(),
+                occluders: Vec::new()
+            }),
+            None => None
+        }
+    }
+
+    /// Set the ambient color
+    ///
+    /// The ambient color lights the parts of the scene reached by no light.
+    ///
+    /// # Arguments
+    /// * color - The new ambient color
+    pub fn set_ambient_color(&mut self, color: &Color) -> () {
+        self.ambient = *color
+    }
+
+    /// Get the ambient color
+    ///
+    /// Return the ambient color
+    pub fn get_ambient_color(&self) -> Color {
+        self.ambient
+    }
+
+    /// Add a light
+    ///
+    /// # Arguments
+    /// * light - The light to add
+    ///
+    /// Return the index of the light
+    pub fn add_light(&mut self, light: Light) -> uint {
+        self.lights.push(light);
+        self.lights.len() - 1
+    }
+
+    /// Get a light
+    ///
+    /// # Arguments
+    /// * index - The index of the light
+    ///
+    /// Return Some(light) or None if the index is out of range
+    pub fn get_light(&self, index: uint) -> Option<&Light> {
+        self.lights.get(index)
+    }
+
+    /// Get a light, to move or change it
+    ///
+    /// # Arguments
+    /// * index - The index of the light
+    ///
+    /// Return Some(light) or None if the index is out of range
+    pub fn get_light_mut(&mut self, index: uint) -> Option<&mut Light> {
+        self.lights.get_mut(index)
+    }
+
+    /// Get all the lights
+    ///
+    /// Return a slice of the lights
+    pub fn get_lights(&self) -> &[Light] {
+        self.lights.as_slice()
+    }
+
+    /// Remove all the lights
+    pub fn clear_lights(&mut self) -> () {
+        self.lights.clear()
+    }
+
+    /// Add an occluder
+    ///
+    /// # Arguments
+    /// * occluder - The occluder to add
+    ///
+    /// Return the index of the occluder
+    pub fn add_occluder(&mut self, occluder: Occluder) -> uint {
+        self.occluders.push(occluder);
+        self.occluders.len() - 1
+    }
+
+    /// Get all the occluders
+    ///
+    /// Return a slice of the occluders
+    pub fn get_occluders(&self) -> &[Occluder] {
+        self.occluders.as_slice()
+    }
+
+    /// Remove all the occluders
+    pub fn clear_occluders(&mut self) -> () {
+        self.occluders.clear()
+    }
+
+    /// Get the light map
+    ///
+    /// Return a reference to the RenderTexture the lights are rendered to
+    pub fn get_light_map(&self) -> &RenderTexture {
+        &self.light_map
+    }
+
+    /// Render the lights into the light map
+    ///
+    /// # Arguments
+    /// * view - The view used to draw the scene
+    pub fn update(&mut self, view: &View) -> () {
+        self.light_map.set_view(view);
+        self.light_map.clear(&self.ambient);
+        let mut rs = RenderStates::new(BlendAdd, Transform::new_identity(), None, None);
+        for light in self.lights.iter() {
+            if !light.enabled || light.radius <= 0. {
+                continue;
+            }
+            let fan = self.light_fan(light);
+            if fan.len() > 2 {
+                self.light_map.draw_primitives_rs(fan.as_slice(), TrianglesFan, &mut rs);
+            }
+        }
+        self.light_map.display()
+    }
+
+    #[doc(hidden)]
+    fn light_fan(&self, light: &Light) -> Vec<Vertex> {
+        let full = light.spread >= 360.;
+        let start = (light.direction - light.spread / 2.) * PI / 180.;
+        let end = (light.direction + light.spread / 2.) * PI / 180.;
+
+        // Angles of the rays, relative to start
+        let mut angles = Vec::new();
+        let span = if full { 2. * PI } else { end - start };
+        for i in range(0u, CIRCLE_RAYS + 1) {
+            angles.push(span * i as f32 / CIRCLE_RAYS as f32);
+        }
+        for occluder in self.occluders.iter() {
+            for point in occluder.points.iter() {
+                let offset = *point - light.position;
+                if offset.x * offset.x + offset.y * offset.y > light.radius * light.radius {
+                    continue;
+                }
+                let angle = offset.y.atan2(offset.x) - start;
+                let angle = ((angle % (2. * PI)) + 2. * PI) % (2. * PI);
+                for &delta in [-0.0001f32, 0., 0.0001].iter() {
+                    let a = angle + delta;
+                    if a >= 0. && a <= span {
+                        angles.push(a);
+                    }
+                }
+            }
+        }
+        // A degenerate light or occluder gives NaN angles, which can't be sorted
+        angles.retain(|angle| angle.is_finite());
+        angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut fan = Vec::with_capacity(angles.len() + 1);
+        fan.push(Vertex::new_with_pos_color(&light.position, &light.color));
+        for &angle in angles.iter() {
+            let direction = Vector2f::new((start + angle).cos(), (start + angle).sin());
+            let distance = self.cast_ray(&light.position, &direction, light.radius);
+            let fade = 1. - distance / light.radius;
+            let color = Color::new_RGB((light.color.red as f32 * fade) as u8,
+                                       (light.color.green as f32 * fade) as u8,
+                                       (light.color.blue as f32 * fade) as u8);
+            fan.push(Vertex::new_with_pos_color(&(light.position + direction * distance),
+                                                &color));
+        }
+        fan
+    }
+
+    #[doc(hidden)]
+    fn cast_ray(&self, origin: &Vector2f, direction: &Vector2f, max: f32) -> f32 {
+        let mut nearest = max;
+        for occluder in self.occluders.iter() {
+            let count = occluder.points.len();
+            for i in range(0u, count) {
+                let a = occluder.points[i];
+                let b = occluder.points[(i + 1) % count];
+                let edge = b - a;
+                let denom = cross(direction, &edge);
+                if denom.abs() < 1e-6 {
+                    continue;
+                }
+                let w = a - *origin;
+                let t = cross(&w, &edge) / denom;
+                let u = cross(&w, direction) / denom;
+                if t >= 0. && t < nearest && u >= 0. && u <= 1. {
+                    nearest = t;
+                }
+            }
+        }
+        nearest
+    }
+}
+
+fn cross(a: &Vector2f, b: &Vector2f) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+impl Drawable for LightSystem {
+    /// Draw the light map over the whole target with a multiplicative blending
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        let texture = match self.light_map.get_texture() {
+            Some(texture) => texture,
+            None => return
+        };
+        let sprite = match Sprite::new_with_texture(&texture) {
+            Some(sprite) => sprite,
+            None => return
+        };
+        let previous = render_target.get_view().clone();
+        let default = render_target.get_default_view();
+        render_target.set_view(&default);
+        let mut rs = RenderStates::new(BlendMultiply, Transform::new_identity(), None, None);
+        render_target.draw_sprite_rs(&sprite, &mut rs);
+        render_target.set_view(&previous)
+    }
+
+    /// Same as draw, the render states are ignored as the light map
+    /// must be blended multiplicatively
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 _: &mut RenderStates) -> () {
+        self.draw(render_target)
+    }
+}
@@ -28,6 +28,7 @@
 pub use graphics::render_target::RenderTarget;
 pub use graphics::render_states::RenderStates;
 pub use graphics::render_window::{RenderWindow, Events};
+pub use graphics::render_windows::RenderWindows;
 pub use graphics::rect::{FloatRect, IntRect};
 pub use graphics::texture::Texture;
 pub use graphics::blend_mode::{BlendMode, BlendAlpha, BlendAdd, BlendMultiply,
@@ -37,7 +38,16 @@ pub use graphics::text::Text;
 pub use graphics::shader::Shader;
 pub use graphics::color::Color;
 pub use graphics::font::Font;
+pub use graphics::font_fallback::FontFallback;
 pub use graphics::view::View;
+pub use graphics::letterbox::Letterbox;
+pub use graphics::screen_split::ScreenSplit;
+pub use graphics::camera::Camera;
+pub use graphics::debug_overlay::DebugOverlay;
+pub use graphics::frame_stats::FrameStats;
+pub use graphics::frame_timing::FrameTiming;
+pub use graphics::scene_node::SceneNode;
+pub use graphics::quad_tree::QuadTree;
 pub use graphics::image::Image;
 pub use graphics::sprite::Sprite;
 pub use graphics::circle_shape::CircleShape;
@@ -53,6 +63,12 @@ pub use graphics::render_texture::RenderTexture;
 pub use graphics::shape::Shape;
 pub use graphics::vertex_array::{VertexArray, Vertices};
 pub use graphics::text_style::{TextStyle, Regular, Bold, Italic, Underlined};
+pub use graphics::nine_slice::NineSlice;
+pub use graphics::sprite_sheet::SpriteSheet;
+pub use graphics::rich_text::{RichText, TextSpan};
+pub use graphics::path_text::PathText;
+pub use graphics::sdf_text::{SdfFont, SdfGlyph, SdfText};
+pub use graphics::bitmap_font::{BitmapFont, BitmapGlyph, BitmapText};
 
 /// Shapes implementations using reference counting to manage shared resources
 pub mod rc {
@@ -66,6 +82,14 @@ pub mod rc {
     pub use graphics::render_states::rc::RenderStates;
 }
 
+pub mod palette;
+pub mod lighting;
+pub mod collision;
+pub mod triangulation;
+pub mod tween;
+#[cfg(feature = "gif")]
+pub mod gif;
+
 #[doc(hidden)]
 #[cfg(any(target_os="macos", target_os="linux", target_os="windows"))]
 mod platform {
@@ -76,6 +100,7 @@ mod platform {
 mod render_target;
 mod render_states;
 mod render_window;
+mod render_windows;
 mod rect;
 mod texture;
 mod blend_mode;
@@ -98,3 +123,18 @@ mod transformable;
 mod glyph;
 mod render_texture;
 mod shape;
+mod nine_slice;
+mod sprite_sheet;
+mod font_fallback;
+mod rich_text;
+mod path_text;
+mod sdf_text;
+mod bitmap_font;
+mod letterbox;
+mod screen_split;
+mod camera;
+mod debug_overlay;
+mod frame_stats;
+mod frame_timing;
+mod scene_node;
+mod quad_tree;
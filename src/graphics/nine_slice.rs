@@ -0,0 +1,283 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Nine-patch drawable for scalable UI elements
+//!
+//! A NineSlice splits a texture into nine regions using four margins.
+//! The corners keep their size, the edges are stretched along one axis
+//! and the center is stretched along both, so a small texture can be used
+//! to draw panels and buttons of any size.
+
+use traits::Drawable;
+use graphics::{FloatRect, Color, Texture, RenderTarget, RenderStates,
+               Vertex, Quads};
+use system::vector2::Vector2f;
+
+/// Nine-patch drawable for scalable UI elements
+///
+/// The texture is split into nine regions by the left, top, right and
+/// bottom margins. Corners are drawn at their original size, edges and
+/// center are stretched to fill the requested size.
+pub struct NineSlice<'s> {
+    #[doc(hidden)]
+    texture: Option<&'s Texture>,
+    #[doc(hidden)]
+    margins: (f32, f32, f32, f32),
+    #[doc(hidden)]
+    size: Vector2f,
+    #[doc(hidden)]
+    position: Vector2f,
+    #[doc(hidden)]
+    color: Color,
+    #[doc(hidden)]
+    vertices: Vec<Vertex>
+}
+
+impl<'s> NineSlice<'s> {
+    /// Create a new NineSlice without texture
+    ///
+    /// Return a new NineSlice
+    pub fn new() -> NineSlice<'s> {
+        let mut nine_slice = NineSlice {
+            texture: None,
+            margins: (0., 0., 0., 0.),
+            size: Vector2f::new(0., 0.),
+            position: Vector2f::new(0., 0.),
+            color: Color::white(),
+            vertices: Vec::with_capacity(36)
+        };
+        nine_slice.update_vertices();
+        nine_slice
+    }
+
+    /// Create a new NineSlice with a texture and margins
+    ///
+    /// The size of the NineSlice is initialized to the size of the texture.
+    ///
+    /// # Arguments
+    /// * texture - The texture to split
+    /// * left - Width of the left column, in texture pixels
+    /// * top - Height of the top row, in texture pixels
+    /// * right - Width of the right column, in texture pixels
+    /// * bottom - Height of the bottom row, in texture pixels
+    ///
+    /// Return a new NineSlice
+    pub fn new_with_texture(texture: &'s Texture,
+                            left: f32,
+                            top: f32,
+                            right: f32,
+                            bottom: f32) -> NineSlice<'s> {
+        let tex_size = texture.get_size();
+        let mut nine_slice = NineSlice {
+            texture: Some(texture),
+            margins: (left, top, right, bottom),
+            size: Vector2f::new(tex_size.x as f32, tex_size.y as f32),
+            position: Vector2f::new(0., 0.),
+            color: Color::white(),
+            vertices: Vec::with_capacity(36)
+        };
+        nine_slice.update_vertices();
+        nine_slice
+    }
+
+    /// Change the source texture of a NineSlice
+    ///
+    /// # Arguments
+    /// * texture - New texture
+    pub fn set_texture(&mut self, texture: &'s Texture) -> () {
+        self.texture = Some(texture);
+        self.update_vertices();
+    }
+
+    /// Get the source texture of a NineSlice
+    ///
+    /// Return an Option to the texture, None if no texture is set
+    pub fn get_texture(&self) -> Option<&'s Texture> {
+        self.texture
+    }
+
+    /// Set the margins of a NineSlice
+    ///
+    /// The margins are expressed in texture pixels and define the size
+    /// of the corners that are never stretched.
+    ///
+    /// # Arguments
+    /// * left - Width of the left column
+    /// * top - Height of the top row
+    /// * right - Width of the right column
+    /// * bottom - Height of the bottom row
+    pub fn set_margins(&mut self,
+                       left: f32,
+                       top: f32,
+                       right: f32,
+                       bottom: f32) -> () {
+        self.margins = (left, top, right, bottom);
+        self.update_vertices();
+    }
+
+    /// Get the margins of a NineSlice
+    ///
+    /// Return the margins as a (left, top, right, bottom) tuple
+    pub fn get_margins(&self) -> (f32, f32, f32, f32) {
+        self.margins
+    }
+
+    /// Set the size of a NineSlice
+    ///
+    /// # Arguments
+    /// * size - New size, in pixels
+    pub fn set_size(&mut self, size: &Vector2f) -> () {
+        self.size = *size;
+        self.update_vertices();
+    }
+
+    /// Set the size of a NineSlice
+    ///
+    /// # Arguments
+    /// * width - New width, in pixels
+    /// * height - New height, in pixels
+    pub fn set_size2f(&mut self, width: f32, height: f32) -> () {
+        self.set_size(&Vector2f::new(width, height))
+    }
+
+    /// Get the size of a NineSlice
+    ///
+    /// Return the size of the NineSlice
+    pub fn get_size(&self) -> Vector2f {
+        self.size
+    }
+
+    /// Set the position of a NineSlice
+    ///
+    /// # Arguments
+    /// * position - New position of the top left corner
+    pub fn set_position(&mut self, position: &Vector2f) -> () {
+        self.position = *position;
+        self.update_vertices();
+    }
+
+    /// Set the position of a NineSlice
+    ///
+    /// # Arguments
+    /// * x - X coordinate of the new position
+    /// * y - Y coordinate of the new position
+    pub fn set_position2f(&mut self, x: f32, y: f32) -> () {
+        self.set_position(&Vector2f::new(x, y))
+    }
+
+    /// Get the position of a NineSlice
+    ///
+    /// Return the position of the top left corner
+    pub fn get_position(&self) -> Vector2f {
+        self.position
+    }
+
+    /// Set the global color of a NineSlice
+    ///
+    /// The color is modulated (multiplied) with the texture.
+    ///
+    /// # Arguments
+    /// * color - New color
+    pub fn set_color(&mut self, color: &Color) -> () {
+        self.color = *color;
+        self.update_vertices();
+    }
+
+    /// Get the global color of a NineSlice
+    ///
+    /// Return the global color
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    /// Get the global bounding rectangle of a NineSlice
+    ///
+    /// Return the global bounding rectangle
+    pub fn get_global_bounds(&self) -> FloatRect {
+        FloatRect::new(self.position.x, self.position.y,
+                       self.size.x, self.size.y)
+    }
+
+    #[doc(hidden)]
+    fn update_vertices(&mut self) -> () {
+        self.vertices.clear();
+        let tex_size = match self.texture {
+            Some(texture) => {
+                let size = texture.get_size();
+                Vector2f::new(size.x as f32, size.y as f32)
+            },
+            None => self.size
+        };
+        let (left, top, right, bottom) = self.margins;
+
+        // Shrink the corners if the requested size is too small to hold them
+        let scale_x = if left + right > self.size.x && left + right > 0. {
+            self.size.x / (left + right)
+        } else {
+            1.
+        };
+        let scale_y = if top + bottom > self.size.y && top + bottom > 0. {
+            self.size.y / (top + bottom)
+        } else {
+            1.
+        };
+
+        let xs = [0., left * scale_x, self.size.x - right * scale_x, self.size.x];
+        let ys = [0., top * scale_y, self.size.y - bottom * scale_y, self.size.y];
+        let txs = [0., left, tex_size.x - right, tex_size.x];
+        let tys = [0., top, tex_size.y - bottom, tex_size.y];
+
+        for row in range(0u, 3) {
+            for col in range(0u, 3) {
+                let corners = [(col, row), (col + 1, row),
+                               (col + 1, row + 1), (col, row + 1)];
+                for &(i, j) in corners.iter() {
+                    self.vertices.push(Vertex::new(
+                        &Vector2f::new(self.position.x + xs[i],
+                                       self.position.y + ys[j]),
+                        &self.color,
+                        &Vector2f::new(txs[i], tys[j])));
+                }
+            }
+        }
+    }
+}
+
+impl<'s> Drawable for NineSlice<'s> {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        let mut rs = RenderStates::default();
+        rs.texture = self.texture;
+        render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+    }
+
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 render_states: &mut RenderStates) -> () {
+        let mut rs = RenderStates::new(render_states.blendMode,
+                                       render_states.transform,
+                                       self.texture,
+                                       render_states.shader);
+        render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+    }
+}
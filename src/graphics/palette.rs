@@ -0,0 +1,679 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Named colors and indexed palettes
+//!
+//! This module provides the full set of CSS named colors as constants, a
+//! lookup by name, and Palette, an indexed list of colors which can be
+//! saved to an image to be used as a lookup texture by a palette swap shader.
+
+use std::num::Int;
+
+use graphics::{Color, Image};
+
+/// CSS color aliceblue (#f0f8ff)
+pub const ALICEBLUE: Color = Color { red: 240, green: 248, blue: 255, alpha: 255 };
+/// CSS color antiquewhite (#faebd7)
+pub const ANTIQUEWHITE: Color = Color { red: 250, green: 235, blue: 215, alpha: 255 };
+/// CSS color aqua (#00ffff)
+pub const AQUA: Color = Color { red: 0, green: 255, blue: 255, alpha: 255 };
+/// CSS color aquamarine (#7fffd4)
+pub const AQUAMARINE: Color = Color { red: 127, green: 255, blue: 212, alpha: 255 };
+/// CSS color azure (#f0ffff)
+pub const AZURE: Color = Color { red: 240, green: 255, blue: 255, alpha: 255 };
+/// CSS color beige (#f5f5dc)
+pub const BEIGE: Color = Color { red: 245, green: 245, blue: 220, alpha: 255 };
+/// CSS color bisque (#ffe4c4)
+pub const BISQUE: Color = Color { red: 255, green: 228, blue: 196, alpha: 255 };
+/// CSS color black (#000000)
+pub const BLACK: Color = Color { red: 0, green: 0, blue: 0, alpha: 255 };
+/// CSS color blanchedalmond (#ffebcd)
+pub const BLANCHEDALMOND: Color = Color { red: 255, green: 235, blue: 205, alpha: 255 };
+/// CSS color blue (#0000ff)
+pub const BLUE: Color = Color { red: 0, green: 0, blue: 255, alpha: 255 };
+/// CSS color blueviolet (#8a2be2)
+pub const BLUEVIOLET: Color = Color { red: 138, green: 43, blue: 226, alpha: 255 };
+/// CSS color brown (#a52a2a)
+pub const BROWN: Color = Color { red: 165, green: 42, blue: 42, alpha: 255 };
+/// CSS color burlywood (#deb887)
+pub const BURLYWOOD: Color = Color { red: 222, green: 184, blue: 135, alpha: 255 };
+/// CSS color cadetblue (#5f9ea0)
+pub const CADETBLUE: Color = Color { red: 95, green: 158, blue: 160, alpha: 255 };
+/// CSS color chartreuse (#7fff00)
+pub const CHARTREUSE: Color = Color { red: 127, green: 255, blue: 0, alpha: 255 };
+/// CSS color chocolate (#d2691e)
+pub const CHOCOLATE: Color = Color { red: 210, green: 105, blue: 30, alpha: 255 };
+/// CSS color coral (#ff7f50)
+pub const CORAL: Color = Color { red: 255, green: 127, blue: 80, alpha: 255 };
+/// CSS color cornflowerblue (#6495ed)
+pub const CORNFLOWERBLUE: Color = Color { red: 100, green: 149, blue: 237, alpha: 255 };
+/// CSS color cornsilk (#fff8dc)
+pub const CORNSILK: Color = Color { red: 255, green: 248, blue: 220, alpha: 255 };
+/// CSS color crimson (#dc143c)
+pub const CRIMSON: Color = Color { red: 220, green: 20, blue: 60, alpha: 255 };
+/// CSS color cyan (#00ffff)
+pub const CYAN: Color = Color { red: 0, green: 255, blue: 255, alpha: 255 };
+/// CSS color darkblue (#00008b)
+pub const DARKBLUE: Color = Color { red: 0, green: 0, blue: 139, alpha: 255 };
+/// CSS color darkcyan (#008b8b)
+pub const DARKCYAN: Color = Color { red: 0, green: 139, blue: 139, alpha: 255 };
+/// CSS color darkgoldenrod (#b8860b)
+pub const DARKGOLDENROD: Color = Color { red: 184, green: 134, blue: 11, alpha: 255 };
+/// CSS color darkgray (#a9a9a9)
+pub const DARKGRAY: Color = Color { red: 169, green: 169, blue: 169, alpha: 255 };
+/// CSS color darkgreen (#006400)
+pub const DARKGREEN: Color = Color { red: 0, green: 100, blue: 0, alpha: 255 };
+/// CSS color darkgrey (#a9a9a9)
+pub const DARKGREY: Color = Color { red: 169, green: 169, blue: 169, alpha: 255 };
+/// CSS color darkkhaki (#bdb76b)
+pub const DARKKHAKI: Color = Color { red: 189, green: 183, blue: 107, alpha: 255 };
+/// CSS color darkmagenta (#8b008b)
+pub const DARKMAGENTA: Color = Color { red: 139, green: 0, blue: 139, alpha: 255 };
+/// CSS color darkolivegreen (#556b2f)
+pub const DARKOLIVEGREEN: Color = Color { red: 85, green: 107, blue: 47, alpha: 255 };
+/// CSS color darkorange (#ff8c00)
+pub const DARKORANGE: Color = Color { red: 255, green: 140, blue: 0, alpha: 255 };
+/// CSS color darkorchid (#9932cc)
+pub const DARKORCHID: Color = Color { red: 153, green: 50, blue: 204, alpha: 255 };
+/// CSS color darkred (#8b0000)
+pub const DARKRED: Color = Color { red: 139, green: 0, blue: 0, alpha: 255 };
+/// CSS color darksalmon (#e9967a)
+pub const DARKSALMON: Color = Color { red: 233, green: 150, blue: 122, alpha: 255 };
+/// CSS color darkseagreen (#8fbc8f)
+pub const DARKSEAGREEN: Color = Color { red: 143, green: 188, blue: 143, alpha: 255 };
+/// CSS color darkslateblue (#483d8b)
+pub const DARKSLATEBLUE: Color = Color { red: 72, green: 61, blue: 139, alpha: 255 };
+/// CSS color darkslategray (#2f4f4f)
+pub const DARKSLATEGRAY: Color = Color { red: 47, green: 79, blue: 79, alpha: 255 };
+/// CSS color darkslategrey (#2f4f4f)
+pub const DARKSLATEGREY: Color = Color { red: 47, green: 79, blue: 79, alpha: 255 };
+/// CSS color darkturquoise (#00ced1)
+pub const DARKTURQUOISE: Color = Color { red: 0, green: 206, blue: 209, alpha: 255 };
+/// CSS color darkviolet (#9400d3)
+pub const DARKVIOLET: Color = Color { red: 148, green: 0, blue: 211, alpha: 255 };
+/// CSS color deeppink (#ff1493)
+pub const DEEPPINK: Color = Color { red: 255, green: 20, blue: 147, alpha: 255 };
+/// CSS color deepskyblue (#00bfff)
+pub const DEEPSKYBLUE: Color = Color { red: 0, green: 191, blue: 255, alpha: 255 };
+/// CSS color dimgray (#696969)
+pub const DIMGRAY: Color = Color { red: 105, green: 105, blue: 105, alpha: 255 };
+/// CSS color dimgrey (#696969)
+pub const DIMGREY: Color = Color { red: 105, green: 105, blue: 105, alpha: 255 };
+/// CSS color dodgerblue (#1e90ff)
+pub const DODGERBLUE: Color = Color { red: 30, green: 144, blue: 255, alpha: 255 };
+/// CSS color firebrick (#b22222)
+pub const FIREBRICK: Color = Color { red: 178, green: 34, blue: 34, alpha: 255 };
+/// CSS color floralwhite (#fffaf0)
+pub const FLORALWHITE: Color = Color { red: 255, green: 250, blue: 240, alpha: 255 };
+/// CSS color forestgreen (#228b22)
+pub const FORESTGREEN: Color = Color { red: 34, green: 139, blue: 34, alpha: 255 };
+/// CSS color fuchsia (#ff00ff)
+pub const FUCHSIA: Color = Color { red: 255, green: 0, blue: 255, alpha: 255 };
+/// CSS color gainsboro (#dcdcdc)
+pub const GAINSBORO: Color = Color { red: 220, green: 220, blue: 220, alpha: 255 };
+/// CSS color ghostwhite (#f8f8ff)
+pub const GHOSTWHITE: Color = Color { red: 248, green: 248, blue: 255, alpha: 255 };
+/// CSS color gold (#ffd700)
+pub const GOLD: Color = Color { red: 255, green: 215, blue: 0, alpha: 255 };
+/// CSS color goldenrod (#daa520)
+pub const GOLDENROD: Color = Color { red: 218, green: 165, blue: 32, alpha: 255 };
+/// CSS color gray (#808080)
+pub const GRAY: Color = Color { red: 128, green: 128, blue: 128, alpha: 255 };
+/// CSS color green (#008000)
+pub const GREEN: Color = Color { red: 0, green: 128, blue: 0, alpha: 255 };
+/// CSS color greenyellow (#adff2f)
+pub const GREENYELLOW: Color = Color { red: 173, green: 255, blue: 47, alpha: 255 };
+/// CSS color grey (#808080)
+pub const GREY: Color = Color { red: 128, green: 128, blue: 128, alpha: 255 };
+/// CSS color honeydew (#f0fff0)
+pub const HONEYDEW: Color = Color { red: 240, green: 255, blue: 240, alpha: 255 };
+/// CSS color hotpink (#ff69b4)
+pub const HOTPINK: Color = Color { red: 255, green: 105, blue: 180, alpha: 255 };
+/// CSS color indianred (#cd5c5c)
+pub const INDIANRED: Color = Color { red: 205, green: 92, blue: 92, alpha: 255 };
+/// CSS color indigo (#4b0082)
+pub const INDIGO: Color = Color { red: 75, green: 0, blue: 130, alpha: 255 };
+/// CSS color ivory (#fffff0)
+pub const IVORY: Color = Color { red: 255, green: 255, blue: 240, alpha: 255 };
+/// CSS color khaki (#f0e68c)
+pub const KHAKI: Color = Color { red: 240, green: 230, blue: 140, alpha: 255 };
+/// CSS color lavender (#e6e6fa)
+pub const LAVENDER: Color = Color { red: 230, green: 230, blue: 250, alpha: 255 };
+/// CSS color lavenderblush (#fff0f5)
+pub const LAVENDERBLUSH: Color = Color { red: 255, green: 240, blue: 245, alpha: 255 };
+/// CSS color lawngreen (#7cfc00)
+pub const LAWNGREEN: Color = Color { red: 124, green: 252, blue: 0, alpha: 255 };
+/// CSS color lemonchiffon (#fffacd)
+pub const LEMONCHIFFON: Color = Color { red: 255, green: 250, blue: 205, alpha: 255 };
+/// CSS color lightblue (#add8e6)
+pub const LIGHTBLUE: Color = Color { red: 173, green: 216, blue: 230, alpha: 255 };
+/// CSS color lightcoral (#f08080)
+pub const LIGHTCORAL: Color = Color { red: 240, green: 128, blue: 128, alpha: 255 };
+/// CSS color lightcyan (#e0ffff)
+pub const LIGHTCYAN: Color = Color { red: 224, green: 255, blue: 255, alpha: 255 };
+/// CSS color lightgoldenrodyellow (#fafad2)
+pub const LIGHTGOLDENRODYELLOW: Color = Color { red: 250, green: 250, blue: 210, alpha: 255 };
+/// CSS color lightgray (#d3d3d3)
+pub const LIGHTGRAY: Color = Color { red: 211, green: 211, blue: 211, alpha: 255 };
+/// CSS color lightgreen (#90ee90)
+pub const LIGHTGREEN: Color = Color { red: 144, green: 238, blue: 144, alpha: 255 };
+/// CSS color lightgrey (#d3d3d3)
+pub const LIGHTGREY: Color = Color { red: 211, green: 211, blue: 211, alpha: 255 };
+/// CSS color lightpink (#ffb6c1)
+pub const LIGHTPINK: Color = Color { red: 255, green: 182, blue: 193, alpha: 255 };
+/// CSS color lightsalmon (#ffa07a)
+pub const LIGHTSALMON: Color = Color { red: 255, green: 160, blue: 122, alpha: 255 };
+/// CSS color lightseagreen (#20b2aa)
+pub const LIGHTSEAGREEN: Color = Color { red: 32, green: 178, blue: 170, alpha: 255 };
+/// CSS color lightskyblue (#87cefa)
+pub const LIGHTSKYBLUE: Color = Color { red: 135, green: 206, blue: 250, alpha: 255 };
+/// CSS color lightslategray (#778899)
+pub const LIGHTSLATEGRAY: Color = Color { red: 119, green: 136, blue: 153, alpha: 255 };
+/// CSS color lightslategrey (#778899)
+pub const LIGHTSLATEGREY: Color = Color { red: 119, green: 136, blue: 153, alpha: 255 };
+/// CSS color lightsteelblue (#b0c4de)
+pub const LIGHTSTEELBLUE: Color = Color { red: 176, green: 196, blue: 222, alpha: 255 };
+/// CSS color lightyellow (#ffffe0)
+pub const LIGHTYELLOW: Color = Color { red: 255, green: 255, blue: 224, alpha: 255 };
+/// CSS color lime (#00ff00)
+pub const LIME: Color = Color { red: 0, green: 255, blue: 0, alpha: 255 };
+/// CSS color limegreen (#32cd32)
+pub const LIMEGREEN: Color = Color { red: 50, green: 205, blue: 50, alpha: 255 };
+/// CSS color linen (#faf0e6)
+pub const LINEN: Color = Color { red: 250, green: 240, blue: 230, alpha: 255 };
+/// CSS color magenta (#ff00ff)
+pub const MAGENTA: Color = Color { red: 255, green: 0, blue: 255, alpha: 255 };
+/// CSS color maroon (#800000)
+pub const MAROON: Color = Color { red: 128, green: 0, blue: 0, alpha: 255 };
+/// CSS color mediumaquamarine (#66cdaa)
+pub const MEDIUMAQUAMARINE: Color = Color { red: 102, green: 205, blue: 170, alpha: 255 };
+/// CSS color mediumblue (#0000cd)
+pub const MEDIUMBLUE: Color = Color { red: 0, green: 0, blue: 205, alpha: 255 };
+/// CSS color mediumorchid (#ba55d3)
+pub const MEDIUMORCHID: Color = Color { red: 186, green: 85, blue: 211, alpha: 255 };
+/// CSS color mediumpurple (#9370db)
+pub const MEDIUMPURPLE: Color = Color { red: 147, green: 112, blue: 219, alpha: 255 };
+/// CSS color mediumseagreen (#3cb371)
+pub const MEDIUMSEAGREEN: Color = Color { red: 60, green: 179, blue: 113, alpha: 255 };
+/// CSS color mediumslateblue (#7b68ee)
+pub const MEDIUMSLATEBLUE: Color = Color { red: 123, green: 104, blue: 238, alpha: 255 };
+/// CSS color mediumspringgreen (#00fa9a)
+pub const MEDIUMSPRINGGREEN: Color = Color { red: 0, green: 250, blue: 154, alpha: 255 };
+/// CSS color mediumturquoise (#48d1cc)
+pub const MEDIUMTURQUOISE: Color = Color { red: 72, green: 209, blue: 204, alpha: 255 };
+/// CSS color mediumvioletred (#c71585)
+pub const MEDIUMVIOLETRED: Color = Color { red: 199, green: 21, blue: 133, alpha: 255 };
+/// CSS color midnightblue (#191970)
+pub const MIDNIGHTBLUE: Color = Color { red: 25, green: 25, blue: 112, alpha: 255 };
+/// CSS color mintcream (#f5fffa)
+pub const MINTCREAM: Color = Color { red: 245, green: 255, blue: 250, alpha: 255 };
+/// CSS color mistyrose (#ffe4e1)
+pub const MISTYROSE: Color = Color { red: 255, green: 228, blue: 225, alpha: 255 };
+/// CSS color moccasin (#ffe4b5)
+pub const MOCCASIN: Color = Color { red: 255, green: 228, blue: 181, alpha: 255 };
+/// CSS color navajowhite (#ffdead)
+pub const NAVAJOWHITE: Color = Color { red: 255, green: 222, blue: 173, alpha: 255 };
+/// CSS color navy (#000080)
+pub const NAVY: Color = Color { red: 0, green: 0, blue: 128, alpha: 255 };
+/// CSS color oldlace (#fdf5e6)
+pub const OLDLACE: Color = Color { red: 253, green: 245, blue: 230, alpha: 255 };
+/// CSS color olive (#808000)
+pub const OLIVE: Color = Color { red: 128, green: 128, blue: 0, alpha: 255 };
+/// CSS color olivedrab (#6b8e23)
+pub const OLIVEDRAB: Color = Color { red: 107, green: 142, blue: 35, alpha: 255 };
+/// CSS color orange (#ffa500)
+pub const ORANGE: Color = Color { red: 255, green: 165, blue: 0, alpha: 255 };
+/// CSS color orangered (#ff4500)
+pub const ORANGERED: Color = Color { red: 255, green: 69, blue: 0, alpha: 255 };
+/// CSS color orchid (#da70d6)
+pub const ORCHID: Color = Color { red: 218, green: 112, blue: 214, alpha: 255 };
+/// CSS color palegoldenrod (#eee8aa)
+pub const PALEGOLDENROD: Color = Color { red: 238, green: 232, blue: 170, alpha: 255 };
+/// CSS color palegreen (#98fb98)
+pub const PALEGREEN: Color = Color { red: 152, green: 251, blue: 152, alpha: 255 };
+/// CSS color paleturquoise (#afeeee)
+pub const PALETURQUOISE: Color = Color { red: 175, green: 238, blue: 238, alpha: 255 };
+/// CSS color palevioletred (#db7093)
+pub const PALEVIOLETRED: Color = Color { red: 219, green: 112, blue: 147, alpha: 255 };
+/// CSS color papayawhip (#ffefd5)
+pub const PAPAYAWHIP: Color = Color { red: 255, green: 239, blue: 213, alpha: 255 };
+/// CSS color peachpuff (#ffdab9)
+pub const PEACHPUFF: Color = Color { red: 255, green: 218, blue: 185, alpha: 255 };
+/// CSS color peru (#cd853f)
+pub const PERU: Color = Color { red: 205, green: 133, blue: 63, alpha: 255 };
+/// CSS color pink (#ffc0cb)
+pub const PINK: Color = Color { red: 255, green: 192, blue: 203, alpha: 255 };
+/// CSS color plum (#dda0dd)
+pub const PLUM: Color = Color { red: 221, green: 160, blue: 221, alpha: 255 };
+/// CSS color powderblue (#b0e0e6)
+pub const POWDERBLUE: Color = Color { red: 176, green: 224, blue: 230, alpha: 255 };
+/// CSS color purple (#800080)
+pub const PURPLE: Color = Color { red: 128, green: 0, blue: 128, alpha: 255 };
+/// CSS color rebeccapurple (#663399)
+pub const REBECCAPURPLE: Color = Color { red: 102, green: 51, blue: 153, alpha: 255 };
+/// CSS color red (#ff0000)
+pub const RED: Color = Color { red: 255, green: 0, blue: 0, alpha: 255 };
+/// CSS color rosybrown (#bc8f8f)
+pub const ROSYBROWN: Color = Color { red: 188, green: 143, blue: 143, alpha: 255 };
+/// CSS color royalblue (#4169e1)
+pub const ROYALBLUE: Color = Color { red: 65, green: 105, blue: 225, alpha: 255 };
+/// CSS color saddlebrown (#8b4513)
+pub const SADDLEBROWN: Color = Color { red: 139, green: 69, blue: 19, alpha: 255 };
+/// CSS color salmon (#fa8072)
+pub const SALMON: Color = Color { red: 250, green: 128, blue: 114, alpha: 255 };
+/// CSS color sandybrown (#f4a460)
+pub const SANDYBROWN: Color = Color { red: 244, green: 164, blue: 96, alpha: 255 };
+/// CSS color seagreen (#2e8b57)
+pub const SEAGREEN: Color = Color { red: 46, green: 139, blue: 87, alpha: 255 };
+/// CSS color seashell (#fff5ee)
+pub const SEASHELL: Color = Color { red: 255, green: 245, blue: 238, alpha: 255 };
+/// CSS color sienna (#a0522d)
+pub const SIENNA: Color = Color { red: 160, green: 82, blue: 45, alpha: 255 };
+/// CSS color silver (#c0c0c0)
+pub const SILVER: Color = Color { red: 192, green: 192, blue: 192, alpha: 255 };
+/// CSS color skyblue (#87ceeb)
+pub const SKYBLUE: Color = Color { red: 135, green: 206, blue: 235, alpha: 255 };
+/// CSS color slateblue (#6a5acd)
+pub const SLATEBLUE: Color = Color { red: 106, green: 90, blue: 205, alpha: 255 };
+/// CSS color slategray (#708090)
+pub const SLATEGRAY: Color = Color { red: 112, green: 128, blue: 144, alpha: 255 };
+/// CSS color slategrey (#708090)
+pub const SLATEGREY: Color = Color { red: 112, green: 128, blue: 144, alpha: 255 };
+/// CSS color snow (#fffafa)
+pub const SNOW: Color = Color { red: 255, green: 250, blue: 250, alpha: 255 };
+/// CSS color springgreen (#00ff7f)
+pub const SPRINGGREEN: Color = Color { red: 0, green: 255, blue: 127, alpha: 255 };
+/// CSS color steelblue (#4682b4)
+pub const STEELBLUE: Color = Color { red: 70, green: 130, blue: 180, alpha: 255 };
+/// CSS color tan (#d2b48c)
+pub const TAN: Color = Color { red: 210, green: 180, blue: 140, alpha: 255 };
+/// CSS color teal (#008080)
+pub const TEAL: Color = Color { red: 0, green: 128, blue: 128, alpha: 255 };
+/// CSS color thistle (#d8bfd8)
+pub const THISTLE: Color = Color { red: 216, green: 191, blue: 216, alpha: 255 };
+/// CSS color tomato (#ff6347)
+pub const TOMATO: Color = Color { red: 255, green: 99, blue: 71, alpha: 255 };
+/// CSS color turquoise (#40e0d0)
+pub const TURQUOISE: Color = Color { red: 64, green: 224, blue: 208, alpha: 255 };
+/// CSS color violet (#ee82ee)
+pub const VIOLET: Color = Color { red: 238, green: 130, blue: 238, alpha: 255 };
+/// CSS color wheat (#f5deb3)
+pub const WHEAT: Color = Color { red: 245, green: 222, blue: 179, alpha: 255 };
+/// CSS color white (#ffffff)
+pub const WHITE: Color = Color { red: 255, green: 255, blue: 255, alpha: 255 };
+/// CSS color whitesmoke (#f5f5f5)
+pub const WHITESMOKE: Color = Color { red: 245, green: 245, blue: 245, alpha: 255 };
+/// CSS color yellow (#ffff00)
+pub const YELLOW: Color = Color { red: 255, green: 255, blue: 0, alpha: 255 };
+/// CSS color yellowgreen (#9acd32)
+pub const YELLOWGREEN: Color = Color { red: 154, green: 205, blue: 50, alpha: 255 };
+/// Fully transparent black
+pub const TRANSPARENT: Color = Color { red: 0, green: 0, blue: 0, alpha: 0 };
+
+/// Find a CSS named color
+///
+/// The lookup is case insensitive and ignores spaces, dashes and
+/// underscores, so "Light Sky Blue", "light-sky-blue" and "LIGHTSKYBLUE"
+/// all name the same color.
+///
+/// # Arguments
+/// * name - The name of the color
+///
+/// Return Some(Color) or None if the name is unknown
+pub fn from_name(name: &str) -> Option<Color> {
+    let key: String = name.chars()
+                          .filter(|c| *c != ' ' && *c != '-' && *c != '_')
+                          .map(|c| c.to_lowercase())
+                          .collect();
+    match key.as_slice() {
+        "aliceblue" => Some(ALICEBLUE),
+        "antiquewhite" => Some(ANTIQUEWHITE),
+        "aqua" => Some(AQUA),
+        "aquamarine" => Some(AQUAMARINE),
+        "azure" => Some(AZURE),
+        "beige" => Some(BEIGE),
+        "bisque" => Some(BISQUE),
+        "black" => Some(BLACK),
+        "blanchedalmond" => Some(BLANCHEDALMOND),
+        "blue" => Some(BLUE),
+        "blueviolet" => Some(BLUEVIOLET),
+        "brown" => Some(BROWN),
+        "burlywood" => Some(BURLYWOOD),
+        "cadetblue" => Some(CADETBLUE),
+        "chartreuse" => Some(CHARTREUSE),
+        "chocolate" => Some(CHOCOLATE),
+        "coral" => Some(CORAL),
+        "cornflowerblue" => Some(CORNFLOWERBLUE),
+        "cornsilk" => Some(CORNSILK),
+        "crimson" => Some(CRIMSON),
+        "cyan" => Some(CYAN),
+        "darkblue" => Some(DARKBLUE),
+        "darkcyan" => Some(DARKCYAN),
+        "darkgoldenrod" => Some(DARKGOLDENROD),
+        "darkgray" => Some(DARKGRAY),
+        "darkgreen" => Some(DARKGREEN),
+        "darkgrey" => Some(DARKGREY),
+        "darkkhaki" => Some(DARKKHAKI),
+        "darkmagenta" => Some(DARKMAGENTA),
+        "darkolivegreen" => Some(DARKOLIVEGREEN),
+        "darkorange" => Some(DARKORANGE),
+        "darkorchid" => Some(DARKORCHID),
+        "darkred" => Some(DARKRED),
+        "darksalmon" => Some(DARKSALMON),
+        "darkseagreen" => Some(DARKSEAGREEN),
+        "darkslateblue" => Some(DARKSLATEBLUE),
+        "darkslategray" => Some(DARKSLATEGRAY),
+        "darkslategrey" => Some(DARKSLATEGREY),
+        "darkturquoise" => Some(DARKTURQUOISE),
+        "darkviolet" => Some(DARKVIOLET),
+        "deeppink" => Some(DEEPPINK),
+        "deepskyblue" => Some(DEEPSKYBLUE),
+        "dimgray" => Some(DIMGRAY),
+        "dimgrey" => Some(DIMGREY),
+        "dodgerblue" => Some(DODGERBLUE),
+        "firebrick" => Some(FIREBRICK),
+        "floralwhite" => Some(FLORALWHITE),
+        "forestgreen" => Some(FORESTGREEN),
+        "fuchsia" => Some(FUCHSIA),
+        "gainsboro" => Some(GAINSBORO),
+        "ghostwhite" => Some(GHOSTWHITE),
+        "gold" => Some(GOLD),
+        "goldenrod" => Some(GOLDENROD),
+        "gray" => Some(GRAY),
+        "green" => Some(GREEN),
+        "greenyellow" => Some(GREENYELLOW),
+        "grey" => Some(GREY),
+        "honeydew" => Some(HONEYDEW),
+        "hotpink" => Some(HOTPINK),
+        "indianred" => Some(INDIANRED),
+        "indigo" => Some(INDIGO),
+        "ivory" => Some(IVORY),
+        "khaki" => Some(KHAKI),
+        "lavender" => Some(LAVENDER),
+        "lavenderblush" => Some(LAVENDERBLUSH),
+        "lawngreen" => Some(LAWNGREEN),
+        "lemonchiffon" => Some(LEMONCHIFFON),
+        "lightblue" => Some(LIGHTBLUE),
+        "lightcoral" => Some(LIGHTCORAL),
+        "lightcyan" => Some(LIGHTCYAN),
+        "lightgoldenrodyellow" => Some(LIGHTGOLDENRODYELLOW),
+        "lightgray" => Some(LIGHTGRAY),
+        "lightgreen" => Some(LIGHTGREEN),
+        "lightgrey" => Some(LIGHTGREY),
+        "lightpink" => Some(LIGHTPINK),
+        "lightsalmon" => Some(LIGHTSALMON),
+        "lightseagreen" => Some(LIGHTSEAGREEN),
+        "lightskyblue" => Some(LIGHTSKYBLUE),
+        "lightslategray" => Some(LIGHTSLATEGRAY),
+        "lightslategrey" => Some(LIGHTSLATEGREY),
+        "lightsteelblue" => Some(LIGHTSTEELBLUE),
+        "lightyellow" => Some(LIGHTYELLOW),
+        "lime" => Some(LIME),
+        "limegreen" => Some(LIMEGREEN),
+        "linen" => Some(LINEN),
+        "magenta" => Some(MAGENTA),
+        "maroon" => Some(MAROON),
+        "mediumaquamarine" => Some(MEDIUMAQUAMARINE),
+        "mediumblue" => Some(MEDIUMBLUE),
+        "mediumorchid" => Some(MEDIUMORCHID),
+        "mediumpurple" => Some(MEDIUMPURPLE),
+        "mediumseagreen" => Some(MEDIUMSEAGREEN),
+        "mediumslateblue" => Some(MEDIUMSLATEBLUE),
+        "mediumspringgreen" => Some(MEDIUMSPRINGGREEN),
+        "mediumturquoise" => Some(MEDIUMTURQUOISE),
+        "mediumvioletred" => Some(MEDIUMVIOLETRED),
+        "midnightblue" => Some(MIDNIGHTBLUE),
+        "mintcream" => Some(MINTCREAM),
+        "mistyrose" => Some(MISTYROSE),
+        "moccasin" => Some(MOCCASIN),
+        "navajowhite" => Some(NAVAJOWHITE),
+        "navy" => Some(NAVY),
+        "oldlace" => Some(OLDLACE),
+        "olive" => Some(OLIVE),
+        "olivedrab" => Some(OLIVEDRAB),
+        "orange" => Some(ORANGE),
+        "orangered" => Some(ORANGERED),
+        "orchid" => Some(ORCHID),
+        "palegoldenrod" => Some(PALEGOLDENROD),
+        "palegreen" => Some(PALEGREEN),
+        "paleturquoise" => Some(PALETURQUOISE),
+        "palevioletred" => Some(PALEVIOLETRED),
+        "papayawhip" => Some(PAPAYAWHIP),
+        "peachpuff" => Some(PEACHPUFF),
+        "peru" => Some(PERU),
+        "pink" => Some(PINK),
+        "plum" => Some(PLUM),
+        "powderblue" => Some(POWDERBLUE),
+        "purple" => Some(PURPLE),
+        "rebeccapurple" => Some(REBECCAPURPLE),
+        "red" => Some(RED),
+        "rosybrown" => Some(ROSYBROWN),
+        "royalblue" => Some(ROYALBLUE),
+        "saddlebrown" => Some(SADDLEBROWN),
+        "salmon" => Some(SALMON),
+        "sandybrown" => Some(SANDYBROWN),
+        "seagreen" => Some(SEAGREEN),
+        "seashell" => Some(SEASHELL),
+        "sienna" => Some(SIENNA),
+        "silver" => Some(SILVER),
+        "skyblue" => Some(SKYBLUE),
+        "slateblue" => Some(SLATEBLUE),
+        "slategray" => Some(SLATEGRAY),
+        "slategrey" => Some(SLATEGREY),
+        "snow" => Some(SNOW),
+        "springgreen" => Some(SPRINGGREEN),
+        "steelblue" => Some(STEELBLUE),
+        "tan" => Some(TAN),
+        "teal" => Some(TEAL),
+        "thistle" => Some(THISTLE),
+        "tomato" => Some(TOMATO),
+        "turquoise" => Some(TURQUOISE),
+        "violet" => Some(VIOLET),
+        "wheat" => Some(WHEAT),
+        "white" => Some(WHITE),
+        "whitesmoke" => Some(WHITESMOKE),
+        "yellow" => Some(YELLOW),
+        "yellowgreen" => Some(YELLOWGREEN),
+        "transparent" => Some(TRANSPARENT),
+        _ => None
+    }
+}
+
+/// An indexed list of colors
+///
+/// A palette can be converted to an image one pixel high, each pixel
+/// being a color of the palette, to be used as a lookup texture.
+#[derive(Clone, PartialEq, Show)]
+pub struct Palette {
+    #[doc(hidden)]
+    colors: Vec<Color>
+}
+
+impl Palette {
+    /// Create a new empty palette
+    ///
+    /// Return a new Palette
+    pub fn new() -> Palette {
+        Palette {
+            colors: Vec::new()
+        }
+    }
+
+    /// Create a palette from a list of colors
+    ///
+    /// # Arguments
+    /// * colors - The colors of the palette
+    ///
+    /// Return a new Palette
+    pub fn new_from_colors(colors: &[Color]) -> Palette {
+        Palette {
+            colors: colors.to_vec()
+        }
+    }
+
+    /// Create a palette from the distinct colors of an image
+    ///
+    /// The colors are indexed in the order they are first met, reading
+    /// the image from left to right then from top to bottom.
+    ///
+    /// # Arguments
+    /// * image - The image to read the colors from
+    /// * max_colors - Maximum number of colors of the palette
+    ///
+    /// Return Some(Palette) or None if the image has more than max_colors colors
+    pub fn new_from_image(image: &Image, max_colors: uint) -> Option<Palette> {
+        let mut palette = Palette::new();
+        let size = image.get_size();
+        for y in range(0u, size.y as uint) {
+            for x in range(0u, size.x as uint) {
+                let color = image.get_pixel(x, y);
+                if palette.find(&color).is_none() {
+                    if palette.colors.len() == max_colors {
+                        return None;
+                    }
+                    palette.colors.push(color);
+                }
+            }
+        }
+        Some(palette)
+    }
+
+    /// Add a color at the end of the palette
+    ///
+    /// # Arguments
+    /// * color - The color to add
+    ///
+    /// Return the index of the color
+    pub fn push(&mut self, color: Color) -> uint {
+        self.colors.push(color);
+        self.colors.len() - 1
+    }
+
+    /// Change a color of the palette
+    ///
+    /// # Arguments
+    /// * index - The index of the color to change
+    /// * color - The new color
+    pub fn set_color(&mut self, index: uint, color: Color) -> () {
+        self.colors[index] = color
+    }
+
+    /// Get a color of the palette
+    ///
+    /// # Arguments
+    /// * index - The index of the color
+    ///
+    /// Return Some(Color) or None if the index is out of range
+    pub fn get_color(&self, index: uint) -> Option<Color> {
+        self.colors.get(index).map(|c| *c)
+    }
+
+    /// Get all the colors of the palette
+    ///
+    /// Return a slice of the colors
+    pub fn get_colors(&self) -> &[Color] {
+        self.colors.as_slice()
+    }
+
+    /// Get the number of colors of the palette
+    ///
+    /// Return the number of colors
+    pub fn get_color_count(&self) -> uint {
+        self.colors.len()
+    }
+
+    /// Find the index of a color
+    ///
+    /// # Arguments
+    /// * color - The color to look for
+    ///
+    /// Return Some(index) or None if the color is not in the palette
+    pub fn find(&self, color: &Color) -> Option<uint> {
+        self.colors.iter().position(|c| c == color)
+    }
+
+    /// Find the index of the closest color
+    ///
+    /// The distance used is the euclidean distance between the RGBA components.
+    ///
+    /// # Arguments
+    /// * color - The color to approach
+    ///
+    /// Return Some(index) or None if the palette is empty
+    pub fn find_nearest(&self, color: &Color) -> Option<uint> {
+        let distance = |&: c: &Color| -> i32 {
+            (c.red as i32 - color.red as i32).pow(2) +
+            (c.green as i32 - color.green as i32).pow(2) +
+            (c.blue as i32 - color.blue as i32).pow(2) +
+            (c.alpha as i32 - color.alpha as i32).pow(2)
+        };
+        let mut best = None;
+        let mut best_distance = 0;
+        for (i, c) in self.colors.iter().enumerate() {
+            let d = distance(c);
+            if best.is_none() || d < best_distance {
+                best = Some(i);
+                best_distance = d;
+            }
+        }
+        best
+    }
+
+    /// Convert the palette to an image
+    ///
+    /// The image is one pixel high and as wide as the number of colors,
+    /// the pixel x being the color at index x.
+    ///
+    /// Return Some(Image) or None if the palette is empty
+    pub fn to_image(&self) -> Option<Image> {
+        if self.colors.is_empty() {
+            return None;
+        }
+        match Image::new(self.colors.len(), 1) {
+            Some(mut image) => {
+                for (i, color) in self.colors.iter().enumerate() {
+                    image.set_pixel(i, 0, color);
+                }
+                Some(image)
+            },
+            None => None
+        }
+    }
+
+    /// Save the palette to an image file
+    ///
+    /// The image has the layout described in to_image.
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to save
+    ///
+    /// Return true if saving was successful
+    pub fn save_to_file(&self, filename: &str) -> bool {
+        match self.to_image() {
+            Some(image) => image.save_to_file(filename),
+            None => false
+        }
+    }
+}
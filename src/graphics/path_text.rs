@@ -0,0 +1,258 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Text laid out along a path
+//!
+//! PathText places each glyph of a string along a polyline, rotated to
+//! follow the direction of the path, for curved labels or circular HUD text.
+
+use std::num::Float;
+
+use traits::Drawable;
+use graphics::{RenderTarget, RenderStates, Font, Color, Texture, Vertex,
+               Quads};
+use system::vector2::Vector2f;
+
+/// Text laid out along a path
+///
+/// The glyphs are placed one after the other along the path, using the
+/// advance and kerning of the font. The characters which don't fit on
+/// the path are not drawn.
+pub struct PathText<'s> {
+    #[doc(hidden)]
+    font: &'s Font,
+    #[doc(hidden)]
+    string: String,
+    #[doc(hidden)]
+    character_size: uint,
+    #[doc(hidden)]
+    color: Color,
+    #[doc(hidden)]
+    path: Vec<Vector2f>,
+    #[doc(hidden)]
+    offset: f32,
+    #[doc(hidden)]
+    vertices: Vec<Vertex>,
+    #[doc(hidden)]
+    texture: Option<Texture>
+}
+
+impl<'s> PathText<'s> {
+    /// Create a new PathText
+    ///
+    /// # Arguments
+    /// * string - The string to display
+    /// * font - The font of the text
+    /// * character_size - The size of the characters, in pixels
+    /// * path - The points of the polyline to follow
+    ///
+    /// Return a new PathText
+    pub fn new(string: &str,
+               font: &'s Font,
+               character_size: uint,
+               path: &[Vector2f]) -> PathText<'s> {
+        let mut path_text = PathText {
+            font: font,
+            string: string.to_string(),
+            character_size: character_size,
+            color: Color::white(),
+            path: path.to_vec(),
+            offset: 0.,
+            vertices: Vec::new(),
+            texture: None
+        };
+        path_text.update_vertices();
+        path_text
+    }
+
+    /// Set the string of a PathText
+    ///
+    /// # Arguments
+    /// * string - New string
+    pub fn set_string(&mut self, string: &str) -> () {
+        self.string = string.to_string();
+        self.update_vertices();
+    }
+
+    /// Get the string of a PathText
+    pub fn get_string(&self) -> &str {
+        self.string.as_slice()
+    }
+
+    /// Set the path followed by a PathText
+    ///
+    /// # Arguments
+    /// * path - The points of the polyline to follow
+    pub fn set_path(&mut self, path: &[Vector2f]) -> () {
+        self.path = path.to_vec();
+        self.update_vertices();
+    }
+
+    /// Get the path followed by a PathText
+    pub fn get_path(&self) -> &[Vector2f] {
+        self.path.as_slice()
+    }
+
+    /// Set the distance along the path where the text starts
+    ///
+    /// # Arguments
+    /// * offset - The distance from the first point of the path, in pixels
+    pub fn set_offset(&mut self, offset: f32) -> () {
+        self.offset = offset;
+        self.update_vertices();
+    }
+
+    /// Get the distance along the path where the text starts
+    pub fn get_offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Set the character size of a PathText
+    ///
+    /// # Arguments
+    /// * size - New character size, in pixels
+    pub fn set_character_size(&mut self, size: uint) -> () {
+        self.character_size = size;
+        self.update_vertices();
+    }
+
+    /// Get the character size of a PathText
+    pub fn get_character_size(&self) -> uint {
+        self.character_size
+    }
+
+    /// Set the color of a PathText
+    ///
+    /// # Arguments
+    /// * color - New color of the text
+    pub fn set_color(&mut self, color: &Color) -> () {
+        self.color = *color;
+        self.update_vertices();
+    }
+
+    /// Get the color of a PathText
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    /// Get the total length of the path
+    ///
+    /// Return the length of the path, in pixels
+    pub fn get_path_length(&self) -> f32 {
+        let mut length = 0.;
+        for i in range(1u, self.path.len()) {
+            length += distance(&self.path[i - 1], &self.path[i]);
+        }
+        length
+    }
+
+    #[doc(hidden)]
+    fn point_at(&self, distance_on_path: f32) -> Option<(Vector2f, Vector2f)> {
+        let mut remaining = distance_on_path;
+        if remaining < 0. {
+            return None
+        }
+        for i in range(1u, self.path.len()) {
+            let (a, b) = (self.path[i - 1], self.path[i]);
+            let length = distance(&a, &b);
+            if length > 0. && remaining <= length {
+                let direction = (b - a) / length;
+                return Some((a + direction * remaining, direction))
+            }
+            remaining -= length;
+        }
+        None
+    }
+
+    #[doc(hidden)]
+    fn update_vertices(&mut self) -> () {
+        self.vertices.clear();
+        let mut pen = self.offset;
+        let mut previous: Option<u32> = None;
+        for c in self.string.chars() {
+            let codepoint = c as u32;
+            match previous {
+                Some(p) => pen += self.font.get_kerning(p, codepoint,
+                                                        self.character_size),
+                None    => {}
+            }
+            previous = Some(codepoint);
+
+            let glyph = self.font.get_glyph(codepoint, self.character_size,
+                                            false, 0.);
+            let half_advance = glyph.advance / 2.;
+            let (center, direction) = match self.point_at(pen + half_advance) {
+                Some(point) => point,
+                None        => break
+            };
+            pen += glyph.advance;
+
+            let normal = Vector2f::new(-direction.y, direction.x);
+            let left = glyph.bounds.left - half_advance;
+            let right = left + glyph.bounds.width;
+            let top = glyph.bounds.top;
+            let bottom = top + glyph.bounds.height;
+            let tex = glyph.texture_rect;
+            let corners = [(left, top, tex.left, tex.top),
+                           (right, top, tex.left + tex.width, tex.top),
+                           (right, bottom, tex.left + tex.width, tex.top + tex.height),
+                           (left, bottom, tex.left, tex.top + tex.height)];
+            for &(x, y, u, v) in corners.iter() {
+                self.vertices.push(Vertex::new(
+                    &(center + direction * x + normal * y),
+                    &self.color,
+                    &Vector2f::new(u as f32, v as f32)));
+            }
+        }
+        // Fetch the texture after the glyphs, which may have grown it
+        self.texture = self.font.get_texture(self.character_size);
+    }
+}
+
+#[doc(hidden)]
+fn distance(a: &Vector2f, b: &Vector2f) -> f32 {
+    ((b.x - a.x) * (b.x - a.x) + (b.y - a.y) * (b.y - a.y)).sqrt()
+}
+
+impl<'s> Drawable for PathText<'s> {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        if !self.vertices.is_empty() {
+            let mut rs = RenderStates::default();
+            rs.texture = self.texture.as_ref();
+            render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+        }
+    }
+
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 render_states: &mut RenderStates) -> () {
+        if !self.vertices.is_empty() {
+            let mut rs = RenderStates::new(render_states.blendMode,
+                                           render_states.transform,
+                                           self.texture.as_ref(),
+                                           render_states.shader);
+            render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+        }
+    }
+}
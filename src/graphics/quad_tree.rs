@@ -0,0 +1,237 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Spatial index of drawables
+//!
+//! QuadTree stores objects with their global bounds and finds quickly the
+//! ones intersecting an area, typically the zone shown by a View, so only
+//! the visible part of a large world is submitted for drawing.
+
+use std::mem;
+
+use traits::Drawable;
+use graphics::{FloatRect, RenderTarget, View};
+
+/// A node of the tree, holding the indices of the items which fit in it
+/// but in none of its children
+struct Node {
+    bounds: FloatRect,
+    items: Vec<uint>,
+    children: Vec<Node>
+}
+
+/// Spatial index of drawables
+///
+/// Items are placed in the smallest node containing them, the nodes being
+/// split in four once they hold more than max_items items. The tree
+/// is meant for objects that don't move, clear and fill it again when
+/// they do.
+pub struct QuadTree<T> {
+    #[doc(hidden)]
+    root: Node,
+    #[doc(hidden)]
+    items: Vec<(T, FloatRect)>,
+    #[doc(hidden)]
+    max_items: uint,
+    #[doc(hidden)]
+    max_depth: uint
+}
+
+impl<T> QuadTree<T> {
+    /// Create a new empty QuadTree
+    ///
+    /// # Arguments
+    /// * bounds - The zone covered by the tree, items outside of it are
+    /// kept in the root node
+    /// * max_items - Number of items a node holds before being split
+    /// * max_depth - Maximum depth of the tree
+    ///
+    /// Return a new QuadTree
+    pub fn new(bounds: &FloatRect, max_items: uint, max_depth: uint) -> QuadTree<T> {
+        QuadTree {
+            root: Node::new(*bounds),
+            items: Vec::new(),
+            max_items: max_items,
+            max_depth: max_depth
+        }
+    }
+
+    /// Insert an item
+    ///
+    /// # Arguments
+    /// * item - The item to insert
+    /// * bounds - The global bounds of the item
+    ///
+    /// Return the index of the item
+    pub fn insert(&mut self, item: T, bounds: &FloatRect) -> uint {
+        let index = self.items.len();
+        self.items.push((item, *bounds));
+        let (max_items, max_depth) = (self.max_items, self.max_depth);
+        self.root.insert(index, &self.items, max_items, max_depth, 0);
+        index
+    }
+
+    /// Get an item
+    ///
+    /// # Arguments
+    /// * index - The index of the item
+    ///
+    /// Return Some(item) or None if the index is out of range
+    pub fn get(&self, index: uint) -> Option<&T> {
+        self.items.get(index).map(|&(ref item, _)| item)
+    }
+
+    /// Get the number of items
+    ///
+    /// Return the number of items in the tree
+    pub fn len(&self) -> uint {
+        self.items.len()
+    }
+
+    /// Remove all the items
+    pub fn clear(&mut self) -> () {
+        let bounds = self.root.bounds;
+        self.root = Node::new(bounds);
+        self.items.clear()
+    }
+
+    /// Find the items intersecting an area
+    ///
+    /// # Arguments
+    /// * area - The area, in world coordinates
+    ///
+    /// Return the items whose bounds intersect the area, in insertion order
+    pub fn query(&self, area: &FloatRect) -> Vec<&T> {
+        let mut found = Vec::new();
+        self.root.query(area, &self.items, &mut found);
+        found.sort();
+        found.iter().map(|&i| match self.items[i] { (ref item, _) => item }).collect()
+    }
+
+    /// Find the items visible through a view
+    ///
+    /// The rotation of the view is taken into account.
+    ///
+    /// # Arguments
+    /// * view - The view
+    ///
+    /// Return the items whose bounds intersect the zone shown by the view
+    pub fn query_view(&self, view: &View) -> Vec<&T> {
+        let area = view.get_inverse_transform()
+                       .transform_rect(&FloatRect::new(-1., -1., 2., 2.));
+        self.query(&area)
+    }
+}
+
+impl<T: Drawable> QuadTree<T> {
+    /// Draw the items visible through the current view of a target
+    ///
+    /// # Arguments
+    /// * render_target - The target to draw to
+    ///
+    /// Return the number of items drawn
+    pub fn draw_visible<RT: RenderTarget>(&self, render_target: &mut RT) -> uint {
+        let view = render_target.get_view();
+        let visible = self.query_view(&view);
+        for item in visible.iter() {
+            render_target.draw(*item);
+        }
+        visible.len()
+    }
+}
+
+impl Node {
+    fn new(bounds: FloatRect) -> Node {
+        Node {
+            bounds: bounds,
+            items: Vec::new(),
+            children: Vec::new()
+        }
+    }
+
+    fn insert<T>(&mut self,
+                 index: uint,
+                 items: &Vec<(T, FloatRect)>,
+                 max_items: uint,
+                 max_depth: uint,
+                 depth: uint) -> () {
+        if !self.children.is_empty() {
+            let bounds = match items[index] { (_, bounds) => bounds };
+            for child in self.children.iter_mut() {
+                if contains(&child.bounds, &bounds) {
+                    child.insert(index, items, max_items, max_depth, depth + 1);
+                    return;
+                }
+            }
+            self.items.push(index);
+            return;
+        }
+
+        self.items.push(index);
+        if self.items.len() > max_items && depth < max_depth {
+            self.split();
+            let held = mem::replace(&mut self.items, Vec::new());
+            for &i in held.iter() {
+                self.insert(i, items, max_items, max_depth, depth);
+            }
+        }
+    }
+
+    fn split(&mut self) -> () {
+        let b = self.bounds;
+        let (w, h) = (b.width / 2., b.height / 2.);
+        self.children.push(Node::new(FloatRect::new(b.left, b.top, w, h)));
+        self.children.push(Node::new(FloatRect::new(b.left + w, b.top, w, h)));
+        self.children.push(Node::new(FloatRect::new(b.left, b.top + h, w, h)));
+        self.children.push(Node::new(FloatRect::new(b.left + w, b.top + h, w, h)));
+    }
+
+    fn query<T>(&self,
+                area: &FloatRect,
+                items: &Vec<(T, FloatRect)>,
+                found: &mut Vec<uint>) -> () {
+        for &i in self.items.iter() {
+            if intersects(match items[i] { (_, ref bounds) => bounds }, area) {
+                found.push(i);
+            }
+        }
+        for child in self.children.iter() {
+            if intersects(&child.bounds, area) {
+                child.query(area, items, found);
+            }
+        }
+    }
+}
+
+fn contains(outer: &FloatRect, inner: &FloatRect) -> bool {
+    inner.left >= outer.left && inner.top >= outer.top &&
+    inner.left + inner.width <= outer.left + outer.width &&
+    inner.top + inner.height <= outer.top + outer.height
+}
+
+fn intersects(a: &FloatRect, b: &FloatRect) -> bool {
+    a.left < b.left + b.width && b.left < a.left + a.width &&
+    a.top < b.top + b.height && b.top < a.top + a.height
+}
@@ -24,7 +24,7 @@
 
 //! Specialized shape representing a rectangle
 
-use libc::{c_float, c_uint};
+use libc::{c_float, size_t};
 use std::ptr;
 
 use traits::{Drawable, Wrappable};
@@ -354,7 +354,7 @@ impl<'s> RectangleShape<'s> {
     pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
             ffi::sfRectangleShape_getPoint(self.rectangle_shape,
-                                           index as c_uint)
+                                           index as size_t)
         }
     }
 
@@ -616,6 +616,8 @@ impl<'s> Wrappable<*mut ffi::sfRectangleShape> for RectangleShape<'s> {
     }
 }
 
+impl_transformable!(RectangleShape<'s>, 's);
+
 impl<'s> Drawable for RectangleShape<'s> {
     fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_rectangle_shape(self);
@@ -26,7 +26,7 @@
 
 use std::rc::Rc;
 use std::cell::RefCell;
-use libc::{c_float, c_uint};
+use libc::{c_float, size_t};
 use std::ptr;
 
 use traits::{Drawable, Wrappable};
@@ -354,7 +354,7 @@ impl RectangleShape {
     pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
             ffi::sfRectangleShape_getPoint(self.rectangle_shape,
-                                           index as c_uint) 
+                                           index as size_t) 
         }
     }
 
@@ -618,6 +618,8 @@ impl Wrappable<*mut ffi::sfRectangleShape> for RectangleShape {
     }
 }
 
+impl_transformable!(RectangleShape);
+
 impl Drawable for RectangleShape {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_rectangle_shape_rc(self);
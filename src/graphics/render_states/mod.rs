@@ -65,7 +65,7 @@ impl<'s> RenderStates<'s> {
                shader: Option<&'s Shader<'s>>) -> RenderStates<'s> {
         RenderStates {
             sfRenderStates: ffi::sfRenderStates {
-                blendMode: blend_mode as i32,
+                blendMode: blend_mode.unwrap(),
                 transform: transform,
                 texture: ptr::null_mut(),
                 shader: ptr::null_mut()
@@ -89,7 +89,7 @@ impl<'s> RenderStates<'s> {
     pub fn default() -> RenderStates<'s> {
         RenderStates {
             sfRenderStates: ffi::sfRenderStates {
-                blendMode: BlendAlpha as i32,
+                blendMode: BlendAlpha.unwrap(),
                 transform: Transform::new_identity(),
                 texture: ptr::null_mut(),
                 shader: ptr::null_mut()
@@ -104,7 +104,7 @@ impl<'s> RenderStates<'s> {
     // Internal rsfml use only
     #[doc(hidden)]
     pub fn unwrap(&mut self) -> *mut ffi::sfRenderStates {
-        self.sfRenderStates.blendMode = self.blendMode as i32;
+        self.sfRenderStates.blendMode = self.blendMode.unwrap();
         self.sfRenderStates.transform = self.transform;
         self.sfRenderStates.texture = if !self.texture.is_none() {
             self.texture.unwrap().unwrap()
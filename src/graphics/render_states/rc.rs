@@ -67,7 +67,7 @@ impl RenderStates {
 
         RenderStates {
             sfRenderStates: ffi::sfRenderStates {
-                blendMode: blend_mode as i32,
+                blendMode: blend_mode.unwrap(),
                 transform: transform,
                 texture: ptr::null_mut(),
                 shader: ptr::null_mut()
@@ -91,7 +91,7 @@ impl RenderStates {
     pub fn default() -> RenderStates {
         RenderStates {
             sfRenderStates: ffi::sfRenderStates {
-                blendMode: BlendAlpha as i32,
+                blendMode: BlendAlpha.unwrap(),
                 transform: Transform::new_identity(),
                 texture: ptr::null_mut(),
                 shader: ptr::null_mut()
@@ -105,7 +105,7 @@ impl RenderStates {
 
     #[doc(hidden)]
     pub fn unwrap(&mut self) -> *mut ffi::sfRenderStates {
-        self.sfRenderStates.blendMode = self.blendMode as i32;
+        self.sfRenderStates.blendMode = self.blendMode.unwrap();
         self.sfRenderStates.transform = self.transform;
         self.sfRenderStates.texture = if !self.texture.is_none() {
             self.texture.as_ref().unwrap().borrow().unwrap()
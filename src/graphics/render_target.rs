@@ -78,7 +78,6 @@ pub trait RenderTarget {
     /// * view - The view to use for converting the point
     ///
     /// Return the converted point, in "world" units
-    ////
     fn map_pixel_to_coords(&self, point: &Vector2i, view: &View) -> Vector2f;
 
     /// Convert a point from window coordinates to world coordinates
@@ -126,33 +125,67 @@ pub trait RenderTarget {
     /// * point - Point to convert
     /// * view - The view to use for converting the point
     ///
-    /// Return the converted point, in "world" units
+    /// Return the converted point, in target coordinates (pixels)
     fn map_coords_to_pixel(&self, point: &Vector2f, view: &View) -> Vector2i;
 
-    /// Convert a point from window coordinates to world coordinates
+    /// Convert a point from world coordinates to window coordinates
     ///
-    /// This function finds the 2D position that matches the
-    /// given pixel of the render-window. In other words, it does
-    /// the inverse of what the graphics card does, to find the
-    /// initial position of a rendered pixel.
+    /// This function finds the pixel of the render-window that matches
+    /// the given 2D point. In other words, it goes through the same process
+    /// as the graphics card, to compute the final position of a rendered point.
     ///
     /// Initially, both coordinate systems (world units and target pixels)
     /// match perfectly. But if you define a custom view or resize your
     /// render window, this assertion is not true anymore, ie. a point
-    /// located at (10, 50) in your render-window may map to the point
-    /// (150, 75) in your 2D world -- if the view is translated by (140, 25).
+    /// located at (150, 75) in your 2D world may map to the pixel
+    /// (10, 50) of your render-window -- if the view is translated by (140, 25).
     ///
-    /// This function is typically used to find which point (or object) is
-    /// located below the mouse cursor.
+    /// This version uses the current view for calculations, see
+    /// [map_coords_to_pixel](#method.map_coords_to_pixel) if you want to use a custom view.
     ///
-    /// This version uses the current view for calculations, see the
-    /// [map_pixel_to_coords](#method.map_pixel_to_coords) function if you want to use a custom view.
+    /// # Arguments
+    /// * point - Point to convert
+    ///
+    /// Return the converted point, in target coordinates (pixels)
+    fn map_coords_to_pixel_current_view(&self, point: &Vector2f) -> Vector2i;
+
+    /// Convert a point from window coordinates to world coordinates
+    ///
+    /// Same as map_pixel_to_coords, but the view is optional: if None is
+    /// given the current view of the target is used.
     ///
     /// # Arguments
     /// * point - Pixel to convert
+    /// * view - The view to use for converting the point, or None
     ///
     /// Return the converted point, in "world" units
-    fn map_coords_to_pixel_current_view(&self, point: &Vector2f) -> Vector2i;
+    fn map_pixel_to_coords_with_view(&self,
+                                     point: &Vector2i,
+                                     view: Option<&View>) -> Vector2f {
+        match view {
+            Some(view) => self.map_pixel_to_coords(point, view),
+            None => self.map_pixel_to_coords_current_view(point)
+        }
+    }
+
+    /// Convert a point from world coordinates to window coordinates
+    ///
+    /// Same as map_coords_to_pixel, but the view is optional: if None is
+    /// given the current view of the target is used.
+    ///
+    /// # Arguments
+    /// * point - Point to convert
+    /// * view - The view to use for converting the point, or None
+    ///
+    /// Return the converted point, in target coordinates (pixels)
+    fn map_coords_to_pixel_with_view(&self,
+                                     point: &Vector2f,
+                                     view: Option<&View>) -> Vector2i {
+        match view {
+            Some(view) => self.map_coords_to_pixel(point, view),
+            None => self.map_coords_to_pixel_current_view(point)
+        }
+    }
 
     /// Draw a drawable object to the render target
     ///
@@ -216,6 +249,35 @@ pub trait RenderTarget {
     /// calls will work as expected.
     fn reset_GL_states(&mut self);
 
+    /// Activate or deactivate the target for OpenGL rendering
+    ///
+    /// Activate the target before issuing raw OpenGL calls on it.
+    /// The default implementation does nothing and returns false,
+    /// for the targets which have no OpenGL context of their own.
+    ///
+    /// # Arguments
+    /// * active - true to activate, false to deactivate
+    ///
+    /// Return true if operation was successful, false otherwise
+    fn set_active(&mut self, _active: bool) -> bool {
+        false
+    }
+
+    /// Run raw OpenGL code between SFML drawing calls
+    ///
+    /// The target is activated and the OpenGL states are saved with
+    /// push_GL_states before calling gl_code, then restored with
+    /// pop_GL_states, so the OpenGL code can't break the SFML
+    /// drawing and the reverse.
+    ///
+    /// # Arguments
+    /// * gl_code - The function issuing the OpenGL calls
+    fn with_GL_states<F: FnOnce(&mut Self)>(&mut self, gl_code: F) -> () {
+        self.set_active(true);
+        self.push_GL_states();
+        gl_code(self);
+        self.pop_GL_states()
+    }
 
     /// Draw Text
     fn draw_text(&self, text: &Text);
@@ -26,14 +26,17 @@
 
 //! Target for off-screen 2D rendering into a texture
 
-use libc::c_uint;
+use libc::{c_uint, size_t};
 use std::ptr;
 
 use traits::{Drawable, Wrappable};
 use system::vector2::{Vector2f, Vector2i, Vector2u};
 use graphics::{View, Sprite, Color, IntRect, Texture, CircleShape,
                RectangleShape, VertexArray, ConvexShape, RenderStates,
-               Shape, Text, RenderTarget, rc, Vertex, PrimitiveType};
+               Shape, Text, RenderTarget, rc, Vertex, PrimitiveType, Image};
+
+use graphics::frame_stats::{FrameStats, StatsRecorder, texture_key, texture_key_rc,
+                             shader_key, shader_key_rc};
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::graphics::render_texture as ffi;
@@ -41,7 +44,9 @@ use ffi::graphics::render_texture as ffi;
 /// Target for off-screen 2D rendering into a texture
 pub struct RenderTexture {
     #[doc(hidden)]
-    render_texture: *mut ffi::sfRenderTexture
+    render_texture: *mut ffi::sfRenderTexture,
+    #[doc(hidden)]
+    stats: StatsRecorder
 }
 
 impl RenderTexture {
@@ -69,7 +74,8 @@ impl RenderTexture {
             None
         } else {
             Some(RenderTexture {
-                    render_texture: tex
+                    render_texture: tex,
+                    stats: StatsRecorder::new()
                 })
         }
     }
@@ -79,6 +85,21 @@ impl RenderTexture {
         unsafe {
             ffi::sfRenderTexture_display(self.render_texture)
         }
+        self.stats.end_frame()
+    }
+
+    /// Get the rendering statistics of the last displayed frame
+    ///
+    /// Return the statistics between the two last calls to display
+    pub fn get_frame_stats(&self) -> FrameStats {
+        self.stats.get_last()
+    }
+
+    /// Get the rendering statistics of the frame being drawn
+    ///
+    /// Return the statistics since the last call to display
+    pub fn get_current_frame_stats(&self) -> FrameStats {
+        self.stats.get_current()
     }
 
     /// Activate or deactivate a render texture as the current target for rendering
@@ -113,6 +134,19 @@ impl RenderTexture {
         }
     }
 
+    /// Copy the current contents of a render texture to an image
+    ///
+    /// This is a slow operation, call display before to make sure
+    /// the contents are up to date.
+    ///
+    /// Return Some(Image) containing the contents or None
+    pub fn capture(&self) -> Option<Image> {
+        match self.get_texture() {
+            Some(texture) => texture.copy_to_image(),
+            None => None
+        }
+    }
+
     /// Enable or disable the smooth filter on a render texture
     ///
     /// # Arguments
@@ -137,6 +171,62 @@ impl RenderTexture {
             SFTRUE  => true
         }
     }
+
+    /// Enable or disable texture repeating
+    ///
+    /// This function is similar to Texture::set_repeated.
+    /// This parameter is disabled by default.
+    ///
+    /// # Arguments
+    /// * repeated - true to enable repeating, false to disable it
+    pub fn set_repeated(&mut self, repeated: bool) -> () {
+        unsafe {
+            match repeated {
+                true        => ffi::sfRenderTexture_setRepeated(self.render_texture,
+                                                                SFTRUE),
+                false       => ffi::sfRenderTexture_setRepeated(self.render_texture,
+                                                                SFFALSE)
+            }
+        }
+    }
+
+    /// Tell whether the texture is repeated or not
+    ///
+    /// Return true if repeat mode is enabled, false if it is disabled
+    pub fn is_repeated(&self) -> bool {
+        match unsafe { ffi::sfRenderTexture_isRepeated(self.render_texture) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Tell if the render texture will use sRGB encoding when drawing on it
+    ///
+    /// Return true if the render texture uses sRGB encoding, false otherwise
+    pub fn is_srgb(&self) -> bool {
+        match unsafe { ffi::sfRenderTexture_isSrgb(self.render_texture) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Generate a mipmap using the current texture data
+    ///
+    /// This function is similar to Texture::generate_mipmap and operates
+    /// on the texture used as the target for drawing.
+    /// Be aware that any draw operation may modify the base level image data.
+    /// For this reason, calling this function only makes sense after all
+    /// drawing is completed and display has been called. Not calling display
+    /// after subsequent drawing will lead to undefined behavior if a mipmap
+    /// had been previously generated.
+    ///
+    /// Return true if mipmap generation was successful, false if unsuccessful
+    pub fn generate_mipmap(&mut self) -> bool {
+        match unsafe { ffi::sfRenderTexture_generateMipmap(self.render_texture) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
 }
 
 impl RenderTarget for RenderTexture{
@@ -217,7 +307,7 @@ impl RenderTarget for RenderTexture{
     /// located below the mouse cursor.
     ///
     /// This version uses a custom view for calculations, see
-    /// map_pixel_to_coords if you want to use the current view of the
+    /// map_pixel_to_coords_current_view if you want to use the current view of the
     /// render-texture.
     ///
     /// # Arguments
@@ -251,8 +341,8 @@ impl RenderTarget for RenderTexture{
     /// This function is typically used to find which point (or object) is
     /// located below the mouse cursor.
     ///
-    /// This version the current view for calculations, see
-    /// map_pixel_to_coordss if you want to use a custom view
+    /// This version uses the current view for calculations, see
+    /// map_pixel_to_coords if you want to use a custom view
     ///
     /// # Arguments
     /// * point - Pixel to convert
@@ -308,8 +398,8 @@ impl RenderTarget for RenderTexture{
     /// located at (150, 75) in your 2D world may map to the pixel
     /// (10, 50) of your render-texture -- if the view is translated by (140, 25).
     ///
-    /// This version uses the default view for calculations, see
-    /// map_coords_to_pixel if you want to use as custom view.
+    /// This version uses the current view for calculations, see
+    /// map_coords_to_pixel if you want to use a custom view.
     ///
     /// # Arguments
     /// * point - Point to convert
@@ -354,6 +444,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw Text
     fn draw_text(&self, text: &Text) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.unwrap() as uint),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawText(self.render_texture,
                                           text.unwrap(),
@@ -363,6 +456,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw Text
     fn draw_text_rc(&self, text: &rc::Text) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.borrow().unwrap() as uint),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawText(self.render_texture,
                                           text.unwrap(),
@@ -372,6 +468,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw Shape
     fn draw_shape(&self, shape: &Shape) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key(shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawShape(self.render_texture,
                                            shape.unwrap(),
@@ -381,6 +480,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw Shape
     fn draw_shape_rc(&self, shape: &rc::Shape) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key_rc(&shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawShape(self.render_texture,
                                            shape.unwrap(),
@@ -390,6 +492,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw Sprite
     fn draw_sprite(&self, sprite: &Sprite) -> () {
+        self.stats.record(4,
+                          texture_key(sprite.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawSprite(self.render_texture,
                                             sprite.unwrap(),
@@ -399,6 +504,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw Sprite
     fn draw_sprite_rc(&self, sprite: &rc::Sprite) -> () {
+        self.stats.record(4,
+                          texture_key_rc(&sprite.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawSprite(self.render_texture,
                                             sprite.unwrap(),
@@ -408,6 +516,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw CircleShape
     fn draw_circle_shape(&self, circle_shape: &CircleShape) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key(circle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawCircleShape(self.render_texture,
                                                  circle_shape.unwrap(),
@@ -417,6 +528,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw CircleShape
     fn draw_circle_shape_rc(&self, circle_shape: &rc::CircleShape) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key_rc(&circle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawCircleShape(self.render_texture,
                                                  circle_shape.unwrap(),
@@ -426,6 +540,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw RectangleShape
     fn draw_rectangle_shape(&self, rectangle_shape: &RectangleShape) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key(rectangle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawRectangleShape(self.render_texture,
                                                     rectangle_shape.unwrap(),
@@ -435,6 +552,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw RectangleShape
     fn draw_rectangle_shape_rc(&self, rectangle_shape: &rc::RectangleShape) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key_rc(&rectangle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawRectangleShape(self.render_texture,
                                                     rectangle_shape.unwrap(),
@@ -444,6 +564,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw ConvexShape
     fn draw_convex_shape(&self, convex_shape: &ConvexShape) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key(convex_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawConvexShape(self.render_texture,
                                                  convex_shape.unwrap(),
@@ -453,6 +576,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw ConvexShape
     fn draw_convex_shape_rc(&self, convex_shape: &rc::ConvexShape) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key_rc(&convex_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawConvexShape(self.render_texture,
                                                  convex_shape.unwrap(),
@@ -462,6 +588,9 @@ impl RenderTarget for RenderTexture{
 
     /// Draw VertexArray
     fn draw_vertex_array(&self, vertex_array: &VertexArray) -> () {
+        self.stats.record(vertex_array.get_vertex_count(),
+                          0,
+                          0);
         unsafe {
             ffi::sfRenderTexture_drawVertexArray(self.render_texture,
                                                  vertex_array.unwrap(),
@@ -473,6 +602,9 @@ impl RenderTarget for RenderTexture{
     fn draw_text_rs(&self,
                         text: &Text,
                         rs: &mut RenderStates) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.unwrap() as uint),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawText(self.render_texture,
                                           text.unwrap(),
@@ -484,6 +616,9 @@ impl RenderTarget for RenderTexture{
     fn draw_text_rs_rc(&self,
                            text: &rc::Text,
                            rs: &mut rc::RenderStates) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.borrow().unwrap() as uint),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawText(self.render_texture,
                                           text.unwrap(),
@@ -495,6 +630,9 @@ impl RenderTarget for RenderTexture{
     fn draw_shape_rs(&self,
                          shape: &Shape,
                          rs: &mut RenderStates) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key(shape.get_texture()),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawShape(self.render_texture,
                                            shape.unwrap(),
@@ -506,6 +644,9 @@ impl RenderTarget for RenderTexture{
     fn draw_shape_rs_rc(&self,
                             shape: &rc::Shape,
                             rs: &mut rc::RenderStates) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key_rc(&shape.get_texture()),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawShape(self.render_texture,
                                            shape.unwrap(),
@@ -517,6 +658,9 @@ impl RenderTarget for RenderTexture{
     fn draw_sprite_rs(&self,
                           sprite: &Sprite,
                           rs: &mut RenderStates) -> () {
+        self.stats.record(4,
+                          texture_key(sprite.get_texture()),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawSprite(self.render_texture,
                                             sprite.unwrap(),
@@ -528,6 +672,9 @@ impl RenderTarget for RenderTexture{
     fn draw_sprite_rs_rc(&self,
                              sprite: &rc::Sprite,
                              rs: &mut rc::RenderStates) -> () {
+        self.stats.record(4,
+                          texture_key_rc(&sprite.get_texture()),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawSprite(self.render_texture,
                                             sprite.unwrap(),
@@ -539,6 +686,9 @@ impl RenderTarget for RenderTexture{
     fn draw_circle_shape_rs(&self,
                                 circle_shape: &CircleShape,
                                 rs: &mut RenderStates) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key(circle_shape.get_texture()),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawCircleShape(self.render_texture,
                                                  circle_shape.unwrap(),
@@ -550,6 +700,9 @@ impl RenderTarget for RenderTexture{
     fn draw_circle_shape_rs_rc(&self,
                                    circle_shape: &rc::CircleShape,
                                    rs: &mut rc::RenderStates) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key_rc(&circle_shape.get_texture()),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawCircleShape(self.render_texture,
                                                  circle_shape.unwrap(),
@@ -561,6 +714,9 @@ impl RenderTarget for RenderTexture{
     fn draw_rectangle_shape_rs(&self,
                                    rectangle_shape: &RectangleShape,
                                    rs: &mut RenderStates) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key(rectangle_shape.get_texture()),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawRectangleShape(self.render_texture,
                                                     rectangle_shape.unwrap(),
@@ -572,6 +728,9 @@ impl RenderTarget for RenderTexture{
     fn draw_rectangle_shape_rs_rc(&self,
                                       rectangle_shape: &rc::RectangleShape,
                                       rs: &mut rc::RenderStates) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key_rc(&rectangle_shape.get_texture()),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawRectangleShape(self.render_texture,
                                                     rectangle_shape.unwrap(),
@@ -583,6 +742,9 @@ impl RenderTarget for RenderTexture{
     fn draw_convex_shape_rs(&self,
                                 convex_shape: &ConvexShape,
                                 rs: &mut RenderStates) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key(convex_shape.get_texture()),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawConvexShape(self.render_texture,
                                                  convex_shape.unwrap(),
@@ -594,6 +756,9 @@ impl RenderTarget for RenderTexture{
     fn draw_convex_shape_rs_rc(&self,
                                    convex_shape: &rc::ConvexShape,
                                    rs: &mut rc::RenderStates) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key_rc(&convex_shape.get_texture()),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawConvexShape(self.render_texture,
                                                  convex_shape.unwrap(),
@@ -605,6 +770,9 @@ impl RenderTarget for RenderTexture{
     fn draw_vertex_array_rs(&self,
                                 vertex_array: &VertexArray,
                                 rs: &mut RenderStates) -> () {
+        self.stats.record(vertex_array.get_vertex_count(),
+                          texture_key(rs.texture),
+                          shader_key(rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawVertexArray(self.render_texture,
                                                  vertex_array.unwrap(),
@@ -616,6 +784,9 @@ impl RenderTarget for RenderTexture{
     fn draw_vertex_array_rs_rc(&self,
                                    vertex_array: &VertexArray,
                                    rs: &mut rc::RenderStates) -> () {
+        self.stats.record(vertex_array.get_vertex_count(),
+                          texture_key_rc(&rs.texture),
+                          shader_key_rc(&rs.shader));
         unsafe {
             ffi::sfRenderTexture_drawVertexArray(self.render_texture,
                                                  vertex_array.unwrap(),
@@ -628,8 +799,11 @@ impl RenderTarget for RenderTexture{
                           vertices: &[Vertex],
                           ty: PrimitiveType,
                           rs: &mut RenderStates) {
+        self.stats.record(vertices.len(),
+                          texture_key(rs.texture),
+                          shader_key(rs.shader));
 
-        let len = vertices.len() as u32;
+        let len = vertices.len() as size_t;
         unsafe {
             ffi::sfRenderTexture_drawPrimitives(self.render_texture,
                                                 &vertices[0],
@@ -643,8 +817,11 @@ impl RenderTarget for RenderTexture{
     fn draw_primitives(&self,
                        vertices: &[Vertex],
                        ty: PrimitiveType) {
+        self.stats.record(vertices.len(),
+                          0,
+                          0);
 
-        let len = vertices.len() as u32;
+        let len = vertices.len() as size_t;
         unsafe {
             ffi::sfRenderTexture_drawPrimitives(self.render_texture,
                                                 &vertices[0],
@@ -696,6 +873,15 @@ impl RenderTarget for RenderTexture{
         }
     }
 
+    /// Activate or deactivate the target for OpenGL rendering
+    ///
+    /// # Arguments
+    /// * active - true to activate, false to deactivate
+    ///
+    /// Return true if operation was successful, false otherwise
+    fn set_active(&mut self, active: bool) -> bool {
+        self.set_active(active)
+    }
 
 }
 
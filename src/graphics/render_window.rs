@@ -30,20 +30,31 @@
 //! It defines an OS window that can be painted using the other classes
 //! of the graphics module.
 
-use libc::{c_float, c_uint};
+use libc::{c_float, c_uint, size_t};
 use std::ptr;
+use std::cmp;
+use std::rc::Rc;
 use std::vec::Vec;
-use std::ffi::CString;
 
 use traits::{Drawable, Wrappable};
-use window::{ContextSettings, VideoMode, event, WindowStyle};
+use window::event::TimedEvents;
+use window::{ContextSettings, VideoMode, event, WindowStyle, Cursor, Fullscreen,
+             DefaultStyle, BorderlessFullscreen, NoStyle, WindowHandle,
+             ScaleWatcher};
 use system::vector2::{Vector2f, Vector2i, Vector2u};
+use system::{Clock, Time, sleep};
+use system::utf32::{to_utf32, to_terminated};
 use graphics::{Text, Color, Sprite, CircleShape, RectangleShape, ConvexShape,
                RenderStates, View, Image, IntRect, VertexArray, Shape, RenderTarget,
-               rc, Vertex, PrimitiveType};
+               rc, Vertex, PrimitiveType, Texture};
+
+use graphics::FrameTiming;
+use graphics::frame_stats::{FrameStats, StatsRecorder, texture_key, texture_key_rc,
+                             shader_key, shader_key_rc};
 
 use ffi::sfml_types::{SfBool, SFTRUE, SFFALSE};
 use ffi::graphics::render_window as ffi;
+use ffi::native;
 
 /// Window that can serve as a target for 2D drawing.
 ///
@@ -52,15 +63,36 @@ use ffi::graphics::render_window as ffi;
 /// of the graphics module.
 pub struct RenderWindow {
     render_window: *mut ffi::sfRenderWindow,
-    title_length: uint,
+    title: Vec<u32>,
+    windowed_style: WindowStyle,
+    fullscreen: bool,
+    from_handle: bool,
+    settings: ContextSettings,
+    vertical_sync: bool,
+    framerate_limit: uint,
+    windowed_size: Vector2u,
+    windowed_position: Option<Vector2i>,
+    stats: StatsRecorder,
+    frame_timing: Option<FrameTiming>,
+    relative_mouse: bool,
+    cursor: Option<Rc<Cursor>>,
+    always_on_top: bool,
+    scale: Rc<ScaleWatcher>,
+    clock: Rc<Clock>,
 //    current_view: Rc<RefCell<View>>,
 //    default_view: Rc<RefCell<View>>
 }
 
 /// An iterator over all the events in the events queue (internally call poll_event)
+///
+/// The iterator stops once the queue is empty, so it only yields the
+/// events pending when it is created (and those arriving meanwhile).
+/// It must not be kept after the window is destroyed.
 pub struct Events {
+    #[doc(hidden)]
     render_window: *mut ffi::sfRenderWindow,
-    event: event::raw::sfEvent,
+    #[doc(hidden)]
+    scale: Rc<ScaleWatcher>
 }
 
 impl RenderWindow {
@@ -87,23 +119,7 @@ impl RenderWindow {
                title: &str,
                style: WindowStyle,
                settings: &ContextSettings) -> Option<RenderWindow> {
-        let mut sf_render_win: *mut ffi::sfRenderWindow = ptr::null_mut();
-        let c_str = CString::from_slice(title.as_bytes()).as_ptr();
-        unsafe {
-            sf_render_win = ffi::sfRenderWindow_create(mode.unwrap(),
-                                                       c_str,
-                                                       style as u32,
-                                                       settings);
-        }
-        if sf_render_win.is_null() {
-            None
-        } else {
-            Some (RenderWindow {
-                      render_window: sf_render_win,
-                      // event: sf_ev,
-                      title_length: title.len()
-            })
-        }
+        RenderWindow::new_with_unicode(mode, to_utf32(title), style, settings)
     }
 
     /// Construct a new render window (with a UTF-32 title)
@@ -130,20 +146,113 @@ impl RenderWindow {
                             style: WindowStyle,
                             settings: &ContextSettings) -> Option<RenderWindow> {
 
+        let title = to_terminated(title);
         let sf_render_win: *mut ffi::sfRenderWindow;
+        let (mode, sf_style) = style.resolve(mode);
         unsafe {
             sf_render_win = ffi::sfRenderWindow_createUnicode(mode.unwrap(),
                                                               title.as_ptr() as *mut u32,
-                                                              style as u32,
+                                                              sf_style,
                                                               settings);
         }
         if sf_render_win.is_null() {
             None
         } else {
+            if style == BorderlessFullscreen {
+                unsafe { ffi::sfRenderWindow_setPosition(sf_render_win, Vector2i::new(0, 0)) }
+            }
             Some (RenderWindow {
                     render_window: sf_render_win,
                     // event: sf_ev,
-                    title_length: title.len()
+                    title: title,
+                    windowed_style: if style == Fullscreen { DefaultStyle } else { style },
+                    fullscreen: style == Fullscreen,
+                    from_handle: false,
+                    settings: *settings,
+                    vertical_sync: false,
+                    framerate_limit: 0,
+                    windowed_size: Vector2u::new(mode.width as u32, mode.height as u32),
+                    windowed_position: None,
+                    stats: StatsRecorder::new(),
+                    frame_timing: None,
+                    relative_mouse: false,
+                    cursor: None,
+                    always_on_top: false,
+                    scale: ScaleWatcher::new(unsafe {
+                        ffi::sfRenderWindow_getSystemHandle(sf_render_win)
+                    }),
+                    clock: Rc::new(Clock::new())
+            })
+        }
+    }
+
+    /// Construct a new render window kept above all the other windows
+    ///
+    /// This is RenderWindow::new followed by set_always_on_top, for
+    /// companion windows and overlays which must start on top.
+    ///
+    /// # Arguments
+    /// * mode - Video mode to use (defines the width, height and depth of the rendering area of the render window)
+    /// * title - Title of the render window
+    /// * style - Window style
+    /// * settings - Additional settings for the underlying OpenGL context
+    ///
+    /// Return Some(RenderWindow) or None
+    pub fn new_always_on_top(mode: VideoMode,
+                             title: &str,
+                             style: WindowStyle,
+                             settings: &ContextSettings) -> Option<RenderWindow> {
+        RenderWindow::new(mode, title, style, settings).map(|mut render_window| {
+            render_window.set_always_on_top(true);
+            render_window
+        })
+    }
+
+    /// Construct a render window from an existing control
+    ///
+    /// Use this constructor if you want to create an OpenGL rendering
+    /// area into an already existing control, like a widget of a GUI
+    /// toolkit. Such a render window belongs to its control and can't
+    /// be switched to fullscreen.
+    ///
+    /// The second parameter is a pointer to a structure specifying
+    /// advanced OpenGL context settings such as antialiasing,
+    /// depth-buffer bits, etc.
+    ///
+    /// # Arguments
+    /// * handle - Platform-specific handle of the control
+    /// * settings - Additional settings for the underlying OpenGL context
+    ///
+    /// Return Some(RenderWindow) or None
+    pub fn new_from_handle(handle: WindowHandle,
+                           settings: &ContextSettings) -> Option<RenderWindow> {
+        let sf_render_win = unsafe {
+            ffi::sfRenderWindow_createFromHandle(handle, settings)
+        };
+        if sf_render_win.is_null() {
+            None
+        } else {
+            let size = unsafe { ffi::sfRenderWindow_getSize(sf_render_win) };
+            Some (RenderWindow {
+                    render_window: sf_render_win,
+                    title: vec![0],
+                    windowed_style: NoStyle,
+                    fullscreen: false,
+                    from_handle: true,
+                    settings: *settings,
+                    vertical_sync: false,
+                    framerate_limit: 0,
+                    windowed_size: size,
+                    windowed_position: None,
+                    stats: StatsRecorder::new(),
+                    frame_timing: None,
+                    relative_mouse: false,
+                    cursor: None,
+                    always_on_top: false,
+                    scale: ScaleWatcher::new(unsafe {
+                        ffi::sfRenderWindow_getSystemHandle(sf_render_win)
+                    }),
+                    clock: Rc::new(Clock::new())
             })
         }
     }
@@ -153,11 +262,12 @@ impl RenderWindow {
     /// # Arguments
     /// * title - New title
     pub fn set_unicode_title(&mut self, title: Vec<u32>) -> () {
+        let title = to_terminated(title);
         unsafe {
-            self.title_length = title.len();
             ffi::sfRenderWindow_setUnicodeTitle(self.render_window,
                                                 title.as_ptr())
         }
+        self.title = title;
     }
 
     /// Change a render window's icon
@@ -166,11 +276,14 @@ impl RenderWindow {
     /// # Arguments
     /// * width - Icon's width, in pixels
     /// * height - Icon's height, in pixels
-    /// * pixels - Vector of pixels
+    /// * pixels - Slice of pixels
     pub fn set_icon(&mut self,
                     width: uint,
                     height: uint,
                     pixels: &[u8]) -> () {
+        if pixels.len() < width * height * 4 {
+            panic!("Not enough pixels for the icon")
+        }
         unsafe {
             ffi::sfRenderWindow_setIcon(self.render_window,
                                         width as c_uint,
@@ -179,14 +292,51 @@ impl RenderWindow {
         }
     }
 
+    /// Change a render window's icon to an image
+    ///
+    /// # Arguments
+    /// * image - The icon image
+    pub fn set_icon_from_image(&mut self, image: &Image) -> () {
+        let size = image.get_size();
+        self.set_icon(size.x as uint, size.y as uint, image.get_pixels())
+    }
+
     /// Return an iterator over all the event currently in the events queue.
+    ///
+    /// This replaces the usual poll_event loop:
+    ///
+    /// ```ignore
+    /// for event in window.events() {
+    ///     match event {
+    ///         event::Closed => window.close(),
+    ///         _             => {}
+    ///     }
+    /// }
+    /// ```
     pub fn events(&self) -> Events {
         Events {
-            render_window: self.render_window.clone(),
-            event: event::raw::sfEvent { data: [032; 6u] }
+            render_window: self.render_window,
+            scale: self.scale.clone()
         }
     }
 
+    /// Return an iterator over the events in the events queue, with the
+    /// time they were polled at
+    ///
+    /// The times come from a monotonic clock started when the window was
+    /// created, the same as get_time, so they can be compared across
+    /// frames to detect double clicks or long presses.
+    pub fn timed_events(&self) -> TimedEvents<Events> {
+        TimedEvents::new(self.events(), self.clock.clone())
+    }
+
+    /// Get the time elapsed since the window was created
+    ///
+    /// Return the time on the clock used to stamp the events
+    pub fn get_time(&self) -> Time {
+        self.clock.get_elapsed_time()
+    }
+
     /// Pop the event on top of event queue, if any, and return it
     ///
     /// This function is not blocking: if there's no pending event then
@@ -197,7 +347,11 @@ impl RenderWindow {
     ///
     /// Return the event if an event was returned, or NoEvent if the event queue was empty
     pub fn poll_event(&mut self) -> event::Event {
-        let mut event = event::raw::sfEvent { data: [032; 6u] };
+        match self.scale.take_pending() {
+            Some(event) => return event,
+            None        => ()
+        }
+        let mut event = event::raw::sfEvent { data: [032; 7u] };
         let have_event: bool =  unsafe {
             match ffi::sfRenderWindow_pollEvent(self.render_window, &mut event) {
                 SFFALSE     => false,
@@ -207,7 +361,8 @@ impl RenderWindow {
         if have_event == false {
             event::NoEvent
         } else {
-            event::raw::get_wrapped_event(&mut event)
+            self.scale.watch(self.get_system_handle(),
+                             event::raw::get_wrapped_event(&mut event))
         }
     }
 
@@ -223,7 +378,11 @@ impl RenderWindow {
     ///
     /// Return the event or NoEvent if an error has occured
     pub fn wait_event(&mut self) -> event::Event {
-        let mut event = event::raw::sfEvent { data: [032; 6u] };
+        match self.scale.take_pending() {
+            Some(event) => return event,
+            None        => ()
+        }
+        let mut event = event::raw::sfEvent { data: [032; 7u] };
         let have_event: bool =  unsafe {
             match ffi::sfRenderWindow_waitEvent(self.render_window, &mut event) {
                 SFFALSE     => false,
@@ -233,7 +392,34 @@ impl RenderWindow {
         if have_event == false {
             event::NoEvent
         } else {
-            event::raw::get_wrapped_event(&mut event)
+            self.scale.watch(self.get_system_handle(),
+                             event::raw::get_wrapped_event(&mut event))
+        }
+    }
+
+    /// Wait for an event and return it, giving up after a timeout
+    ///
+    /// Unlike wait_event this doesn't block forever, which lets
+    /// event-driven applications wake up periodically to run timers.
+    /// The window is polled every few milliseconds while waiting.
+    ///
+    /// # Arguments
+    /// * timeout - Maximum time to wait
+    ///
+    /// Return Some(event) or None if no event arrived before the timeout
+    pub fn wait_event_timeout(&mut self, timeout: Time) -> Option<event::Event> {
+        let clock = Clock::new();
+        loop {
+            match self.poll_event() {
+                event::NoEvent => (),
+                event          => return Some(event)
+            }
+            let remaining = timeout.as_microseconds() -
+                clock.get_elapsed_time().as_microseconds();
+            if remaining <= 0 {
+                return None;
+            }
+            sleep(Time::with_microseconds(cmp::min(remaining, 5000)));
         }
     }
 
@@ -267,6 +453,90 @@ impl RenderWindow {
         }
     }
 
+    /// Switch between fullscreen and windowed mode
+    ///
+    /// The window is recreated with the desktop video mode in
+    /// fullscreen, or with its previous size, style and position in
+    /// windowed mode. The title, view, vertical synchronization,
+    /// framerate limit, cursor, relative mouse mode and always on top
+    /// state are restored.
+    /// The icon and key repeat settings are not.
+    ///
+    /// # Arguments
+    /// * fullscreen - true for fullscreen, false for windowed
+    ///
+    /// Return true if the window was recreated, false if it failed or if
+    /// the window was created from a handle (the window is then left as
+    /// it was)
+    pub fn set_fullscreen(&mut self, fullscreen: bool) -> bool {
+        if fullscreen == self.fullscreen {
+            return true;
+        }
+        if self.from_handle {
+            return false;
+        }
+        let view = match self.get_view().clone_opt() {
+            Some(view) => view,
+            None       => return false
+        };
+        let (mode, style) = if fullscreen {
+            (VideoMode::get_desktop_mode(), Fullscreen)
+        } else {
+            (VideoMode::new_init(self.windowed_size.x as uint,
+                                 self.windowed_size.y as uint,
+                                 32),
+             self.windowed_style)
+        };
+        let (mode, sf_style) = style.resolve(mode);
+        let sf_render_win = unsafe {
+            ffi::sfRenderWindow_createUnicode(mode.unwrap(),
+                                              self.title.as_ptr() as *mut u32,
+                                              sf_style,
+                                              &self.settings)
+        };
+        if sf_render_win.is_null() {
+            return false;
+        }
+        if fullscreen {
+            self.windowed_size = self.get_size();
+            self.windowed_position = Some(self.get_position());
+        }
+        unsafe {
+            ffi::sfRenderWindow_destroy(self.render_window);
+        }
+        self.render_window = sf_render_win;
+        self.fullscreen = fullscreen;
+        if !fullscreen {
+            match self.windowed_position {
+                Some(position) => self.set_position(&position),
+                None           => ()
+            }
+        }
+        self.set_view(&view);
+        let vertical_sync = self.vertical_sync;
+        self.set_vertical_sync_enabled(vertical_sync);
+        let framerate_limit = self.framerate_limit;
+        self.set_framerate_limit(framerate_limit);
+        match self.cursor {
+            Some(ref cursor) => unsafe {
+                ffi::sfRenderWindow_setMouseCursor(self.render_window, cursor.unwrap())
+            },
+            None => ()
+        }
+        if self.relative_mouse {
+            self.set_relative_mouse_mode(true);
+        }
+        if self.always_on_top {
+            native::set_always_on_top(self.get_system_handle(), true);
+        }
+        true
+    }
+
+    /// Tell whether the window is in fullscreen mode
+    pub fn is_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+
     /// Display on screen what has been rendered to the window so far
     ///
     /// This function is typically called after all OpenGL rendering
@@ -277,6 +547,48 @@ impl RenderWindow {
         unsafe {
             ffi::sfRenderWindow_display(self.render_window)
         }
+        self.stats.end_frame();
+        match self.frame_timing {
+            Some(ref mut timing) => timing.tick(),
+            None                 => ()
+        }
+    }
+
+    /// Get the rendering statistics of the last displayed frame
+    ///
+    /// The statistics count every draw call made on the window
+    /// between the two last calls to display.
+    ///
+    /// Return the statistics of the last frame
+    pub fn get_frame_stats(&self) -> FrameStats {
+        self.stats.get_last()
+    }
+
+    /// Get the rendering statistics of the frame being drawn
+    ///
+    /// Return the statistics since the last call to display
+    pub fn get_current_frame_stats(&self) -> FrameStats {
+        self.stats.get_current()
+    }
+
+    /// Enable or disable the measure of the frame times
+    ///
+    /// When enabled, the time between two calls to display is measured
+    /// over the last 300 frames. Disabling it drops the measures.
+    ///
+    /// # Arguments
+    /// * enabled - true to measure the frame times
+    pub fn set_frame_timing_enabled(&mut self, enabled: bool) -> () {
+        if enabled != self.frame_timing.is_some() {
+            self.frame_timing = if enabled { Some(FrameTiming::new(300)) } else { None };
+        }
+    }
+
+    /// Get the frame time statistics of the window
+    ///
+    /// Return Some(FrameTiming) or None if the measure is disabled
+    pub fn get_frame_timing(&self) -> Option<&FrameTiming> {
+        self.frame_timing.as_ref()
     }
 
     /// Limit the framerate to a maximum fixed frequency
@@ -293,6 +605,7 @@ impl RenderWindow {
             ffi::sfRenderWindow_setFramerateLimit(self.render_window,
                                                   limit as c_uint)
         }
+        self.framerate_limit = limit;
     }
 
     /// Get the settings of the OpenGL context of a window
@@ -312,15 +625,13 @@ impl RenderWindow {
 
     /// Change the title of a window
     ///
+    /// The title can contain any Unicode character.
+    ///
     /// # Arguments
     /// * title - New title
     ////
     pub fn set_title(&mut self, title: &str) -> () {
-        let c_str = CString::from_slice(title.as_bytes()).as_ptr();
-        unsafe {
-            ffi::sfRenderWindow_setTitle(self.render_window, c_str);
-        }
-        self.title_length = title.len();
+        self.set_unicode_title(to_utf32(title))
     }
 
     /// Show or hide a window
@@ -338,10 +649,65 @@ impl RenderWindow {
         }
     }
 
+    /// Change the opacity of a window
+    ///
+    /// This goes through the native window handle. On Linux the
+    /// window manager must support _NET_WM_WINDOW_OPACITY (most
+    /// compositing ones do).
+    ///
+    /// # Arguments
+    /// * opacity - Opacity, from 0 (fully transparent) to 1 (opaque)
+    ///
+    /// Return true if the opacity was changed
+    pub fn set_opacity(&mut self, opacity: f32) -> bool {
+        native::set_opacity(self.get_system_handle(), opacity)
+    }
+
+    /// Keep a window above all the other ones, or stop doing so
+    ///
+    /// This goes through the native window handle. Use
+    /// new_always_on_top to have the window start on top.
+    ///
+    /// # Arguments
+    /// * on_top - true to keep the window on top, false for the normal behavior
+    ///
+    /// Return true if the request was sent to the window system
+    pub fn set_always_on_top(&mut self, on_top: bool) -> bool {
+        self.always_on_top = on_top;
+        native::set_always_on_top(self.get_system_handle(), on_top)
+    }
+
+    /// Get the OS-specific handle of the window
+    ///
+    /// The type of the returned handle is WindowHandle, which is
+    /// HWND on Windows, Window on Linux/FreeBSD and NSWindow on OS X.
+    /// You shouldn't need to use this function, unless you have
+    /// very specific stuff to implement that SFML doesn't support,
+    /// or implement a temporary workaround until a bug is fixed.
+    ///
+    /// Return the system handle of the window
+    pub fn get_system_handle(&self) -> WindowHandle {
+        unsafe {
+            ffi::sfRenderWindow_getSystemHandle(self.render_window)
+        }
+    }
+
+    /// Get the scale factor of the window
+    ///
+    /// The scale factor is the ratio between the DPI of the monitor and
+    /// the usual 96 DPI: fonts and layouts should be scaled by it to keep
+    /// their physical size. A ScaleFactorChanged event is sent when it
+    /// changes.
+    ///
+    /// Return the scale factor, 1 if it can't be known
+    pub fn get_scale_factor(&self) -> f32 {
+        native::get_scale_factor(self.get_system_handle())
+    }
+
     /// Show or hide the mouse cursor
     ///
     /// # Arguments
-    /// * visible - true to  false to hide
+    /// * visible - true to show, false to hide
     ////
     pub fn set_mouse_cursor_visible(&mut self, visible: bool) -> () {
         let tmp: SfBool = match visible {
@@ -353,6 +719,64 @@ impl RenderWindow {
         }
     }
 
+    /// Grab or release the mouse cursor
+    ///
+    /// If set, grabs the mouse cursor inside this window's client
+    /// area so it may no longer be moved outside its bounds.
+    /// Note that grabbing is only active while the window has
+    /// focus.
+    ///
+    /// # Arguments
+    /// * grabbed - true to enable, false to disable
+    pub fn set_mouse_cursor_grabbed(&mut self, grabbed: bool) -> () {
+        let tmp: SfBool = match grabbed {
+            true    => SFTRUE,
+            false   => SFFALSE
+        };
+        unsafe {
+            ffi::sfRenderWindow_setMouseCursorGrabbed(self.render_window, tmp);
+        }
+    }
+
+    /// Set the displayed cursor to a native system cursor or a custom one
+    ///
+    /// The window keeps the cursor alive for as long as it displays it,
+    /// and the same cursor can be shared by several windows.
+    ///
+    /// # Arguments
+    /// * cursor - Cursor to display
+    pub fn set_mouse_cursor(&mut self, cursor: Rc<Cursor>) -> () {
+        unsafe {
+            ffi::sfRenderWindow_setMouseCursor(self.render_window, cursor.unwrap())
+        }
+        self.cursor = Some(cursor);
+    }
+
+    /// Request the current window to be made the active foreground window
+    ///
+    /// At any given time, only one window may have the input focus
+    /// to receive input events such as keystrokes or mouse events.
+    /// If a window requests focus, it only hints to the operating
+    /// system, that it would like to be focused. The operating system
+    /// is free to deny the request.
+    pub fn request_focus(&mut self) -> () {
+        unsafe {
+            ffi::sfRenderWindow_requestFocus(self.render_window)
+        }
+    }
+
+    /// Check whether the window has the input focus
+    ///
+    /// The GainedFocus and LostFocus events report the changes.
+    ///
+    /// Return true if the window has focus, false otherwise
+    pub fn has_focus(&self) -> bool {
+        match unsafe { ffi::sfRenderWindow_hasFocus(self.render_window) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
     /// Enable or disable vertical synchronization
     ///
     /// Activating vertical synchronization will limit the number
@@ -372,6 +796,7 @@ impl RenderWindow {
         unsafe {
             ffi::sfRenderWindow_setVerticalSyncEnabled(self.render_window, tmp);
         }
+        self.vertical_sync = enabled;
     }
 
     /// Enable or disable automatic key-repeat
@@ -515,6 +940,67 @@ impl RenderWindow {
         }
     }
 
+    /// Enable or disable the relative mouse mode
+    ///
+    /// In relative mode the cursor is hidden, grabbed and kept at the
+    /// center of the render window, so get_mouse_delta reports the motion of
+    /// the mouse even when the cursor would have reached the edge of
+    /// the screen. This is the usual mode of camera-look controls.
+    ///
+    /// Keeping the cursor centered generates MouseMoved events,
+    /// ignore them while the relative mode is enabled.
+    ///
+    /// # Arguments
+    /// * enabled - true to enable, false to disable
+    pub fn set_relative_mouse_mode(&mut self, enabled: bool) -> () {
+        self.relative_mouse = enabled;
+        self.set_mouse_cursor_visible(!enabled);
+        self.set_mouse_cursor_grabbed(enabled);
+        if enabled {
+            let center = self.get_window_center();
+            self.set_mouse_position(&center);
+        }
+    }
+
+    /// Tell whether the relative mouse mode is enabled
+    pub fn is_relative_mouse_mode(&self) -> bool {
+        self.relative_mouse
+    }
+
+    /// Get the motion of the mouse since the last call
+    ///
+    /// Only available in relative mode, call it once per frame.
+    /// The motion is zero while the window doesn't have the focus.
+    ///
+    /// Return the motion of the mouse, in pixels
+    pub fn get_mouse_delta(&mut self) -> Vector2i {
+        if !self.relative_mouse || !self.has_focus() {
+            return Vector2i::new(0, 0);
+        }
+        let center = self.get_window_center();
+        let position = self.get_mouse_position();
+        self.set_mouse_position(&center);
+        Vector2i::new(position.x - center.x, position.y - center.y)
+    }
+
+    /// Get the center of the render window, in window coordinates
+    fn get_window_center(&self) -> Vector2i {
+        let size = self.get_size();
+        Vector2i::new((size.x / 2) as i32, (size.y / 2) as i32)
+    }
+
+    /// Get the current position of a touch relatively to a render window
+    ///
+    /// # Arguments
+    /// * finger - Finger index
+    ///
+    /// Return the current position of the finger, relative to the render window
+    pub fn get_touch_position(&self, finger: uint) -> Vector2i {
+        unsafe {
+            ffi::sfTouch_getPositionRenderWindow(finger as c_uint, self.render_window)
+        }
+    }
+
     /// Copy the current contents of a render window to an image
     ///
     /// This is a slow operation, whose main purpose is to make
@@ -525,14 +1011,34 @@ impl RenderWindow {
     /// You can also draw things directly to a texture with the
     /// RenderWindow.
     ///
-    /// Return a new image containing the captured contents
-    ////
+    /// The contents are copied through a texture, call this function
+    /// before display to capture the frame being drawn.
+    ///
+    /// Return Some(Image) containing the captured contents or None
     pub fn capture(&mut self) -> Option<Image> {
-        let img = unsafe { ffi::sfRenderWindow_capture(self.render_window) };
-        if img.is_null() {
-            None
-        } else {
-            Some(Wrappable::wrap(img))
+        let size = self.get_size();
+        match Texture::new(size.x as uint, size.y as uint) {
+            Some(mut texture) => {
+                texture.update_from_render_window(self, 0, 0);
+                texture.copy_to_image()
+            },
+            None => None
+        }
+    }
+
+    /// Save the current contents of a render window to a file
+    ///
+    /// The format of the image is deduced from the extension
+    /// of the filename, see capture for the limitations.
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to save
+    ///
+    /// Return true if saving was successful
+    pub fn save_screenshot(&mut self, filename: &str) -> bool {
+        match self.capture() {
+            Some(image) => image.save_to_file(filename),
+            None => false
         }
     }
 
@@ -586,6 +1092,15 @@ impl RenderTarget for RenderWindow{
         }
     }
 
+    /// Activate or deactivate the target for OpenGL rendering
+    ///
+    /// # Arguments
+    /// * active - true to activate, false to deactivate
+    ///
+    /// Return true if operation was successful, false otherwise
+    fn set_active(&mut self, active: bool) -> bool {
+        self.set_active(active)
+    }
 
     /// Change the current active view of a render window
     ///
@@ -800,6 +1315,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a Text
     fn draw_text(&self, text: &Text) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.unwrap() as uint),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawText(self.render_window,
                                          text.unwrap(),
@@ -809,6 +1327,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a Text
     fn draw_text_rc(&self, text: &rc::Text) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.borrow().unwrap() as uint),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawText(self.render_window,
                                          text.unwrap(),
@@ -818,6 +1339,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a Shape
     fn draw_shape(&self, shape: &Shape) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key(shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawShape(self.render_window,
                                           shape.unwrap(),
@@ -827,6 +1351,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a Shape
     fn draw_shape_rc(&self, shape: &rc::Shape) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key_rc(&shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawShape(self.render_window,
                                           shape.unwrap(),
@@ -836,6 +1363,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a sprite
     fn draw_sprite(&self, sprite: &Sprite) -> () {
+        self.stats.record(4,
+                          texture_key(sprite.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawSprite(self.render_window,
                                            sprite.unwrap(),
@@ -845,6 +1375,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a sprite
     fn draw_sprite_rc(&self, sprite: &rc::Sprite) -> () {
+        self.stats.record(4,
+                          texture_key_rc(&sprite.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawSprite(self.render_window,
                                            sprite.unwrap(),
@@ -854,6 +1387,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a CircleShape
     fn draw_circle_shape(&self, circle_shape: &CircleShape) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key(circle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawCircleShape(self.render_window,
                                                 circle_shape.unwrap(),
@@ -863,6 +1399,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a CircleShape
     fn draw_circle_shape_rc(&self, circle_shape: &rc::CircleShape) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key_rc(&circle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawCircleShape(self.render_window,
                                                 circle_shape.unwrap(),
@@ -872,6 +1411,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a RectangleShape
     fn draw_rectangle_shape(&self, rectangle_shape: &RectangleShape) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key(rectangle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawRectangleShape(self.render_window,
                                                    rectangle_shape.unwrap(),
@@ -881,6 +1423,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a RectangleShape
     fn draw_rectangle_shape_rc(&self, rectangle_shape: &rc::RectangleShape) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key_rc(&rectangle_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawRectangleShape(self.render_window,
                                                    rectangle_shape.unwrap(),
@@ -890,6 +1435,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a ConvexShape
     fn draw_convex_shape(&self, convex_shape: &ConvexShape) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key(convex_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawConvexShape(self.render_window,
                                                 convex_shape.unwrap(),
@@ -899,6 +1447,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a ConvexShape
     fn draw_convex_shape_rc(&self, convex_shape: &rc::ConvexShape) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key_rc(&convex_shape.get_texture()),
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawConvexShape(self.render_window,
                                                 convex_shape.unwrap(),
@@ -908,6 +1459,9 @@ impl RenderTarget for RenderWindow{
 
     /// Draw a VertexArray
     fn draw_vertex_array(&self, vertex_array: &VertexArray) -> () {
+        self.stats.record(vertex_array.get_vertex_count(),
+                          0,
+                          0);
         unsafe {
             ffi::sfRenderWindow_drawVertexArray(self.render_window,
                                                 vertex_array.unwrap(),
@@ -919,6 +1473,9 @@ impl RenderTarget for RenderWindow{
     fn draw_text_rs(&self,
                         text: &Text,
                         render_states: &mut RenderStates) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.unwrap() as uint),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawText(self.render_window,
                                          text.unwrap(),
@@ -930,6 +1487,9 @@ impl RenderTarget for RenderWindow{
     fn draw_text_rs_rc(&self,
                            text: &rc::Text,
                            render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(text.get_unicode_string().len() * 4,
+                          text.get_font().map_or(0, |font| font.borrow().unwrap() as uint),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawText(self.render_window,
                                          text.unwrap(),
@@ -941,6 +1501,9 @@ impl RenderTarget for RenderWindow{
     fn draw_shape_rs(&self,
                          shape: &Shape,
                          render_states: &mut RenderStates) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key(shape.get_texture()),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawShape(self.render_window,
                                           shape.unwrap(),
@@ -952,6 +1515,9 @@ impl RenderTarget for RenderWindow{
     fn draw_shape_rs_rc(&self,
                             shape: &rc::Shape,
                             render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(shape.get_point_count() + 2,
+                          texture_key_rc(&shape.get_texture()),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawShape(self.render_window,
                                           shape.unwrap(),
@@ -963,6 +1529,9 @@ impl RenderTarget for RenderWindow{
     fn draw_sprite_rs(&self,
                           sprite: &Sprite,
                           render_states: &mut RenderStates) -> () {
+        self.stats.record(4,
+                          texture_key(sprite.get_texture()),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawSprite(self.render_window,
                                            sprite.unwrap(),
@@ -974,6 +1543,9 @@ impl RenderTarget for RenderWindow{
     fn draw_sprite_rs_rc(&self,
                              sprite: &rc::Sprite,
                              render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(4,
+                          texture_key_rc(&sprite.get_texture()),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawSprite(self.render_window,
                                            sprite.unwrap(),
@@ -985,6 +1557,9 @@ impl RenderTarget for RenderWindow{
     fn draw_circle_shape_rs(&self,
                                 circle_shape: &CircleShape,
                                 render_states: &mut RenderStates) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key(circle_shape.get_texture()),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawCircleShape(self.render_window,
                                                 circle_shape.unwrap(),
@@ -996,6 +1571,9 @@ impl RenderTarget for RenderWindow{
     fn draw_circle_shape_rs_rc(&self,
                                    circle_shape: &rc::CircleShape,
                                    render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(circle_shape.get_point_count() + 2,
+                          texture_key_rc(&circle_shape.get_texture()),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawCircleShape(self.render_window,
                                                 circle_shape.unwrap(),
@@ -1007,6 +1585,9 @@ impl RenderTarget for RenderWindow{
     fn draw_rectangle_shape_rs(&self,
                                    rectangle_shape: &RectangleShape,
                                    render_states: &mut RenderStates) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key(rectangle_shape.get_texture()),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawRectangleShape(self.render_window,
                                                    rectangle_shape.unwrap(),
@@ -1018,6 +1599,9 @@ impl RenderTarget for RenderWindow{
     fn draw_rectangle_shape_rs_rc(&self,
                                       rectangle_shape: &rc::RectangleShape,
                                       render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(rectangle_shape.get_point_count() + 2,
+                          texture_key_rc(&rectangle_shape.get_texture()),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawRectangleShape(self.render_window,
                                                    rectangle_shape.unwrap(),
@@ -1029,6 +1613,9 @@ impl RenderTarget for RenderWindow{
     fn draw_convex_shape_rs(&self,
                                 convex_shape: &ConvexShape,
                                 render_states: &mut RenderStates) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key(convex_shape.get_texture()),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawConvexShape(self.render_window,
                                                 convex_shape.unwrap(),
@@ -1040,6 +1627,9 @@ impl RenderTarget for RenderWindow{
     fn draw_convex_shape_rs_rc(&self,
                                    convex_shape: &rc::ConvexShape,
                                    render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(convex_shape.get_point_count() + 2,
+                          texture_key_rc(&convex_shape.get_texture()),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawConvexShape(self.render_window,
                                                 convex_shape.unwrap(),
@@ -1051,6 +1641,9 @@ impl RenderTarget for RenderWindow{
     fn draw_vertex_array_rs(&self,
                                 vertex_array: &VertexArray,
                                 render_states: &mut RenderStates) -> () {
+        self.stats.record(vertex_array.get_vertex_count(),
+                          texture_key(render_states.texture),
+                          shader_key(render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawVertexArray(self.render_window,
                                                 vertex_array.unwrap(),
@@ -1062,6 +1655,9 @@ impl RenderTarget for RenderWindow{
     fn draw_vertex_array_rs_rc(&self,
                                    vertex_array: &VertexArray,
                                    render_states: &mut rc::RenderStates) -> () {
+        self.stats.record(vertex_array.get_vertex_count(),
+                          texture_key_rc(&render_states.texture),
+                          shader_key_rc(&render_states.shader));
         unsafe {
             ffi::sfRenderWindow_drawVertexArray(self.render_window,
                                                 vertex_array.unwrap(),
@@ -1074,8 +1670,11 @@ impl RenderTarget for RenderWindow{
                           vertices: &[Vertex],
                           ty: PrimitiveType,
                           rs: &mut RenderStates) {
+        self.stats.record(vertices.len(),
+                          texture_key(rs.texture),
+                          shader_key(rs.shader));
 
-        let len = vertices.len() as u32;
+        let len = vertices.len() as size_t;
         unsafe {
             ffi::sfRenderWindow_drawPrimitives(self.render_window,
                                                &vertices[0],
@@ -1089,8 +1688,11 @@ impl RenderTarget for RenderWindow{
     fn draw_primitives(&self,
                        vertices: &[Vertex],
                        ty: PrimitiveType) {
+        self.stats.record(vertices.len(),
+                          0,
+                          0);
 
-        let len = vertices.len() as u32;
+        let len = vertices.len() as size_t;
         unsafe {
             ffi::sfRenderWindow_drawPrimitives(self.render_window,
                                                &vertices[0],
@@ -1113,10 +1715,17 @@ impl Iterator for Events {
     type Item = event::Event;
 
     fn next(&mut self) -> Option<event::Event> {
-        let mut event = event::raw::sfEvent { data: [032; 6u] };
+        match self.scale.take_pending() {
+            Some(event) => return Some(event),
+            None        => ()
+        }
+        let mut event = event::raw::sfEvent { data: [032; 7u] };
         match unsafe { ffi::sfRenderWindow_pollEvent(self.render_window, &mut event) } {
             SFFALSE     => None,
-            SFTRUE      => Some(event::raw::get_wrapped_event(&mut event))
+            SFTRUE      => {
+                let handle = unsafe { ffi::sfRenderWindow_getSystemHandle(self.render_window) };
+                Some(self.scale.watch(handle, event::raw::get_wrapped_event(&mut event)))
+            }
         }
     }
 }
@@ -0,0 +1,221 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Manage several render windows
+//!
+//! All the windows share their OpenGL resources with each other, so a
+//! Texture, Font or Shader created once can be drawn in any of them.
+//! Only one window can be active on a thread at a time though:
+//! RenderWindows keeps track of the active one and deactivates it before
+//! activating another one.
+
+use std::vec::Vec;
+
+use graphics::RenderWindow;
+
+/// Manage several render windows
+///
+/// Each window added gets an id which stays valid until the window is
+/// removed. Ids are never reused, so the id of a removed window can't
+/// refer to another window added later.
+pub struct RenderWindows {
+    #[doc(hidden)]
+    windows: Vec<Option<RenderWindow>>,
+    #[doc(hidden)]
+    active: Option<uint>
+}
+
+impl RenderWindows {
+    /// Create an empty RenderWindows
+    pub fn new() -> RenderWindows {
+        RenderWindows {
+            windows: Vec::new(),
+            active: None
+        }
+    }
+
+    /// Add a window
+    ///
+    /// # Arguments
+    /// * window - The window to manage
+    ///
+    /// Return the id of the window
+    pub fn add(&mut self, window: RenderWindow) -> uint {
+        self.windows.push(Some(window));
+        self.windows.len() - 1
+    }
+
+    /// Remove a window
+    ///
+    /// # Arguments
+    /// * id - The id of the window
+    ///
+    /// Return the window, or None if there is no window with this id
+    pub fn remove(&mut self, id: uint) -> Option<RenderWindow> {
+        if id >= self.windows.len() {
+            return None;
+        }
+        if self.active == Some(id) {
+            self.active = None;
+        }
+        self.windows[id].take()
+    }
+
+    /// Remove and destroy all the closed windows
+    ///
+    /// Return the number of windows removed
+    pub fn remove_closed(&mut self) -> uint {
+        let mut removed = 0;
+        for id in range(0, self.windows.len()) {
+            let closed = match self.windows[id] {
+                Some(ref window) => !window.is_open(),
+                None             => false
+            };
+            if closed {
+                self.remove(id);
+                removed += 1;
+            }
+        }
+        removed
+    }
+
+    /// Get a window
+    ///
+    /// # Arguments
+    /// * id - The id of the window
+    ///
+    /// Return the window, or None if there is no window with this id
+    pub fn get(&self, id: uint) -> Option<&RenderWindow> {
+        match self.windows.get(id) {
+            Some(&Some(ref window)) => Some(window),
+            _                       => None
+        }
+    }
+
+    /// Get a window to handle its events or change its settings
+    ///
+    /// The window is not activated, use activate to draw in it.
+    ///
+    /// # Arguments
+    /// * id - The id of the window
+    ///
+    /// Return the window, or None if there is no window with this id
+    pub fn get_mut(&mut self, id: uint) -> Option<&mut RenderWindow> {
+        match self.windows.get_mut(id) {
+            Some(&mut Some(ref mut window)) => Some(window),
+            _                               => None
+        }
+    }
+
+    /// Activate a window for drawing
+    ///
+    /// The previously active window is deactivated first.
+    ///
+    /// # Arguments
+    /// * id - The id of the window
+    ///
+    /// Return the window, or None if there is no window with this id or
+    /// if it can't be activated
+    pub fn activate(&mut self, id: uint) -> Option<&mut RenderWindow> {
+        if self.get(id).is_none() {
+            return None;
+        }
+        if self.active != Some(id) {
+            match self.active {
+                Some(active) => {
+                    match self.windows[active] {
+                        Some(ref mut window) => { window.set_active(false); },
+                        None                 => ()
+                    }
+                },
+                None => ()
+            }
+            self.active = None;
+            let activated = match self.windows[id] {
+                Some(ref mut window) => window.set_active(true),
+                None                 => false
+            };
+            if !activated {
+                return None;
+            }
+            self.active = Some(id);
+        }
+        self.get_mut(id)
+    }
+
+    /// Get the id of the active window
+    ///
+    /// Return the id, or None if no window was activated through
+    /// this RenderWindows
+    pub fn get_active(&self) -> Option<uint> {
+        self.active
+    }
+
+    /// Get the ids of all the windows
+    pub fn get_ids(&self) -> Vec<uint> {
+        range(0, self.windows.len()).filter(|&id| self.windows[id].is_some()).collect()
+    }
+
+    /// Display the content of all the open windows
+    ///
+    /// Each window is activated in turn, the last one stays active.
+    pub fn display_all(&mut self) -> () {
+        for id in self.get_ids().into_iter() {
+            let open = match self.get(id) {
+                Some(window) => window.is_open(),
+                None         => false
+            };
+            if open {
+                match self.activate(id) {
+                    Some(window) => window.display(),
+                    None         => ()
+                }
+            }
+        }
+    }
+
+    /// Close all the windows
+    pub fn close_all(&mut self) -> () {
+        for slot in self.windows.iter_mut() {
+            match *slot {
+                Some(ref mut window) => window.close(),
+                None                 => ()
+            }
+        }
+    }
+
+    /// Tell whether at least one of the windows is open
+    pub fn is_any_open(&self) -> bool {
+        self.windows.iter().any(|slot| match *slot {
+            Some(ref window) => window.is_open(),
+            None             => false
+        })
+    }
+
+    /// Get the number of windows
+    pub fn len(&self) -> uint {
+        self.windows.iter().filter(|slot| slot.is_some()).count()
+    }
+}
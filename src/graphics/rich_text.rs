@@ -0,0 +1,350 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Multi-style text block with word wrapping
+//!
+//! RichText displays several spans of text, each with its own color,
+//! style and character size, in a single block which is automatically
+//! wrapped to a maximum width.
+
+use std::cmp;
+use std::num::Float;
+
+use traits::Drawable;
+use graphics::{RenderTarget, RenderStates, Font, FloatRect, Color, Text,
+               TextStyle, Regular, Bold};
+use system::vector2::Vector2f;
+
+/// A span of text sharing the same color, style and size
+#[derive(Clone, PartialEq, Show)]
+pub struct TextSpan {
+    /// The string of the span
+    pub string: String,
+    /// Color of the span
+    pub color: Color,
+    /// Style of the span
+    pub style: TextStyle,
+    /// Character size of the span, in pixels
+    pub character_size: uint
+}
+
+impl TextSpan {
+    /// Create a new TextSpan
+    ///
+    /// # Arguments
+    /// * string - The string of the span
+    /// * color - Color of the span
+    /// * style - Style of the span
+    /// * character_size - Character size of the span, in pixels
+    ///
+    /// Return a new TextSpan
+    pub fn new(string: &str,
+               color: &Color,
+               style: TextStyle,
+               character_size: uint) -> TextSpan {
+        TextSpan {
+            string: string.to_string(),
+            color: *color,
+            style: style,
+            character_size: character_size
+        }
+    }
+
+    /// Create a new regular white TextSpan
+    ///
+    /// # Arguments
+    /// * string - The string of the span
+    /// * character_size - Character size of the span, in pixels
+    ///
+    /// Return a new TextSpan
+    pub fn new_plain(string: &str, character_size: uint) -> TextSpan {
+        TextSpan::new(string, &Color::white(), Regular, character_size)
+    }
+}
+
+// A part of a span laid out on a single line
+struct Segment {
+    span: uint,
+    string: String,
+    x: f32
+}
+
+// A laid out line of the block
+struct Line {
+    segments: Vec<Segment>,
+    width: f32,
+    character_size: uint,
+    spacing: f32
+}
+
+impl Line {
+    fn new() -> Line {
+        Line {
+            segments: Vec::new(),
+            width: 0.,
+            character_size: 0,
+            spacing: 0.
+        }
+    }
+}
+
+/// Multi-style text block with word wrapping
+///
+/// Spans are laid out one after the other, and lines are broken
+/// between words when they exceed the maximum width, or on '\n'.
+pub struct RichText<'s> {
+    #[doc(hidden)]
+    font: &'s Font,
+    #[doc(hidden)]
+    spans: Vec<TextSpan>,
+    #[doc(hidden)]
+    max_width: f32,
+    #[doc(hidden)]
+    position: Vector2f,
+    #[doc(hidden)]
+    size: Vector2f,
+    #[doc(hidden)]
+    texts: Vec<Text<'s>>
+}
+
+impl<'s> RichText<'s> {
+    /// Create a new empty RichText
+    ///
+    /// # Arguments
+    /// * font - The font used to display the spans
+    ///
+    /// Return a new RichText
+    pub fn new(font: &'s Font) -> RichText<'s> {
+        RichText {
+            font: font,
+            spans: Vec::new(),
+            max_width: 0.,
+            position: Vector2f::new(0., 0.),
+            size: Vector2f::new(0., 0.),
+            texts: Vec::new()
+        }
+    }
+
+    /// Add a span at the end of a RichText
+    ///
+    /// # Arguments
+    /// * span - The span to add
+    pub fn push_span(&mut self, span: TextSpan) -> () {
+        self.spans.push(span);
+        self.update_layout();
+    }
+
+    /// Remove all the spans of a RichText
+    pub fn clear(&mut self) -> () {
+        self.spans.clear();
+        self.update_layout();
+    }
+
+    /// Get the spans of a RichText
+    pub fn get_spans(&self) -> &[TextSpan] {
+        self.spans.as_slice()
+    }
+
+    /// Set the maximum width of the lines of a RichText
+    ///
+    /// A width of 0 disables word wrapping.
+    ///
+    /// # Arguments
+    /// * max_width - The maximum width, in pixels
+    pub fn set_max_width(&mut self, max_width: f32) -> () {
+        self.max_width = max_width;
+        self.update_layout();
+    }
+
+    /// Get the maximum width of the lines of a RichText
+    pub fn get_max_width(&self) -> f32 {
+        self.max_width
+    }
+
+    /// Set the position of a RichText
+    ///
+    /// # Arguments
+    /// * position - New position of the top left corner
+    pub fn set_position(&mut self, position: &Vector2f) -> () {
+        self.position = *position;
+        self.update_layout();
+    }
+
+    /// Set the position of a RichText
+    ///
+    /// # Arguments
+    /// * x - X coordinate of the new position
+    /// * y - Y coordinate of the new position
+    pub fn set_position2f(&mut self, x: f32, y: f32) -> () {
+        self.set_position(&Vector2f::new(x, y))
+    }
+
+    /// Get the position of a RichText
+    pub fn get_position(&self) -> Vector2f {
+        self.position
+    }
+
+    /// Get the global bounding rectangle of a RichText
+    ///
+    /// Return the bounding rectangle of the laid out block
+    pub fn get_global_bounds(&self) -> FloatRect {
+        FloatRect::new(self.position.x, self.position.y,
+                       self.size.x, self.size.y)
+    }
+
+    #[doc(hidden)]
+    fn measure(&self, string: &str, character_size: uint, bold: bool) -> f32 {
+        let mut width = 0.;
+        let mut previous: Option<u32> = None;
+        for c in string.chars() {
+            let codepoint = c as u32;
+            match previous {
+                Some(p) => width += self.font.get_kerning(p, codepoint,
+                                                          character_size),
+                None    => {}
+            }
+            width += self.font.get_glyph(codepoint, character_size, bold, 0.).advance;
+            previous = Some(codepoint);
+        }
+        width
+    }
+
+    #[doc(hidden)]
+    fn layout(&self) -> Vec<Line> {
+        let mut lines = vec![Line::new()];
+        let mut x = 0.;
+        for (index, span) in self.spans.iter().enumerate() {
+            let bold = span.style == Bold;
+            let spacing = self.font.get_line_spacing(span.character_size);
+
+            // Split the span into words, single whitespaces and line breaks
+            let mut tokens: Vec<String> = Vec::new();
+            let mut word = String::new();
+            for c in span.string.chars() {
+                if c.is_whitespace() {
+                    if !word.is_empty() {
+                        tokens.push(word.clone());
+                        word.clear();
+                    }
+                    tokens.push(c.to_string());
+                } else {
+                    word.push(c);
+                }
+            }
+            if !word.is_empty() {
+                tokens.push(word);
+            }
+
+            for token in tokens.iter() {
+                if token.as_slice() == "\n" {
+                    {
+                        let line = lines.last_mut().unwrap();
+                        line.character_size = cmp::max(line.character_size, span.character_size);
+                        line.spacing = line.spacing.max(spacing);
+                    }
+                    lines.push(Line::new());
+                    x = 0.;
+                    continue
+                }
+                let is_space = token.as_slice().chars().all(|c| c.is_whitespace());
+                let width = self.measure(token.as_slice(), span.character_size, bold);
+                if !is_space && self.max_width > 0. && x > 0. && x + width > self.max_width {
+                    lines.push(Line::new());
+                    x = 0.;
+                }
+                if is_space && x == 0. && lines.len() > 1 {
+                    // Don't start a wrapped line with a space
+                    continue
+                }
+                let line = lines.last_mut().unwrap();
+                line.character_size = cmp::max(line.character_size, span.character_size);
+                line.spacing = line.spacing.max(spacing);
+                let same_span = match line.segments.last() {
+                    Some(segment) => segment.span == index,
+                    None          => false
+                };
+                if same_span {
+                    line.segments.last_mut().unwrap().string.push_str(token.as_slice());
+                } else {
+                    line.segments.push(Segment {
+                        span: index,
+                        string: token.clone(),
+                        x: x
+                    });
+                }
+                x += width;
+                line.width = x;
+            }
+        }
+        lines
+    }
+
+    #[doc(hidden)]
+    fn update_layout(&mut self) -> () {
+        let lines = self.layout();
+        let mut texts = Vec::new();
+        let mut y = 0.;
+        let mut width: f32 = 0.;
+        for line in lines.iter() {
+            for segment in line.segments.iter() {
+                let span = &self.spans[segment.span];
+                match Text::new_init(segment.string.as_slice(),
+                                     self.font,
+                                     span.character_size) {
+                    Some(mut text) => {
+                        text.set_color(&span.color);
+                        text.set_style(span.style);
+                        // Align the baselines of the different sizes
+                        text.set_position2f(
+                            self.position.x + segment.x,
+                            self.position.y + y +
+                            (line.character_size - span.character_size) as f32);
+                        texts.push(text);
+                    },
+                    None => {}
+                }
+            }
+            width = width.max(line.width);
+            y += line.spacing;
+        }
+        self.size = Vector2f::new(width, y);
+        self.texts = texts;
+    }
+}
+
+impl<'s> Drawable for RichText<'s> {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        for text in self.texts.iter() {
+            render_target.draw(text)
+        }
+    }
+
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 render_states: &mut RenderStates) -> () {
+        for text in self.texts.iter() {
+            render_target.draw_with_renderstates(text, render_states)
+        }
+    }
+}
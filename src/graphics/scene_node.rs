@@ -0,0 +1,306 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Hierarchy of drawable objects
+//!
+//! A SceneNode holds an optional drawable, a local transform and children.
+//! Drawing a node draws its content and its children with the transform
+//! of the node combined with the ones of its parents, so a turret attached
+//! to a tank follows it without any computation from the user.
+
+use std::mem;
+
+use traits::{Drawable, Transformable};
+use graphics::{RenderTarget, RenderStates, Transform, BlendAlpha};
+use graphics::Transformable as TransformableStruct;
+use system::vector2::Vector2f;
+use system::Time;
+
+/// Hierarchy of drawable objects
+///
+/// The nodes of a tree all hold the same type of drawable, use an enum
+/// implementing Drawable to mix several types. The children with a negative
+/// z-order are drawn before the content of the node, the others after it,
+/// children with the same z-order being drawn in the order of attachment.
+pub struct SceneNode<T> {
+    #[doc(hidden)]
+    content: Option<T>,
+    #[doc(hidden)]
+    transformable: TransformableStruct,
+    #[doc(hidden)]
+    z_order: i32,
+    #[doc(hidden)]
+    children: Vec<SceneNode<T>>
+}
+
+impl<T: Drawable> SceneNode<T> {
+    /// Create a new SceneNode
+    ///
+    /// # Arguments
+    /// * content - Some(drawable) to draw at this node, or None for a
+    /// node only used to group its children
+    ///
+    /// Return Some(SceneNode) or None
+    pub fn new(content: Option<T>) -> Option<SceneNode<T>> {
+        match TransformableStruct::new() {
+            Some(transformable) => Some(SceneNode {
+                content: content,
+                transformable: transformable,
+                z_order: 0,
+                children: Vec::new()
+            }),
+            None => None
+        }
+    }
+
+    /// Get the content of the node
+    ///
+    /// Return Some(reference) to the content or None
+    pub fn get_content(&self) -> Option<&T> {
+        self.content.as_ref()
+    }
+
+    /// Get the content of the node, to change it
+    ///
+    /// Return Some(mutable reference) to the content or None
+    pub fn get_content_mut(&mut self) -> Option<&mut T> {
+        self.content.as_mut()
+    }
+
+    /// Replace the content of the node
+    ///
+    /// # Arguments
+    /// * content - The new content
+    ///
+    /// Return the previous content
+    pub fn set_content(&mut self, content: Option<T>) -> Option<T> {
+        mem::replace(&mut self.content, content)
+    }
+
+    /// Set the z-order of the node among its siblings
+    ///
+    /// # Arguments
+    /// * z_order - The new z-order, 0 by default
+    pub fn set_z_order(&mut self, z_order: i32) -> () {
+        self.z_order = z_order
+    }
+
+    /// Get the z-order of the node among its siblings
+    ///
+    /// Return the z-order
+    pub fn get_z_order(&self) -> i32 {
+        self.z_order
+    }
+
+    /// Attach a child to the node
+    ///
+    /// # Arguments
+    /// * child - The node to attach
+    ///
+    /// Return the index of the child
+    pub fn attach(&mut self, child: SceneNode<T>) -> uint {
+        self.children.push(child);
+        self.children.len() - 1
+    }
+
+    /// Detach a child from the node
+    ///
+    /// The indices of the following children are shifted down by one.
+    ///
+    /// # Arguments
+    /// * index - The index of the child
+    ///
+    /// Return Some(child) or None if the index is out of range
+    pub fn detach(&mut self, index: uint) -> Option<SceneNode<T>> {
+        if index < self.children.len() {
+            Some(self.children.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Get a child of the node
+    ///
+    /// # Arguments
+    /// * index - The index of the child
+    ///
+    /// Return Some(child) or None if the index is out of range
+    pub fn get_child(&self, index: uint) -> Option<&SceneNode<T>> {
+        self.children.get(index)
+    }
+
+    /// Get a child of the node, to change it
+    ///
+    /// # Arguments
+    /// * index - The index of the child
+    ///
+    /// Return Some(child) or None if the index is out of range
+    pub fn get_child_mut(&mut self, index: uint) -> Option<&mut SceneNode<T>> {
+        self.children.get_mut(index)
+    }
+
+    /// Get the number of children of the node
+    ///
+    /// Return the number of children
+    pub fn get_child_count(&self) -> uint {
+        self.children.len()
+    }
+
+    /// Update the node and all its descendants
+    ///
+    /// The function is called on this node first, then on its children,
+    /// depth first.
+    ///
+    /// # Arguments
+    /// * elapsed - Time elapsed since the last update
+    /// * update - The function updating a node
+    pub fn update<F: FnMut(&mut SceneNode<T>, &Time)>(&mut self,
+                                                       elapsed: &Time,
+                                                       update: &mut F) -> () {
+        (*update)(self, elapsed);
+        for child in self.children.iter_mut() {
+            child.update(elapsed, update);
+        }
+    }
+
+    /// Get the transform of the node combined with the ones of its parents
+    ///
+    /// # Arguments
+    /// * parent_transform - The world transform of the parent node
+    ///
+    /// Return the world transform of the node
+    pub fn get_world_transform(&self, parent_transform: &Transform) -> Transform {
+        parent_transform.combined(&self.transformable.get_transform())
+    }
+
+    #[doc(hidden)]
+    fn draw_tree<RT: RenderTarget>(&self,
+                                   render_target: &mut RT,
+                                   render_states: &mut RenderStates) -> () {
+        let transform = self.get_world_transform(&render_states.transform);
+        let mut order: Vec<&SceneNode<T>> = self.children.iter().collect();
+        order.sort_by(|a, b| a.z_order.cmp(&b.z_order));
+
+        let mut content_drawn = false;
+        for child in order.iter() {
+            if !content_drawn && child.z_order >= 0 {
+                self.draw_content(render_target, render_states, &transform);
+                content_drawn = true;
+            }
+            let mut rs = RenderStates::new(render_states.blendMode,
+                                           transform,
+                                           render_states.texture,
+                                           render_states.shader);
+            child.draw_tree(render_target, &mut rs);
+        }
+        if !content_drawn {
+            self.draw_content(render_target, render_states, &transform);
+        }
+    }
+
+    #[doc(hidden)]
+    fn draw_content<RT: RenderTarget>(&self,
+                                      render_target: &mut RT,
+                                      render_states: &RenderStates,
+                                      transform: &Transform) -> () {
+        match self.content {
+            Some(ref content) => {
+                let mut rs = RenderStates::new(render_states.blendMode,
+                                               *transform,
+                                               render_states.texture,
+                                               render_states.shader);
+                content.draw_rs(render_target, &mut rs)
+            },
+            None => ()
+        }
+    }
+}
+
+impl<T: Drawable> Drawable for SceneNode<T> {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        let mut rs = RenderStates::new(BlendAlpha, Transform::new_identity(), None, None);
+        self.draw_tree(render_target, &mut rs)
+    }
+
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 render_states: &mut RenderStates) -> () {
+        self.draw_tree(render_target, render_states)
+    }
+}
+
+impl<T> Transformable for SceneNode<T> {
+    fn set_position(&mut self, position: &Vector2f) -> () {
+        self.transformable.set_position(position)
+    }
+
+    fn set_rotation(&mut self, angle: f32) -> () {
+        self.transformable.set_rotation(angle)
+    }
+
+    fn set_scale(&mut self, scale: &Vector2f) -> () {
+        self.transformable.set_scale(scale)
+    }
+
+    fn set_origin(&mut self, origin: &Vector2f) -> () {
+        self.transformable.set_origin(origin)
+    }
+
+    fn get_position(&self) -> Vector2f {
+        self.transformable.get_position()
+    }
+
+    fn get_rotation(&self) -> f32 {
+        self.transformable.get_rotation()
+    }
+
+    fn get_scale(&self) -> Vector2f {
+        self.transformable.get_scale()
+    }
+
+    fn get_origin(&self) -> Vector2f {
+        self.transformable.get_origin()
+    }
+
+    fn move_(&mut self, offset: &Vector2f) -> () {
+        self.transformable.move_(offset)
+    }
+
+    fn rotate(&mut self, angle: f32) -> () {
+        self.transformable.rotate(angle)
+    }
+
+    fn scale(&mut self, factors: &Vector2f) -> () {
+        self.transformable.scale(factors)
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.transformable.get_transform()
+    }
+
+    fn get_inverse_transform(&self) -> Transform {
+        self.transformable.get_inverse_transform()
+    }
+}
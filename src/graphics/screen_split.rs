@@ -0,0 +1,174 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Split the screen between several players
+//!
+//! ScreenSplit manages one View per player, with non overlapping viewports
+//! laid out for 1 to 4 players, and runs a draw pass for each of them.
+
+use graphics::{FloatRect, RenderTarget, View};
+use system::vector2::Vector2f;
+
+/// Split the screen between several players
+///
+/// The layouts are:
+/// * 1 player - the whole screen
+/// * 2 players - left and right halves
+/// * 3 players - top left and top right quarters, bottom half
+/// * 4 players - the four quarters
+///
+/// Each view is sized so one world unit matches one pixel, use zoom on the
+/// views to change their scale.
+pub struct ScreenSplit {
+    #[doc(hidden)]
+    views: Vec<View>
+}
+
+impl ScreenSplit {
+    /// Create a new ScreenSplit
+    ///
+    /// # Arguments
+    /// * players - Number of players, between 1 and 4
+    /// * window_width - Current width of the window
+    /// * window_height - Current height of the window
+    ///
+    /// Return Some(ScreenSplit) or None if the number of players is
+    /// not supported
+    pub fn new(players: uint,
+               window_width: u32,
+               window_height: u32) -> Option<ScreenSplit> {
+        if players == 0 || players > 4 {
+            return None;
+        }
+        let mut views = Vec::with_capacity(players);
+        for _ in range(0u, players) {
+            match View::new() {
+                Some(view) => views.push(view),
+                None => return None
+            }
+        }
+        let mut split = ScreenSplit {
+            views: views
+        };
+        for i in range(0u, players) {
+            let viewport = split.layout(i);
+            let size = Vector2f::new(viewport.width * window_width as f32,
+                                     viewport.height * window_height as f32);
+            let view = &mut split.views[i];
+            view.set_viewport(&viewport);
+            view.set_size(&size);
+            view.set_center(&Vector2f::new(size.x / 2., size.y / 2.));
+        }
+        Some(split)
+    }
+
+    #[doc(hidden)]
+    fn layout(&self, index: uint) -> FloatRect {
+        match (self.views.len(), index) {
+            (2, 0) => FloatRect::new(0., 0., 0.5, 1.),
+            (2, _) => FloatRect::new(0.5, 0., 0.5, 1.),
+            (3, 0) => FloatRect::new(0., 0., 0.5, 0.5),
+            (3, 1) => FloatRect::new(0.5, 0., 0.5, 0.5),
+            (3, _) => FloatRect::new(0., 0.5, 1., 0.5),
+            (4, i) => FloatRect::new((i % 2) as f32 * 0.5,
+                                     (i / 2) as f32 * 0.5,
+                                     0.5,
+                                     0.5),
+            _ => FloatRect::new(0., 0., 1., 1.)
+        }
+    }
+
+    /// Resize the views for a new size of the window
+    ///
+    /// The center and the zoom of each view are kept.
+    ///
+    /// # Arguments
+    /// * window_width - New width of the window
+    /// * window_height - New height of the window
+    /// * previous_width - Previous width of the window
+    /// * previous_height - Previous height of the window
+    pub fn resize(&mut self,
+                  window_width: u32,
+                  window_height: u32,
+                  previous_width: u32,
+                  previous_height: u32) -> () {
+        if previous_width == 0 || previous_height == 0 {
+            return;
+        }
+        let ratio_x = window_width as f32 / previous_width as f32;
+        let ratio_y = window_height as f32 / previous_height as f32;
+        for view in self.views.iter_mut() {
+            let size = view.get_size();
+            view.set_size2f(size.x * ratio_x, size.y * ratio_y);
+        }
+    }
+
+    /// Get the number of players
+    ///
+    /// Return the number of views
+    pub fn get_player_count(&self) -> uint {
+        self.views.len()
+    }
+
+    /// Get the view of a player
+    ///
+    /// # Arguments
+    /// * player - Index of the player
+    ///
+    /// Return Some(view) or None if the index is out of range
+    pub fn get_view(&self, player: uint) -> Option<&View> {
+        self.views.get(player)
+    }
+
+    /// Get the view of a player, to move or zoom the camera
+    ///
+    /// # Arguments
+    /// * player - Index of the player
+    ///
+    /// Return Some(view) or None if the index is out of range
+    pub fn get_view_mut(&mut self, player: uint) -> Option<&mut View> {
+        self.views.get_mut(player)
+    }
+
+    /// Run a draw pass for each player
+    ///
+    /// The view of each player is set on the target before calling pass
+    /// with the index of the player. The view of the target is restored
+    /// once every pass is done.
+    ///
+    /// # Arguments
+    /// * target - The target to draw on
+    /// * pass - The function drawing the scene
+    pub fn draw_passes<RT: RenderTarget, F: FnMut(&mut RT, uint)>(&self,
+                                                                  target: &mut RT,
+                                                                  mut pass: F) -> () {
+        let previous = target.get_view().clone();
+        for (i, view) in self.views.iter().enumerate() {
+            target.set_view(view);
+            pass(target, i);
+        }
+        target.set_view(&previous)
+    }
+}
@@ -0,0 +1,396 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Signed distance field text rendering
+//!
+//! SdfFont generates a signed distance field atlas from the glyphs of a
+//! Font, and SdfText renders strings with it through a bundled shader.
+//! Unlike the bitmap glyphs of Text, signed distance field glyphs stay
+//! crisp under any scale or rotation.
+
+use std::cmp;
+use std::num::Float;
+use std::collections::HashMap;
+
+use traits::Drawable;
+use graphics::{RenderTarget, RenderStates, Font, Color, Texture, Image,
+               Shader, Vertex, FloatRect, IntRect, Quads, BlendAlpha,
+               Transform, Glyph};
+use system::vector2::Vector2f;
+
+// Character size used to rasterize the glyphs of the atlas
+static BASE_SIZE: uint = 48;
+// Maximum distance encoded in the field, in pixels of the base size
+static SPREAD: uint = 6;
+// Width of the generated atlas
+static ATLAS_WIDTH: uint = 512;
+
+static FRAGMENT_SHADER: &'static str = "
+uniform sampler2D texture;
+
+void main()
+{
+    float distance = texture2D(texture, gl_TexCoord[0].xy).a;
+    float smoothing = fwidth(distance) * 0.7;
+    float alpha = smoothstep(0.5 - smoothing, 0.5 + smoothing, distance);
+    gl_FragColor = vec4(gl_Color.rgb, gl_Color.a * alpha);
+}
+";
+
+/// Metrics of a glyph of an SdfFont, at the base size
+#[derive(Clone, PartialEq, Show, Copy)]
+pub struct SdfGlyph {
+    /// Offset to move horizontally to the next character
+    pub advance: f32,
+    /// Bounding rectangle of the glyph quad, relative to the baseline
+    pub bounds: FloatRect,
+    /// Coordinates of the glyph inside the atlas texture
+    pub texture_rect: IntRect
+}
+
+/// Signed distance field atlas generated from a Font
+pub struct SdfFont {
+    #[doc(hidden)]
+    glyphs: HashMap<u32, SdfGlyph>,
+    #[doc(hidden)]
+    line_spacing: f32,
+    #[doc(hidden)]
+    texture: Texture,
+    #[doc(hidden)]
+    shader: Shader<'static>
+}
+
+impl SdfFont {
+    /// Generate a signed distance field font
+    ///
+    /// This function is slow, it should be called once at load time.
+    /// Shaders must be available (see Shader::is_available).
+    ///
+    /// # Arguments
+    /// * font - The font to generate the atlas from
+    /// * characters - The characters to put in the atlas
+    ///
+    /// Return Some(SdfFont) or None
+    pub fn new(font: &Font, characters: &str) -> Option<SdfFont> {
+        let mut codepoints: Vec<u32> = characters.chars().map(|c| c as u32).collect();
+        codepoints.sort();
+        codepoints.dedup();
+
+        // Rasterize all the glyphs first, the texture may grow meanwhile
+        let source_glyphs: Vec<(u32, Glyph)> = codepoints.iter().map(|&c| {
+            (c, font.get_glyph(c, BASE_SIZE, false, 0.))
+        }).collect();
+        let source = match font.get_texture(BASE_SIZE) {
+            Some(texture) => match texture.copy_to_image() {
+                Some(image) => image,
+                None        => return None
+            },
+            None => return None
+        };
+        let source_width = source.get_size().x as uint;
+        let source_pixels = source.get_pixels();
+
+        // Place the glyphs on shelves
+        let mut places = Vec::with_capacity(source_glyphs.len());
+        let (mut x, mut y, mut shelf_height) = (0u, 0u, 0u);
+        for &(_, ref glyph) in source_glyphs.iter() {
+            let width = glyph.texture_rect.width as uint + 2 * SPREAD;
+            let height = glyph.texture_rect.height as uint + 2 * SPREAD;
+            if x + width > ATLAS_WIDTH {
+                x = 0;
+                y += shelf_height;
+                shelf_height = 0;
+            }
+            places.push((x, y, width, height));
+            x += width;
+            shelf_height = cmp::max(shelf_height, height);
+        }
+        let atlas_height = cmp::max(y + shelf_height, 1);
+
+        // Compute the distance field of every glyph
+        let mut pixels = Vec::from_elem(ATLAS_WIDTH * atlas_height * 4, 255u8);
+        for i in range(0u, pixels.len() / 4) {
+            pixels[i * 4 + 3] = 0;
+        }
+        let mut glyphs = HashMap::new();
+        for (&(codepoint, ref glyph), &(px, py, width, height)) in source_glyphs.iter().zip(places.iter()) {
+            let rect = glyph.texture_rect;
+            let inside = |&: gx: int, gy: int| -> bool {
+                if gx < 0 || gy < 0 || gx >= rect.width as int || gy >= rect.height as int {
+                    false
+                } else {
+                    let sx = (rect.left as int + gx) as uint;
+                    let sy = (rect.top as int + gy) as uint;
+                    source_pixels[(sy * source_width + sx) * 4 + 3] > 127
+                }
+            };
+            let spread = SPREAD as int;
+            for oy in range(0u, height) {
+                for ox in range(0u, width) {
+                    let gx = ox as int - spread;
+                    let gy = oy as int - spread;
+                    let state = inside(gx, gy);
+                    let mut nearest = (spread * spread) as f32;
+                    for dy in range(-spread, spread + 1) {
+                        for dx in range(-spread, spread + 1) {
+                            if inside(gx + dx, gy + dy) != state {
+                                nearest = nearest.min((dx * dx + dy * dy) as f32);
+                            }
+                        }
+                    }
+                    let distance = nearest.sqrt() / (2. * SPREAD as f32);
+                    let value = if state { 0.5 + distance } else { 0.5 - distance };
+                    let index = ((py + oy) * ATLAS_WIDTH + px + ox) * 4 + 3;
+                    pixels[index] = (value.max(0.).min(1.) * 255.) as u8;
+                }
+            }
+            glyphs.insert(codepoint, SdfGlyph {
+                advance: glyph.advance,
+                bounds: FloatRect::new(glyph.bounds.left - SPREAD as f32,
+                                       glyph.bounds.top - SPREAD as f32,
+                                       glyph.bounds.width + 2. * SPREAD as f32,
+                                       glyph.bounds.height + 2. * SPREAD as f32),
+                texture_rect: IntRect::new(px as i32, py as i32,
+                                           width as i32, height as i32)
+            });
+        }
+
+        let image = match Image::create_from_pixels(ATLAS_WIDTH, atlas_height,
+                                                    pixels.as_slice()) {
+            Some(image) => image,
+            None        => return None
+        };
+        let mut texture = match Texture::new_from_image(&image) {
+            Some(texture) => texture,
+            None          => return None
+        };
+        texture.set_smooth(true);
+        let shader = match Shader::new_from_memory(None, Some(FRAGMENT_SHADER)) {
+            Some(shader) => shader,
+            None         => return None
+        };
+        shader.set_current_texture_parameter("texture");
+
+        Some(SdfFont {
+            glyphs: glyphs,
+            line_spacing: font.get_line_spacing(BASE_SIZE),
+            texture: texture,
+            shader: shader
+        })
+    }
+
+    /// Generate a signed distance field font for the printable ASCII characters
+    ///
+    /// # Arguments
+    /// * font - The font to generate the atlas from
+    ///
+    /// Return Some(SdfFont) or None
+    pub fn new_ascii(font: &Font) -> Option<SdfFont> {
+        let characters: String = range(32u8, 127).map(|c| c as char).collect();
+        SdfFont::new(font, characters.as_slice())
+    }
+
+    /// Get the metrics of a glyph, at the base size
+    ///
+    /// # Arguments
+    /// * codepoint - Unicode code point of the character
+    ///
+    /// Return Some(SdfGlyph) or None if the character isn't in the atlas
+    pub fn get_glyph(&self, codepoint: u32) -> Option<SdfGlyph> {
+        self.glyphs.get(&codepoint).map(|glyph| *glyph)
+    }
+
+    /// Get the size at which the glyphs of the atlas were generated
+    pub fn get_base_size() -> uint {
+        BASE_SIZE
+    }
+
+    /// Get the atlas texture
+    pub fn get_texture(&self) -> &Texture {
+        &self.texture
+    }
+}
+
+/// Text drawn with a signed distance field font
+pub struct SdfText<'s> {
+    #[doc(hidden)]
+    font: &'s SdfFont,
+    #[doc(hidden)]
+    string: String,
+    #[doc(hidden)]
+    character_size: f32,
+    #[doc(hidden)]
+    color: Color,
+    #[doc(hidden)]
+    position: Vector2f,
+    #[doc(hidden)]
+    vertices: Vec<Vertex>
+}
+
+impl<'s> SdfText<'s> {
+    /// Create a new SdfText
+    ///
+    /// # Arguments
+    /// * string - The string to display
+    /// * font - The signed distance field font
+    /// * character_size - The size of the characters, in pixels
+    ///
+    /// Return a new SdfText
+    pub fn new(string: &str, font: &'s SdfFont, character_size: f32) -> SdfText<'s> {
+        let mut text = SdfText {
+            font: font,
+            string: string.to_string(),
+            character_size: character_size,
+            color: Color::white(),
+            position: Vector2f::new(0., 0.),
+            vertices: Vec::new()
+        };
+        text.update_vertices();
+        text
+    }
+
+    /// Set the string of an SdfText
+    ///
+    /// # Arguments
+    /// * string - New string
+    pub fn set_string(&mut self, string: &str) -> () {
+        self.string = string.to_string();
+        self.update_vertices();
+    }
+
+    /// Get the string of an SdfText
+    pub fn get_string(&self) -> &str {
+        self.string.as_slice()
+    }
+
+    /// Set the character size of an SdfText
+    ///
+    /// Any size can be used, the glyphs are scaled without losing quality.
+    ///
+    /// # Arguments
+    /// * size - New character size, in pixels
+    pub fn set_character_size(&mut self, size: f32) -> () {
+        self.character_size = size;
+        self.update_vertices();
+    }
+
+    /// Get the character size of an SdfText
+    pub fn get_character_size(&self) -> f32 {
+        self.character_size
+    }
+
+    /// Set the color of an SdfText
+    ///
+    /// # Arguments
+    /// * color - New color of the text
+    pub fn set_color(&mut self, color: &Color) -> () {
+        self.color = *color;
+        self.update_vertices();
+    }
+
+    /// Get the color of an SdfText
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    /// Set the position of an SdfText
+    ///
+    /// # Arguments
+    /// * position - New position of the top left corner
+    pub fn set_position(&mut self, position: &Vector2f) -> () {
+        self.position = *position;
+        self.update_vertices();
+    }
+
+    /// Set the position of an SdfText
+    ///
+    /// # Arguments
+    /// * x - X coordinate of the new position
+    /// * y - Y coordinate of the new position
+    pub fn set_position2f(&mut self, x: f32, y: f32) -> () {
+        self.set_position(&Vector2f::new(x, y))
+    }
+
+    /// Get the position of an SdfText
+    pub fn get_position(&self) -> Vector2f {
+        self.position
+    }
+
+    #[doc(hidden)]
+    fn update_vertices(&mut self) -> () {
+        self.vertices.clear();
+        let scale = self.character_size / BASE_SIZE as f32;
+        let mut x = 0.;
+        let mut baseline = self.character_size;
+        for c in self.string.chars() {
+            if c == '\n' {
+                x = 0.;
+                baseline += self.font.line_spacing * scale;
+                continue
+            }
+            let glyph = match self.font.get_glyph(c as u32) {
+                Some(glyph) => glyph,
+                None        => continue
+            };
+            let left = self.position.x + x + glyph.bounds.left * scale;
+            let top = self.position.y + baseline + glyph.bounds.top * scale;
+            let right = left + glyph.bounds.width * scale;
+            let bottom = top + glyph.bounds.height * scale;
+            let tex = glyph.texture_rect;
+            let u1 = tex.left as f32;
+            let v1 = tex.top as f32;
+            let u2 = (tex.left + tex.width) as f32;
+            let v2 = (tex.top + tex.height) as f32;
+            self.vertices.push(Vertex::new(&Vector2f::new(left, top), &self.color, &Vector2f::new(u1, v1)));
+            self.vertices.push(Vertex::new(&Vector2f::new(right, top), &self.color, &Vector2f::new(u2, v1)));
+            self.vertices.push(Vertex::new(&Vector2f::new(right, bottom), &self.color, &Vector2f::new(u2, v2)));
+            self.vertices.push(Vertex::new(&Vector2f::new(left, bottom), &self.color, &Vector2f::new(u1, v2)));
+            x += glyph.advance * scale;
+        }
+    }
+}
+
+impl<'s> Drawable for SdfText<'s> {
+    fn draw<RT: RenderTarget>(&self, render_target: &mut RT) -> () {
+        if !self.vertices.is_empty() {
+            let mut rs = RenderStates::new(BlendAlpha,
+                                           Transform::new_identity(),
+                                           Some(&self.font.texture),
+                                           Some(&self.font.shader));
+            render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+        }
+    }
+
+    fn draw_rs<RT: RenderTarget>(&self,
+                                 render_target: &mut RT,
+                                 render_states: &mut RenderStates) -> () {
+        if !self.vertices.is_empty() {
+            let mut rs = RenderStates::new(render_states.blendMode,
+                                           render_states.transform,
+                                           Some(&self.font.texture),
+                                           Some(&self.font.shader));
+            render_target.draw_primitives_rs(self.vertices.as_slice(), Quads, &mut rs)
+        }
+    }
+}
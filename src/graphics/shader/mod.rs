@@ -74,18 +74,17 @@ impl<'s> Shader<'s> {
                          fragment_shader_filename: Option<&str>)
                          -> Option<Shader<'s>> {
         let shader = unsafe {
-            let c_vertex_shader_filename = if vertex_shader_filename.is_none() {
-                ptr::null()
-            } else {
-                CString::from_slice(vertex_shader_filename.unwrap().as_bytes()).as_ptr()
-            };
-            let c_fragment_shader_filename = if fragment_shader_filename.is_none() {
-                ptr::null()
-            } else {
-                CString::from_slice(fragment_shader_filename.unwrap().as_bytes()).as_ptr()
-            };
-            ffi::sfShader_createFromFile(c_vertex_shader_filename,
-                                         c_fragment_shader_filename)
+            let c_vertex_shader_filename = vertex_shader_filename.map(|filename| {
+                CString::from_slice(filename.as_bytes())
+            });
+            let c_fragment_shader_filename = fragment_shader_filename.map(|filename| {
+                CString::from_slice(filename.as_bytes())
+            });
+            // CSFML takes a geometry shader too, these bindings don't load any
+            ffi::sfShader_createFromFile(
+                c_vertex_shader_filename.as_ref().map_or(ptr::null(), |c| c.as_ptr()),
+                ptr::null(),
+                c_fragment_shader_filename.as_ref().map_or(ptr::null(), |c| c.as_ptr()))
         };
         if shader.is_null() {
             None
@@ -116,17 +115,16 @@ impl<'s> Shader<'s> {
                            fragment_shader: Option<&str>)
                            -> Option<Shader<'s>> {
         let shader = unsafe {
-            let c_vertex_shader = if vertex_shader.is_none() {
-                ptr::null()
-            } else {
-                CString::from_slice(vertex_shader.unwrap().as_bytes()).as_ptr()
-            };
-            let c_fragment_shader = if fragment_shader.is_none() {
-                ptr::null()
-            } else {
-                CString::from_slice(fragment_shader.unwrap().as_bytes()).as_ptr()
-            };
-            ffi::sfShader_createFromFile(c_vertex_shader, c_fragment_shader)
+            let c_vertex_shader = vertex_shader.map(|source| {
+                CString::from_slice(source.as_bytes())
+            });
+            let c_fragment_shader = fragment_shader.map(|source| {
+                CString::from_slice(source.as_bytes())
+            });
+            ffi::sfShader_createFromMemory(
+                c_vertex_shader.as_ref().map_or(ptr::null(), |c| c.as_ptr()),
+                ptr::null(),
+                c_fragment_shader.as_ref().map_or(ptr::null(), |c| c.as_ptr()))
         };
         if shader.is_null() {
             None
@@ -271,6 +269,29 @@ impl<'s> Shader<'s> {
         }
     }
 
+    /// Unbind the shader currently bound
+    ///
+    /// Same restrictions as bind: it must be used only if you
+    /// mix sfShader with OpenGL code.
+    pub fn unbind() -> () {
+        unsafe {
+            ffi::sfShader_bind(ptr::null_mut())
+        }
+    }
+
+    /// Get the underlying OpenGL handle of a shader
+    ///
+    /// You shouldn't need to use this function, unless you have
+    /// very specific stuff to implement that SFML doesn't support,
+    /// or implement a temporary workaround until a bug is fixed.
+    ///
+    /// Return the OpenGL handle of the shader program or 0 if not yet loaded
+    pub fn get_native_handle(&self) -> u32 {
+        unsafe {
+            ffi::sfShader_getNativeHandle(self.shader) as u32
+        }
+    }
+
     /// Tell whether or not the system supports shaders
     ///
     /// This function should always be called before using
@@ -24,7 +24,7 @@
 
 //! Base class for textured shapes with outline
 
-use libc::{c_void, c_float, c_uint};
+use libc::{c_void, c_float, size_t};
 use std::{ptr, mem};
 
 use traits::{Drawable, ShapeImpl, Wrappable};
@@ -51,17 +51,17 @@ pub struct Shape<'s> {
 }
 
 #[doc(hidden)]
-extern fn get_point_count_callback(obj: *mut c_void) -> u32 {
+extern fn get_point_count_callback(obj: *mut c_void) -> size_t {
     let shape = unsafe { mem::transmute::<*mut c_void, Box<Box<WrapObj>>>(obj) };
-    let ret = shape.shape_impl.get_point_count();
+    let ret = shape.shape_impl.get_point_count() as size_t;
     unsafe { mem::forget(shape) };
     ret
 }
 
 #[doc(hidden)]
-extern fn get_point_callback(point: u32, obj: *mut c_void) -> Vector2f {
+extern fn get_point_callback(point: size_t, obj: *mut c_void) -> Vector2f {
     let shape = unsafe { mem::transmute::<*mut c_void, Box<Box<WrapObj>>>(obj) };
-    let ret = shape.shape_impl.get_point(point);
+    let ret = shape.shape_impl.get_point(point as u32);
     unsafe { mem::forget(shape) };
     ret
 }
@@ -509,7 +509,7 @@ impl<'s> Shape<'s> {
     /// Return the index-th point of the shape
     pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
-            ffi::sfShape_getPoint(self.shape, index as c_uint)
+            ffi::sfShape_getPoint(self.shape, index as size_t)
         }
     }
 
@@ -560,6 +560,8 @@ impl<'s> Shape<'s> {
     }
 }
 
+impl_transformable!(Shape<'s>, 's);
+
 impl<'s> Drawable for Shape<'s> {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) {
         render_target.draw_shape(self)
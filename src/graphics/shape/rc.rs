@@ -26,7 +26,7 @@
 
 use std::rc::Rc;
 use std::cell::RefCell;
-use libc::{c_void, c_float, c_uint};
+use libc::{c_void, c_float, size_t};
 use std::{ptr, mem};
 
 use traits::{Drawable, ShapeImpl, Wrappable};
@@ -51,17 +51,17 @@ pub struct Shape {
 }
 
 #[doc(hidden)]
-extern fn get_point_count_callback(obj: *mut c_void) -> u32 {
+extern fn get_point_count_callback(obj: *mut c_void) -> size_t {
     let shape = unsafe { mem::transmute::<*mut c_void, Box<Box<WrapObj>>>(obj) };
-    let ret = shape.shape_impl.get_point_count();
+    let ret = shape.shape_impl.get_point_count() as size_t;
     unsafe { mem::forget(shape) };
     ret
 }
 
 #[doc(hidden)]
-extern fn get_point_callback(point: u32, obj: *mut c_void) -> Vector2f {
+extern fn get_point_callback(point: size_t, obj: *mut c_void) -> Vector2f {
     let shape = unsafe { mem::transmute::<*mut c_void, Box<Box<WrapObj>>>(obj) };
-    let ret = shape.shape_impl.get_point(point);
+    let ret = shape.shape_impl.get_point(point as u32);
     unsafe { mem::forget(shape) };
     ret
 }
@@ -514,7 +514,7 @@ impl Shape {
     /// Return the index-th point of the shape
     pub fn get_point(&self, index: uint) -> Vector2f {
         unsafe {
-            ffi::sfShape_getPoint(self.shape, index as c_uint)
+            ffi::sfShape_getPoint(self.shape, index as size_t)
         }
     }
 
@@ -565,6 +565,8 @@ impl Shape {
     }
 }
 
+impl_transformable!(Shape);
+
 impl Drawable for Shape {
     fn draw<RT: RenderTarget>(&self, render_target: &mut RT) {
         render_target.draw_shape_rc(self)
@@ -492,6 +492,8 @@ impl<'s> Wrappable<*mut ffi::sfSprite> for Sprite<'s> {
     }
 }
 
+impl_transformable!(Sprite<'s>, 's);
+
 impl<'s> Drawable for Sprite<'s> {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_sprite(self)
@@ -492,6 +492,8 @@ impl Wrappable<*mut ffi::sfSprite> for Sprite {
     }
 }
 
+impl_transformable!(Sprite);
+
 impl Drawable for Sprite {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_sprite_rc(self)
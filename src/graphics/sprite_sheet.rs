@@ -0,0 +1,163 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Slice a texture into a grid of frames
+//!
+//! SpriteSheet computes the texture rectangles of a sprite sheet laid out
+//! as a regular grid, with an optional margin around the sheet and spacing
+//! between frames, as exported by most sprite packing tools.
+
+use graphics::{IntRect, Texture};
+
+/// Slice a texture into a grid of frames
+///
+/// Frames are indexed from left to right, then from top to bottom.
+#[derive(Clone, PartialEq, Show)]
+pub struct SpriteSheet {
+    #[doc(hidden)]
+    columns: uint,
+    #[doc(hidden)]
+    rows: uint,
+    #[doc(hidden)]
+    frames: Vec<IntRect>
+}
+
+impl SpriteSheet {
+    /// Create a new SpriteSheet from the size of its frames
+    ///
+    /// # Arguments
+    /// * columns - Number of columns of the grid
+    /// * rows - Number of rows of the grid
+    /// * frame_width - Width of a frame, in pixels
+    /// * frame_height - Height of a frame, in pixels
+    /// * margin - Space around the whole grid, in pixels
+    /// * spacing - Space between two frames, in pixels
+    ///
+    /// Return a new SpriteSheet
+    pub fn new(columns: uint,
+               rows: uint,
+               frame_width: uint,
+               frame_height: uint,
+               margin: uint,
+               spacing: uint) -> SpriteSheet {
+        let mut frames = Vec::with_capacity(columns * rows);
+        for row in range(0u, rows) {
+            for col in range(0u, columns) {
+                frames.push(IntRect::new(
+                    (margin + col * (frame_width + spacing)) as i32,
+                    (margin + row * (frame_height + spacing)) as i32,
+                    frame_width as i32,
+                    frame_height as i32));
+            }
+        }
+        SpriteSheet {
+            columns: columns,
+            rows: rows,
+            frames: frames
+        }
+    }
+
+    /// Create a new SpriteSheet filling a whole texture
+    ///
+    /// The size of the frames is deduced from the size of the texture,
+    /// the number of columns and rows, the margin and the spacing.
+    ///
+    /// # Arguments
+    /// * texture - The sprite sheet texture
+    /// * columns - Number of columns of the grid
+    /// * rows - Number of rows of the grid
+    /// * margin - Space around the whole grid, in pixels
+    /// * spacing - Space between two frames, in pixels
+    ///
+    /// Return Some(SpriteSheet) or None if the grid doesn't fit in the texture
+    pub fn new_from_texture(texture: &Texture,
+                            columns: uint,
+                            rows: uint,
+                            margin: uint,
+                            spacing: uint) -> Option<SpriteSheet> {
+        if columns == 0 || rows == 0 {
+            return None
+        }
+        let size = texture.get_size();
+        let used_x = 2 * margin + (columns - 1) * spacing;
+        let used_y = 2 * margin + (rows - 1) * spacing;
+        if used_x >= size.x as uint || used_y >= size.y as uint {
+            None
+        } else {
+            Some(SpriteSheet::new(columns,
+                                  rows,
+                                  (size.x as uint - used_x) / columns,
+                                  (size.y as uint - used_y) / rows,
+                                  margin,
+                                  spacing))
+        }
+    }
+
+    /// Get the number of columns of a SpriteSheet
+    pub fn get_columns(&self) -> uint {
+        self.columns
+    }
+
+    /// Get the number of rows of a SpriteSheet
+    pub fn get_rows(&self) -> uint {
+        self.rows
+    }
+
+    /// Get the total number of frames of a SpriteSheet
+    pub fn get_frame_count(&self) -> uint {
+        self.frames.len()
+    }
+
+    /// Get a frame by its index
+    ///
+    /// # Arguments
+    /// * index - Index of the frame, counted row by row
+    ///
+    /// Return Some(IntRect) or None if the index is out of range
+    pub fn get_frame(&self, index: uint) -> Option<IntRect> {
+        self.frames.get(index).map(|rect| *rect)
+    }
+
+    /// Get a frame by its position in the grid
+    ///
+    /// # Arguments
+    /// * column - Column of the frame
+    /// * row - Row of the frame
+    ///
+    /// Return Some(IntRect) or None if the position is out of the grid
+    pub fn get_frame_at(&self, column: uint, row: uint) -> Option<IntRect> {
+        if column >= self.columns || row >= self.rows {
+            None
+        } else {
+            self.get_frame(row * self.columns + column)
+        }
+    }
+
+    /// Get all the frames of a SpriteSheet
+    ///
+    /// Return a slice of the frames, counted row by row
+    pub fn get_frames(&self) -> &[IntRect] {
+        self.frames.as_slice()
+    }
+}
@@ -29,15 +29,15 @@
 
 use std::mem;
 use std::vec::Vec;
-use std::ffi::{CString, c_str_to_bytes_with_nul};
-use std::str;
+use std::char;
 use libc::{c_float, c_uint, size_t};
 use core::raw;
 
 use traits::{Drawable, Wrappable};
 use graphics::{RenderTarget, Font, FloatRect,
-               Color, Transform, RenderStates, TextStyle};
+               Color, Transform, RenderStates, TextStyle, Bold};
 use system::vector2::Vector2f;
+use system::utf32::to_utf32;
 
 use ffi::graphics::text as ffi;
 
@@ -51,8 +51,6 @@ pub struct Text<'s> {
     #[doc(hidden)]
     text: *mut ffi::sfText,
     #[doc(hidden)]
-    string_length: uint,
-    #[doc(hidden)]
     font: Option<&'s Font>
 }
 
@@ -67,7 +65,6 @@ impl<'s> Text<'s> {
         } else {
             Some(Text {
                     text: text,
-                    string_length: 0,
                     font: None
                 })
         }
@@ -90,15 +87,14 @@ impl<'s> Text<'s> {
         if text.is_null() {
             None
         } else {
-            let c_str = CString::from_slice(string.as_bytes()).as_ptr();
+            let utf32 = to_utf32(string);
             unsafe {
-                ffi::sfText_setString(text, c_str);
+                ffi::sfText_setUnicodeString(text, utf32.as_ptr());
                 ffi::sfText_setFont(text, font.unwrap());
                 ffi::sfText_setCharacterSize(text, character_size as c_uint)
             }
             Some(Text {
                     text: text,
-                    string_length: string.len(),
                     font: Some(font)
                 })
         }
@@ -113,35 +109,34 @@ impl<'s> Text<'s> {
             None
         } else {
             Some(Text {
-                text: self.text,
-                string_length: self.string_length,
+                text: sp,
                 font: self.font
             })
         }
     }
 
-    /// Set the string of a text (from an ANSI string)
+    /// Set the string of a text
     ///
+    /// The string is converted to UTF-32, so any unicode character
+    /// supported by the font can be displayed.
     /// A text's string is empty by default.
     ///
     /// # Arguments
     /// * string - New string
     pub fn set_string(&mut self, string: &str) -> () {
+        let utf32 = to_utf32(string);
         unsafe {
-            let c_str = CString::from_slice(string.as_bytes()).as_ptr();
-            ffi::sfText_setString(self.text, c_str);
+            ffi::sfText_setUnicodeString(self.text, utf32.as_ptr())
         }
-        self.string_length = string.len()
     }
 
-    /// Get the string of a text (returns an ANSI string)
+    /// Get the string of a text
     ///
-    /// Return a string as a locale-dependant ANSI string
+    /// Return the string of the text
     pub fn get_string(&self) -> String {
-        unsafe {
-            let string = ffi::sfText_getString(self.text);
-            str::from_utf8(c_str_to_bytes_with_nul(&string)).unwrap().to_string()
-        }
+        self.get_unicode_string().iter()
+                                 .filter_map(|&c| char::from_u32(c))
+                                 .collect()
     }
 
     /// Get the string of a text (returns a unicode string)
@@ -151,17 +146,25 @@ impl<'s> Text<'s> {
         let string: *const u32 = unsafe {
             ffi::sfText_getUnicodeString(self.text)
         };
+        if string.is_null() {
+            return Vec::new()
+        }
+
+        let mut length = 0u;
+        unsafe {
+            while *string.offset(length as int) != 0 {
+                length += 1;
+            }
+        }
 
         let string_slice: &[u32] = unsafe { mem::transmute(
                 raw::Slice{
                     data: string,
-                    len: self.string_length,
+                    len: length,
                 }
             )};
 
-        let result = string_slice.to_vec();
-
-        result
+        string_slice.to_vec()
     }
 
     /// Get the size of the characters
@@ -290,6 +293,100 @@ impl<'s> Text<'s> {
         }
     }
 
+    /// Set the outline color of a text
+    ///
+    /// By default, the text's outline color is opaque black.
+    ///
+    /// # Arguments
+    /// * color - The new outline color of the text
+    pub fn set_outline_color(&mut self, color: &Color) -> () {
+        unsafe {
+            ffi::sfText_setOutlineColor(self.text, *color)
+        }
+    }
+
+    /// Get the outline color of a text
+    ///
+    /// Return the outline color of the text
+    pub fn get_outline_color(&self) -> Color {
+        unsafe {
+            ffi::sfText_getOutlineColor(self.text)
+        }
+    }
+
+    /// Set the thickness of a text's outline
+    ///
+    /// By default, the outline thickness is 0.
+    /// Be aware that using a negative value for the outline
+    /// thickness will cause distorted rendering.
+    ///
+    /// # Arguments
+    /// * thickness - The new outline thickness, in pixels
+    pub fn set_outline_thickness(&mut self, thickness: f32) -> () {
+        unsafe {
+            ffi::sfText_setOutlineThickness(self.text, thickness as c_float)
+        }
+    }
+
+    /// Get the outline thickness of a text
+    ///
+    /// Return the outline thickness of the text, in pixels
+    pub fn get_outline_thickness(&self) -> f32 {
+        unsafe {
+            ffi::sfText_getOutlineThickness(self.text) as f32
+        }
+    }
+
+    /// Set the letter spacing factor of a text
+    ///
+    /// The default spacing between letters is defined by the font.
+    /// This factor doesn't directly apply to the existing spacing
+    /// between each character, it rather adds a fixed space between
+    /// them which is calculated from the font metrics and the character
+    /// size. Letter spacing factors smaller than 1 (even negative) bring
+    /// the characters closer to each other.
+    /// By default the letter spacing factor is 1.
+    ///
+    /// # Arguments
+    /// * spacing_factor - New letter spacing factor
+    pub fn set_letter_spacing(&mut self, spacing_factor: f32) -> () {
+        unsafe {
+            ffi::sfText_setLetterSpacing(self.text, spacing_factor as c_float)
+        }
+    }
+
+    /// Get the letter spacing factor of a text
+    ///
+    /// Return the letter spacing factor
+    pub fn get_letter_spacing(&self) -> f32 {
+        unsafe {
+            ffi::sfText_getLetterSpacing(self.text) as f32
+        }
+    }
+
+    /// Set the line spacing factor of a text
+    ///
+    /// The default spacing between lines is defined by the font.
+    /// This method enables you to set a factor for the spacing
+    /// between lines. By default the line spacing factor is 1.
+    ///
+    /// # Arguments
+    /// * spacing_factor - New line spacing factor
+    pub fn set_line_spacing(&mut self, spacing_factor: f32) -> () {
+        unsafe {
+            ffi::sfText_setLineSpacing(self.text, spacing_factor as c_float)
+        }
+    }
+
+    /// Get the line spacing factor of a text
+    ///
+    /// Return the line spacing factor
+    pub fn get_line_spacing(&self) -> f32 {
+        unsafe {
+            ffi::sfText_getLineSpacing(self.text) as f32
+        }
+    }
+
     /// Scale a text
     ///
     /// This function multiplies the current scale of the object,
@@ -474,6 +571,52 @@ impl<'s> Text<'s> {
         }
     }
 
+    /// Get the bounding rectangle of a character of a text
+    ///
+    /// The returned rectangle is in local coordinates and fits the
+    /// glyph of the character, which is useful to draw text cursors
+    /// or selection highlights.
+    ///
+    /// # Arguments
+    /// * index - The index of the character
+    ///
+    /// Return Some(FloatRect) or None if the text has no font or
+    /// if index is out of range
+    pub fn get_character_bounds(&self, index: uint) -> Option<FloatRect> {
+        let string = self.get_unicode_string();
+        if index >= string.len() {
+            return None
+        }
+        let character_size = self.get_character_size();
+        let bold = unsafe { ffi::sfText_getStyle(self.text) } & (Bold as u32) != 0;
+        let glyph = match self.font {
+            Some(ref font) => font.get_glyph(string[index], character_size, bold, 0.),
+            None           => return None
+        };
+        let position = self.get_inverse_transform()
+                           .transform_point(&self.find_character_pos(index as u64));
+        Some(FloatRect::new(position.x + glyph.bounds.left,
+                            position.y + character_size as f32 + glyph.bounds.top,
+                            glyph.bounds.width,
+                            glyph.bounds.height))
+    }
+
+    /// Get the global bounding rectangle of a character of a text
+    ///
+    /// The returned rectangle is in global coordinates, which means
+    /// that it takes in account the transformations of the text.
+    ///
+    /// # Arguments
+    /// * index - The index of the character
+    ///
+    /// Return Some(FloatRect) or None if the text has no font or
+    /// if index is out of range
+    pub fn get_global_character_bounds(&self, index: uint) -> Option<FloatRect> {
+        self.get_character_bounds(index).map(|bounds| {
+            self.get_transform().transform_rect(&bounds)
+        })
+    }
+
     /// Get the position of a text
     ///
     /// Return the current position
@@ -517,9 +660,12 @@ impl<'s> Text<'s> {
     ///
     /// # Arguments
     /// * string - The new string
-    pub fn set_unicode_string(&mut self, string: Vec<u32>) -> () {
+    pub fn set_unicode_string(&mut self, mut string: Vec<u32>) -> () {
+        // CSFML expects a null terminated string
+        if string.last() != Some(&0) {
+            string.push(0);
+        }
         unsafe {
-            self.string_length = string.len();
             ffi::sfText_setUnicodeString(self.text, string.as_ptr())
         }
     }
@@ -552,8 +698,7 @@ impl<'s> Clone for Text<'s> {
             panic!("Not enough memory to clone Text")
         } else {
             Text {
-                text: self.text,
-                string_length: self.string_length,
+                text: sp,
                 font: self.font.clone()
             }
         }
@@ -564,7 +709,6 @@ impl<'s> Wrappable<*mut ffi::sfText> for Text<'s> {
     fn wrap(text: *mut ffi::sfText) -> Text<'s> {
         Text {
             text: text,
-            string_length: 0,
             font: None
         }
     }
@@ -574,6 +718,8 @@ impl<'s> Wrappable<*mut ffi::sfText> for Text<'s> {
     }
 }
 
+impl_transformable!(Text<'s>, 's);
+
 impl<'s> Drawable for Text<'s> {
     fn draw<RT:RenderTarget>(&self, render_target: &mut RT) -> () {
         render_target.draw_text(self)
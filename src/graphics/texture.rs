@@ -28,11 +28,15 @@
 
 use libc::{c_uint, size_t};
 use std::ptr;
+use std::mem;
+use std::sync::atomic::{AtomicUint, ATOMIC_UINT_INIT, SeqCst};
 use std::ffi::CString;
 
 use traits::Wrappable;
 use graphics::{RenderWindow, Image, IntRect};
 use system::vector2::Vector2u;
+use system::InputStream;
+use system::input_stream::InputStreamBridge;
 use window::Window;
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
@@ -45,10 +49,26 @@ pub struct Texture {
     #[doc(hidden)]
     texture: *mut ffi::sfTexture,
     #[doc(hidden)]
-    dropable: bool
+    dropable: bool,
+    #[doc(hidden)]
+    mipmap: bool
 }
 
+// Estimated amount of GPU memory held by the textures owned by rsfml
+static TEXTURE_MEMORY: AtomicUint = ATOMIC_UINT_INIT;
+
 impl Texture {
+    #[doc(hidden)]
+    fn owned(texture: *mut ffi::sfTexture) -> Texture {
+        let tex = Texture {
+            texture: texture,
+            dropable: true,
+            mipmap: false
+        };
+        TEXTURE_MEMORY.fetch_add(tex.memory_usage(), SeqCst);
+        tex
+    }
+
     /// Create a new texture
     ///
     /// # Arguments
@@ -62,31 +82,81 @@ impl Texture {
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
         }
     }
 
-    /// Create a new texture from memory
+    /// Create a new texture from a whole file in memory
+    ///
+    /// This is useful to load textures embedded in the executable
+    /// with include_bytes! or extracted from an archive.
     ///
     /// # Arguments
-    /// * mem - Pointer to the file data in memory
+    /// * mem - The file data in memory
+    ///
+    /// Return Some(Texture) or None
+    pub fn new_from_memory_full(mem: &[u8]) -> Option<Texture> {
+        let tex = unsafe { ffi::sfTexture_createFromMemory(mem.as_ptr(),
+                                                           mem.len() as size_t,
+                                                           ptr::null()) };
+        if tex.is_null() {
+            None
+        } else {
+            Some(Texture::owned(tex))
+        }
+    }
+
+    /// Create a new texture from a file in memory with a given area
+    ///
+    /// # Arguments
+    /// * mem - The file data in memory
     /// * area - Area of the image to load
     ///
     /// Return Some(Texture) or None
     pub fn new_from_memory(mem: &[u8], area: &IntRect) -> Option<Texture> {
-        let tex = unsafe { ffi::sfTexture_createFromMemory(&mem[0],
+        let tex = unsafe { ffi::sfTexture_createFromMemory(mem.as_ptr(),
                                                            mem.len() as size_t,
-                                                           area) };
+                                                           &*area) };
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
+        }
+    }
+
+    /// Create a new texture from a custom stream
+    ///
+    /// # Arguments
+    /// * stream - Source stream to read from
+    ///
+    /// Return Some(Texture) or None
+    pub fn new_from_stream(stream: &mut InputStream) -> Option<Texture> {
+        let mut bridge = InputStreamBridge::new(stream);
+        let tex = unsafe { ffi::sfTexture_createFromStream(bridge.unwrap(),
+                                                           ptr::null()) };
+        if tex.is_null() {
+            None
+        } else {
+            Some(Texture::owned(tex))
+        }
+    }
+
+    /// Create a new texture from a custom stream with a given area
+    ///
+    /// # Arguments
+    /// * stream - Source stream to read from
+    /// * area - Area of the image to load
+    ///
+    /// Return Some(Texture) or None
+    pub fn new_from_stream_with_rect(stream: &mut InputStream,
+                                     area: &IntRect) -> Option<Texture> {
+        let mut bridge = InputStreamBridge::new(stream);
+        let tex = unsafe { ffi::sfTexture_createFromStream(bridge.unwrap(),
+                                                           &*area) };
+        if tex.is_null() {
+            None
+        } else {
+            Some(Texture::owned(tex))
         }
     }
 
@@ -105,10 +175,7 @@ impl Texture {
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
         }
     }
 
@@ -129,10 +196,7 @@ impl Texture {
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
         }
     }
 
@@ -147,10 +211,7 @@ impl Texture {
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
         }
     }
 
@@ -168,10 +229,7 @@ impl Texture {
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
         }
     }
 
@@ -187,10 +245,7 @@ impl Texture {
         if tex.is_null() {
             None
         } else {
-            Some(Texture {
-                    texture: tex,
-                    dropable: true
-                })
+            Some(Texture::owned(tex))
         }
     }
 
@@ -257,10 +312,33 @@ impl Texture {
         }
     }
 
+    /// Update a texture from the contents of another texture
+    ///
+    /// # Arguments
+    /// * texture - Source texture to copy to the texture
+    /// * x - X offset in the texture where to copy the source texture
+    /// * y - Y offset in the texture where to copy the source texture
+    pub fn update_from_texture(&mut self,
+                               texture: &Texture,
+                               x: uint,
+                               y: uint) -> () {
+        unsafe {
+            ffi::sfTexture_updateFromTexture(self.texture,
+                                             texture.unwrap(),
+                                             x as c_uint,
+                                             y as c_uint)
+        }
+    }
+
     /// Update a texture from the contents of a Vector of pixels
     ///
+    /// The pixels must be in 32-bits RGBA format, so the slice must
+    /// contain at least width * height * 4 bytes.
+    ///
     /// # Arguments
     /// * pixels - Pixels to copy to the texture
+    /// * width - Width of the pixel region contained in pixels
+    /// * height - Height of the pixel region contained in pixels
     /// * x - X offset in the texture where to copy the source pixels
     /// * y - Y offset in the texture where to copy the source pixels
     pub fn update_from_pixels(&mut self,
@@ -269,6 +347,9 @@ impl Texture {
                               height: uint,
                               x: uint,
                               y: uint) -> () {
+        if pixels.len() < width * height * 4 {
+            panic!("Not enough pixels to update the Texture")
+        }
         unsafe {
             ffi::sfTexture_updateFromPixels(self.texture,
                                             pixels.as_ptr(),
@@ -279,6 +360,33 @@ impl Texture {
         }
     }
 
+    /// Update a sub-rectangle of a texture from a Vector of pixels
+    ///
+    /// # Arguments
+    /// * pixels - Pixels to copy to the texture, in 32-bits RGBA format
+    /// * area - Area of the texture to update, which must not be negative
+    pub fn update_from_pixels_rect(&mut self,
+                                   pixels: &[u8],
+                                   area: &IntRect) -> () {
+        if area.left < 0 || area.top < 0 || area.width < 0 || area.height < 0 {
+            panic!("Negative area to update the Texture")
+        }
+        self.update_from_pixels(pixels,
+                                area.width as uint,
+                                area.height as uint,
+                                area.left as uint,
+                                area.top as uint)
+    }
+
+    /// Update the whole texture from a Vector of pixels
+    ///
+    /// # Arguments
+    /// * pixels - Pixels to copy to the texture, in 32-bits RGBA format
+    pub fn update(&mut self, pixels: &[u8]) -> () {
+        let size = self.get_size();
+        self.update_from_pixels(pixels, size.x as uint, size.y as uint, 0, 0)
+    }
+
     /// Enable or disable the smooth filter on a texture
     ///
     /// # Arguments
@@ -339,6 +447,42 @@ impl Texture {
         }
     }
 
+    /// Enable or disable conversion from sRGB
+    ///
+    /// When providing texture data from an image file or memory, it can
+    /// either be stored in a linear color space or an sRGB color space.
+    /// Most digital images account for gamma correction already, so they
+    /// would need to be "uncorrected" back to linear color space before
+    /// being processed by the hardware. The hardware can automatically
+    /// convert it from the sRGB color space to a linear color space when
+    /// it gets sampled. When the rendered image gets output to the final
+    /// framebuffer, it gets converted back to sRGB.
+    ///
+    /// This option is only useful in conjunction with an sRGB capable
+    /// framebuffer. This option has to be set before the texture data
+    /// is loaded. sRGB conversion is disabled by default.
+    ///
+    /// # Arguments
+    /// * srgb - true to enable sRGB conversion, false to disable it
+    pub fn set_srgb(&mut self, srgb: bool) -> () {
+        unsafe {
+            match srgb {
+                true        => ffi::sfTexture_setSrgb(self.texture, SFTRUE),
+                false       => ffi::sfTexture_setSrgb(self.texture, SFFALSE)
+            }
+        }
+    }
+
+    /// Tell whether the texture source is converted from sRGB or not
+    ///
+    /// Return true if the texture source is converted from sRGB, false if not
+    pub fn is_srgb(&self) -> bool {
+        match unsafe { ffi::sfTexture_isSrgb(self.texture) } {
+            SFFALSE   => false,
+            SFTRUE    => true
+        }
+    }
+
     /// Bind a texture for rendering
     ///
     /// This function is not part of the graphics API, it mustn't be
@@ -350,6 +494,29 @@ impl Texture {
         }
     }
 
+    /// Unbind the texture currently bound
+    ///
+    /// Same restrictions as bind: it must be used only if you
+    /// mix sfTexture with OpenGL code.
+    pub fn unbind() -> () {
+        unsafe {
+            ffi::sfTexture_bind(ptr::null_mut())
+        }
+    }
+
+    /// Get the underlying OpenGL handle of a texture
+    ///
+    /// You shouldn't need to use this function, unless you have
+    /// very specific stuff to implement that SFML doesn't support,
+    /// or implement a temporary workaround until a bug is fixed.
+    ///
+    /// Return the OpenGL handle of the texture or 0 if not yet created
+    pub fn get_native_handle(&self) -> u32 {
+        unsafe {
+            ffi::sfTexture_getNativeHandle(self.texture) as u32
+        }
+    }
+
     /// Get the maximum texture size allowed
     ///
     /// Return the maximum size allowed for textures, in pixels
@@ -359,6 +526,92 @@ impl Texture {
         }
     }
 
+    /// Generate a mipmap using the current texture data
+    ///
+    /// Mipmaps are pre-computed chains of optimized textures. Each
+    /// level of texture in a mipmap is generated by halving each of
+    /// the previous level's dimensions. This is done until the final
+    /// level has the size of 1x1. The textures generated in this process
+    /// may make use of more advanced filters which might improve the
+    /// visual quality of textures when they are applied to objects
+    /// much smaller than they are. This is known as minification.
+    /// Because fewer texels (texture elements) have to be sampled from
+    /// when heavily minified, usage of mipmaps can also improve rendering
+    /// performance in certain scenarios.
+    ///
+    /// Mipmap generation relies on the necessary OpenGL extension being
+    /// available. If it is unavailable or generation fails due to another
+    /// reason, this function will return false. Mipmap data is only valid
+    /// from the time it is generated until the next time the base level
+    /// image is modified, at which point this function will have to be
+    /// called again to regenerate it.
+    ///
+    /// Return true if mipmap generation was successful, false if unsuccessful
+    pub fn generate_mipmap(&mut self) -> bool {
+        match unsafe { ffi::sfTexture_generateMipmap(self.texture) } {
+            SFFALSE   => false,
+            SFTRUE    => {
+                if self.dropable && !self.mipmap {
+                    let base = self.memory_usage();
+                    self.mipmap = true;
+                    TEXTURE_MEMORY.fetch_add(self.memory_usage() - base, SeqCst);
+                } else {
+                    self.mipmap = true;
+                }
+                true
+            }
+        }
+    }
+
+    /// Swap the contents of this texture with those of another
+    ///
+    /// # Arguments
+    /// * other - Texture to swap with
+    pub fn swap(&mut self, other: &mut Texture) -> () {
+        if self.dropable {
+            TEXTURE_MEMORY.fetch_sub(self.memory_usage(), SeqCst);
+        }
+        if other.dropable {
+            TEXTURE_MEMORY.fetch_sub(other.memory_usage(), SeqCst);
+        }
+        unsafe {
+            ffi::sfTexture_swap(self.texture, other.texture)
+        }
+        mem::swap(&mut self.mipmap, &mut other.mipmap);
+        if self.dropable {
+            TEXTURE_MEMORY.fetch_add(self.memory_usage(), SeqCst);
+        }
+        if other.dropable {
+            TEXTURE_MEMORY.fetch_add(other.memory_usage(), SeqCst);
+        }
+    }
+
+    /// Get an estimation of the GPU memory used by a texture
+    ///
+    /// The estimation assumes 4 bytes per pixel, plus one third
+    /// of that if a mipmap was generated.
+    ///
+    /// Return the estimated memory usage, in bytes
+    pub fn memory_usage(&self) -> uint {
+        let size = self.get_size();
+        let base = size.x as uint * size.y as uint * 4;
+        if self.mipmap {
+            base + base / 3
+        } else {
+            base
+        }
+    }
+
+    /// Get an estimation of the GPU memory used by all the textures
+    ///
+    /// Only the textures created by rsfml are counted, textures
+    /// borrowed from other objects (like a RenderTexture) are not.
+    ///
+    /// Return the estimated memory usage, in bytes
+    pub fn get_total_memory_usage() -> uint {
+        TEXTURE_MEMORY.load(SeqCst)
+    }
+
     /// Copy a texture's pixels to an image
     ///
     /// Return an image containing the texture's pixels
@@ -370,6 +623,24 @@ impl Texture {
             Some(Wrappable::wrap(img))
         }
     }
+
+    /// Save the pixels of a texture to a file on disk
+    ///
+    /// The texture is first copied to an image, so this function
+    /// is slow and should only be used for debugging or screenshots.
+    /// The format of the image is automatically deduced from
+    /// the extension.
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to save
+    ///
+    /// Return true if saving was successful
+    pub fn save_to_file(&self, filename: &str) -> bool {
+        match self.copy_to_image() {
+            Some(image) => image.save_to_file(filename),
+            None        => false
+        }
+    }
 }
 
 impl Clone for Texture {
@@ -379,10 +650,7 @@ impl Clone for Texture {
         if tex.is_null() {
             panic!("Not enough memory to clone Texture")
         } else {
-            Texture {
-                texture: tex,
-                dropable: true
-            }
+            Texture::owned(tex)
         }
     }
 }
@@ -395,7 +663,8 @@ impl Wrappable<*mut ffi::sfTexture> for Texture {
     fn wrap(texture: *mut ffi::sfTexture) -> Texture {
         Texture {
             texture: texture,
-            dropable: false
+            dropable: false,
+            mipmap: false
         }
     }
 }
@@ -404,6 +673,7 @@ impl Drop for Texture {
     /// Destroy an existing texture
     fn drop(&mut self) {
         if self.dropable {
+            TEXTURE_MEMORY.fetch_sub(self.memory_usage(), SeqCst);
             unsafe {
                 ffi::sfTexture_destroy(self.texture)
             }
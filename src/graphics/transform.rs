@@ -28,6 +28,7 @@
 //! rotate, scale, shear, project, whatever things.
 
 use libc::c_float;
+use std::ops::Mul;
 
 use system::vector2::Vector2f;
 use graphics::FloatRect;
@@ -40,7 +41,7 @@ use ffi::graphics::transform as ffi;
 /// rotate, scale, shear, project, whatever things.
 #[repr(C)]
 #[allow(missing_docs)]
-#[derive(Copy)]
+#[derive(Clone, PartialEq, Show, Copy)]
 pub struct Transform {
     pub a00: f32,
     pub a01: f32,
@@ -78,15 +79,30 @@ impl Transform {
         }
     }
 
+    /// Create a new transform from a 3x3 matrix
+    ///
+    /// # Arguments
+    /// * matrix - The elements of the matrix, row by row
+    ///
+    /// Return a new Transform
+    pub fn new_from_matrix(matrix: &[f32; 9]) -> Transform {
+        Transform::new(matrix[0], matrix[1], matrix[2],
+                       matrix[3], matrix[4], matrix[5],
+                       matrix[6], matrix[7], matrix[8])
+    }
+
     /// Return the matrix
-    pub fn get_matrix(&mut self) -> [f32; 16] {
+    ///
+    /// The matrix is returned as a 4x4 matrix, in column-major order,
+    /// which can be directly used with OpenGL functions.
+    pub fn get_matrix(&self) -> [f32; 16] {
         unsafe {
-            let matrix: [f32; 16] =
+            let mut matrix: [f32; 16] =
                 [0.,0.,0.,0.,
                  0.,0.,0.,0.,
                  0.,0.,0.,0.,
                  0.,0.,0.,0.];
-            ffi::sfTransform_getMatrix(self, matrix.as_ptr() as *mut f32);
+            ffi::sfTransform_getMatrix(self, matrix.as_mut_ptr());
             matrix
         }
     }
@@ -106,7 +122,7 @@ impl Transform {
     /// is returned.
     ///
     /// Return the inverse matrix
-    pub fn get_inverse(&mut self) -> Transform {
+    pub fn get_inverse(&self) -> Transform {
         unsafe {
             ffi::sfTransform_getInverse(self)
         }
@@ -120,7 +136,7 @@ impl Transform {
     ///
     /// # Arguments
     /// * other - Transform to combine to transform
-    pub fn combine(&mut self, other: &mut Transform) -> () {
+    pub fn combine(&mut self, other: &Transform) -> () {
         unsafe {
             ffi::sfTransform_combine(self, other)
         }
@@ -213,7 +229,7 @@ impl Transform {
     /// * point - Point to transform
     ///
     /// Return a transformed point
-    pub fn transform_point(&mut self, point: &Vector2f) -> Vector2f {
+    pub fn transform_point(&self, point: &Vector2f) -> Vector2f {
         unsafe {
             ffi::sfTransform_transformPoint(self, *point)
         }
@@ -228,12 +244,123 @@ impl Transform {
     /// is returned.
     ///
     /// # Arguments
-    /// rectangle - Rectangle to transform
+    /// * rectangle - Rectangle to transform
     ///
     /// Return the transformed rectangle
-    pub fn transform_rect(&mut self, rectangle: &FloatRect) -> FloatRect {
+    pub fn transform_rect(&self, rectangle: &FloatRect) -> FloatRect {
         unsafe {
             ffi::sfTransform_transformRect(self, *rectangle)
         }
     }
+
+    /// Return the combination of two transforms
+    ///
+    /// # Arguments
+    /// * other - Transform to combine to self
+    ///
+    /// Return a new Transform equal to the matrix product self * other,
+    /// which transforms a point by other first, then by self
+    pub fn combined(&self, other: &Transform) -> Transform {
+        let mut transform = *self;
+        transform.combine(other);
+        transform
+    }
+
+    /// Return the combination of a transform with a translation
+    ///
+    /// # Arguments
+    /// * x - Offset to apply on X axis
+    /// * y - Offset to apply on Y axis
+    ///
+    /// Return a new Transform
+    pub fn translated(&self, x: f32, y: f32) -> Transform {
+        let mut transform = *self;
+        transform.translate(x, y);
+        transform
+    }
+
+    /// Return the combination of a transform with a rotation
+    ///
+    /// # Arguments
+    /// * angle - Rotation angle, in degrees
+    ///
+    /// Return a new Transform
+    pub fn rotated(&self, angle: f32) -> Transform {
+        let mut transform = *self;
+        transform.rotate(angle);
+        transform
+    }
+
+    /// Return the combination of a transform with a rotation around a point
+    ///
+    /// # Arguments
+    /// * angle - Rotation angle, in degrees
+    /// * center_x - X coordinate of the center of rotation
+    /// * center_y - Y coordinate of the center of rotation
+    ///
+    /// Return a new Transform
+    pub fn rotated_with_center(&self,
+                               angle: f32,
+                               center_x: f32,
+                               center_y: f32) -> Transform {
+        let mut transform = *self;
+        transform.rotate_with_center(angle, center_x, center_y);
+        transform
+    }
+
+    /// Return the combination of a transform with a scaling
+    ///
+    /// # Arguments
+    /// * scale_x - Scaling factor on the X axis
+    /// * scale_y - Scaling factor on the Y axis
+    ///
+    /// Return a new Transform
+    pub fn scaled(&self, scale_x: f32, scale_y: f32) -> Transform {
+        let mut transform = *self;
+        transform.scale(scale_x, scale_y);
+        transform
+    }
+
+    /// Return the combination of a transform with a scaling around a point
+    ///
+    /// # Arguments
+    /// * scale_x - Scaling factor on X axis
+    /// * scale_y - Scaling factor on Y axis
+    /// * center_x - X coordinate of the center of scaling
+    /// * center_y - Y coordinate of the center of scaling
+    ///
+    /// Return a new Transform
+    pub fn scaled_with_center(&self,
+                              scale_x: f32,
+                              scale_y: f32,
+                              center_x: f32,
+                              center_y: f32) -> Transform {
+        let mut transform = *self;
+        transform.scale_with_center(scale_x, scale_y, center_x, center_y);
+        transform
+    }
+}
+
+impl Mul for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Transform {
+        self.combined(&rhs)
+    }
+}
+
+impl Mul<Vector2f> for Transform {
+    type Output = Vector2f;
+
+    fn mul(self, rhs: Vector2f) -> Vector2f {
+        self.transform_point(&rhs)
+    }
+}
+
+impl Mul<FloatRect> for Transform {
+    type Output = FloatRect;
+
+    fn mul(self, rhs: FloatRect) -> FloatRect {
+        self.transform_rect(&rhs)
+    }
 }
@@ -27,6 +27,7 @@
 use libc::c_float;
 
 use traits::Wrappable;
+use traits::Transformable as TransformableTrait;
 use graphics::Transform;
 use system::vector2::Vector2f;
 
@@ -237,6 +238,60 @@ impl Clone for Transformable {
     }
 }
 
+impl TransformableTrait for Transformable {
+    fn set_position(&mut self, position: &Vector2f) -> () {
+        self.set_position(position)
+    }
+
+    fn set_rotation(&mut self, angle: f32) -> () {
+        self.set_rotation(angle)
+    }
+
+    fn set_scale(&mut self, scale: &Vector2f) -> () {
+        self.set_scale(scale)
+    }
+
+    fn set_origin(&mut self, origin: &Vector2f) -> () {
+        self.set_origin(origin)
+    }
+
+    fn get_position(&self) -> Vector2f {
+        self.get_position()
+    }
+
+    fn get_rotation(&self) -> f32 {
+        self.get_rotation()
+    }
+
+    fn get_scale(&self) -> Vector2f {
+        self.get_scale()
+    }
+
+    fn get_origin(&self) -> Vector2f {
+        self.get_origin()
+    }
+
+    fn move_(&mut self, offset: &Vector2f) -> () {
+        self.move_(offset)
+    }
+
+    fn rotate(&mut self, angle: f32) -> () {
+        self.rotate(angle)
+    }
+
+    fn scale(&mut self, factors: &Vector2f) -> () {
+        self.scale(factors)
+    }
+
+    fn get_transform(&self) -> Transform {
+        self.get_transform()
+    }
+
+    fn get_inverse_transform(&self) -> Transform {
+        self.get_inverse_transform()
+    }
+}
+
 impl Wrappable<*mut ffi::sfTransformable> for Transformable {
     fn wrap(transformable: *mut ffi::sfTransformable) -> Transformable {
         Transformable {
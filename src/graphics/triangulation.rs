@@ -0,0 +1,183 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Triangulation of concave polygons
+//!
+//! ConvexShape can only draw convex polygons, concave ones are rendered
+//! incorrectly. This module splits any simple polygon (without holes or
+//! crossing edges) into triangles with the ear clipping method, to be
+//! drawn as a VertexArray of Triangles.
+
+use graphics::{Color, FloatRect, Vertex, VertexArray, Triangles};
+use system::vector2::Vector2f;
+
+/// Split a simple polygon into triangles
+///
+/// The points can be given clockwise or counter clockwise.
+///
+/// # Arguments
+/// * points - The points of the polygon
+///
+/// Return Some(triangles), each triangle being three indices in points,
+/// or None if the polygon has less than 3 points or isn't simple
+pub fn triangulate(points: &[Vector2f]) -> Option<Vec<(uint, uint, uint)>> {
+    let count = points.len();
+    if count < 3 {
+        return None;
+    }
+    let orientation = if signed_area(points) < 0. { -1. } else { 1. };
+    let mut remaining: Vec<uint> = range(0u, count).collect();
+    let mut triangles = Vec::with_capacity(count - 2);
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let mut ear = None;
+        for i in range(0u, len) {
+            let prev = remaining[(i + len - 1) % len];
+            let curr = remaining[i];
+            let next = remaining[(i + 1) % len];
+            if is_ear(points, remaining.as_slice(), prev, curr, next, orientation) {
+                ear = Some(i);
+                break;
+            }
+        }
+        match ear {
+            Some(i) => {
+                triangles.push((remaining[(i + len - 1) % len],
+                                remaining[i],
+                                remaining[(i + 1) % len]));
+                remaining.remove(i);
+            },
+            None => {
+                // Drop a flat corner if any, otherwise the polygon crosses itself
+                let flat = range(0u, len).find(|&i| {
+                    let a = points[remaining[(i + len - 1) % len]];
+                    let b = points[remaining[i]];
+                    let c = points[remaining[(i + 1) % len]];
+                    cross(&a, &b, &c) == 0.
+                });
+                match flat {
+                    Some(i) => { remaining.remove(i); },
+                    None => return None
+                }
+            }
+        }
+    }
+    triangles.push((remaining[0], remaining[1], remaining[2]));
+    Some(triangles)
+}
+
+/// Build a VertexArray of Triangles filling a simple polygon
+///
+/// If a texture rectangle is given, it is stretched over the bounding
+/// box of the polygon to compute the texture coordinates.
+///
+/// # Arguments
+/// * points - The points of the polygon
+/// * color - The fill color
+/// * texture_rect - Some(rectangle) of the texture to map, or None
+///
+/// Return Some(VertexArray) or None if the polygon can't be triangulated
+pub fn new_vertex_array(points: &[Vector2f],
+                        color: &Color,
+                        texture_rect: Option<&FloatRect>) -> Option<VertexArray> {
+    let triangles = match triangulate(points) {
+        Some(triangles) => triangles,
+        None => return None
+    };
+    let mut array = match VertexArray::new() {
+        Some(array) => array,
+        None => return None
+    };
+    array.set_primitive_type(Triangles);
+
+    let bounds = bounding_box(points);
+    let tex_coords = |&: point: &Vector2f| -> Vector2f {
+        match texture_rect {
+            Some(rect) if bounds.width > 0. && bounds.height > 0. => Vector2f::new(
+                rect.left + (point.x - bounds.left) / bounds.width * rect.width,
+                rect.top + (point.y - bounds.top) / bounds.height * rect.height),
+            _ => Vector2f::new(0., 0.)
+        }
+    };
+    for &(a, b, c) in triangles.iter() {
+        for &i in [a, b, c].iter() {
+            array.append(&Vertex::new(&points[i], color, &tex_coords(&points[i])));
+        }
+    }
+    Some(array)
+}
+
+fn signed_area(points: &[Vector2f]) -> f32 {
+    let count = points.len();
+    let mut area = 0.;
+    for i in range(0u, count) {
+        let a = points[i];
+        let b = points[(i + 1) % count];
+        area += a.x * b.y - b.x * a.y;
+    }
+    area / 2.
+}
+
+fn cross(o: &Vector2f, a: &Vector2f, b: &Vector2f) -> f32 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn is_ear(points: &[Vector2f],
+          remaining: &[uint],
+          prev: uint,
+          curr: uint,
+          next: uint,
+          orientation: f32) -> bool {
+    let (a, b, c) = (points[prev], points[curr], points[next]);
+    // Reflex or flat corners are not ears
+    if cross(&a, &b, &c) * orientation <= 0. {
+        return false;
+    }
+    for &i in remaining.iter() {
+        if i == prev || i == curr || i == next {
+            continue;
+        }
+        let p = points[i];
+        if cross(&a, &b, &p) * orientation >= 0. &&
+           cross(&b, &c, &p) * orientation >= 0. &&
+           cross(&c, &a, &p) * orientation >= 0. {
+            return false;
+        }
+    }
+    true
+}
+
+fn bounding_box(points: &[Vector2f]) -> FloatRect {
+    let mut min = points[0];
+    let mut max = points[0];
+    for point in points.iter() {
+        if point.x < min.x { min.x = point.x; }
+        if point.y < min.y { min.y = point.y; }
+        if point.x > max.x { max.x = point.x; }
+        if point.y > max.y { max.y = point.y; }
+    }
+    FloatRect::new(min.x, min.y, max.x - min.x, max.y - min.y)
+}
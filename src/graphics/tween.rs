@@ -0,0 +1,624 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Tweening and timeline animations
+//!
+//! A Tween interpolates a value between two ends over a duration, shaped
+//! by an easing function. The animations built on top of it change the
+//! properties of an object: PropertyTween drives the position, rotation,
+//! scale or origin of any Transformable, ValueTween calls a user function
+//! with the interpolated value (to change a color for example). Sequence
+//! and Parallel group animations, and every animation is driven by the
+//! time elapsed since the last frame.
+
+use std::num::Float;
+use std::f32::consts::PI;
+
+use traits::Transformable;
+use graphics::Color;
+use system::vector2::Vector2f;
+use system::Time;
+
+/// Easing functions
+///
+/// An easing function maps the progress of an animation, between 0 and 1,
+/// to the interpolation factor.
+#[derive(Clone, PartialEq, Eq, Show, Copy)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Quadratic, accelerating
+    QuadIn,
+    /// Quadratic, decelerating
+    QuadOut,
+    /// Quadratic, accelerating then decelerating
+    QuadInOut,
+    /// Cubic, accelerating
+    CubicIn,
+    /// Cubic, decelerating
+    CubicOut,
+    /// Cubic, accelerating then decelerating
+    CubicInOut,
+    /// Sinusoidal, accelerating
+    SineIn,
+    /// Sinusoidal, decelerating
+    SineOut,
+    /// Sinusoidal, accelerating then decelerating
+    SineInOut,
+    /// Exponential, accelerating
+    ExpoIn,
+    /// Exponential, decelerating
+    ExpoOut,
+    /// Going slightly backward before starting
+    BackIn,
+    /// Going slightly past the end before coming back
+    BackOut,
+    /// Oscillating around the end before settling
+    ElasticOut,
+    /// Bouncing on the end
+    BounceOut
+}
+
+impl Easing {
+    /// Apply the easing function
+    ///
+    /// # Arguments
+    /// * t - The progress, clamped between 0 and 1
+    ///
+    /// Return the interpolation factor, 0 for t = 0 and 1 for t = 1
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.max(0.).min(1.);
+        match *self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2. - t),
+            Easing::QuadInOut => if t < 0.5 {
+                2. * t * t
+            } else {
+                -1. + (4. - 2. * t) * t
+            },
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => {
+                let u = t - 1.;
+                u * u * u + 1.
+            },
+            Easing::CubicInOut => if t < 0.5 {
+                4. * t * t * t
+            } else {
+                let u = 2. * t - 2.;
+                0.5 * u * u * u + 1.
+            },
+            Easing::SineIn => 1. - (t * PI / 2.).cos(),
+            Easing::SineOut => (t * PI / 2.).sin(),
+            Easing::SineInOut => 0.5 * (1. - (t * PI).cos()),
+            Easing::ExpoIn => if t == 0. { 0. } else { 2f32.powf(10. * (t - 1.)) },
+            Easing::ExpoOut => if t == 1. { 1. } else { 1. - 2f32.powf(-10. * t) },
+            Easing::BackIn => {
+                let s = 1.70158;
+                t * t * ((s + 1.) * t - s)
+            },
+            Easing::BackOut => {
+                let s = 1.70158;
+                let u = t - 1.;
+                u * u * ((s + 1.) * u + s) + 1.
+            },
+            Easing::ElasticOut => if t == 0. || t == 1. {
+                t
+            } else {
+                2f32.powf(-10. * t) * ((t - 0.075) * (2. * PI) / 0.3).sin() + 1.
+            },
+            Easing::BounceOut => {
+                let n = 7.5625;
+                if t < 1. / 2.75 {
+                    n * t * t
+                } else if t < 2. / 2.75 {
+                    let u = t - 1.5 / 2.75;
+                    n * u * u + 0.75
+                } else if t < 2.5 / 2.75 {
+                    let u = t - 2.25 / 2.75;
+                    n * u * u + 0.9375
+                } else {
+                    let u = t - 2.625 / 2.75;
+                    n * u * u + 0.984375
+                }
+            }
+        }
+    }
+}
+
+/// Values which can be interpolated
+pub trait Lerp: Copy {
+    /// Interpolate between two values
+    ///
+    /// # Arguments
+    /// * from - The value for t = 0
+    /// * to - The value for t = 1
+    /// * t - The interpolation factor, may be outside of 0 .. 1 with
+    /// some easing functions
+    ///
+    /// Return the interpolated value
+    fn lerp(from: &Self, to: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(from: &f32, to: &f32, t: f32) -> f32 {
+        *from + (*to - *from) * t
+    }
+}
+
+impl Lerp for Vector2f {
+    fn lerp(from: &Vector2f, to: &Vector2f, t: f32) -> Vector2f {
+        *from + (*to - *from) * t
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(from: &Color, to: &Color, t: f32) -> Color {
+        Color::lerp(*from, *to, t)
+    }
+}
+
+/// Interpolate a value over a duration
+#[derive(Clone, PartialEq, Show, Copy)]
+pub struct Tween<V> {
+    #[doc(hidden)]
+    from: V,
+    #[doc(hidden)]
+    to: V,
+    #[doc(hidden)]
+    duration: f32,
+    #[doc(hidden)]
+    elapsed: f32,
+    #[doc(hidden)]
+    easing: Easing
+}
+
+impl<V: Lerp> Tween<V> {
+    /// Create a new Tween
+    ///
+    /// # Arguments
+    /// * from - The value at the start
+    /// * to - The value at the end
+    /// * duration - The duration of the tween
+    /// * easing - The easing function
+    ///
+    /// Return a new Tween
+    pub fn new(from: V, to: V, duration: &Time, easing: Easing) -> Tween<V> {
+        Tween {
+            from: from,
+            to: to,
+            duration: duration.as_seconds(),
+            elapsed: 0.,
+            easing: easing
+        }
+    }
+
+    /// Advance the tween
+    ///
+    /// # Arguments
+    /// * seconds - Time to advance, in seconds
+    ///
+    /// Return the part of seconds left once the tween is finished
+    pub fn advance(&mut self, seconds: f32) -> f32 {
+        let left = self.duration - self.elapsed;
+        if seconds >= left {
+            self.elapsed = self.duration;
+            seconds - left
+        } else {
+            self.elapsed += seconds;
+            0.
+        }
+    }
+
+    /// Get the current value
+    ///
+    /// Return the interpolated value
+    pub fn get_value(&self) -> V {
+        let progress = if self.duration > 0. { self.elapsed / self.duration } else { 1. };
+        Lerp::lerp(&self.from, &self.to, self.easing.apply(progress))
+    }
+
+    /// Tell whether the tween reached its end
+    ///
+    /// Return true if the tween is finished
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Restart the tween from the beginning
+    pub fn reset(&mut self) -> () {
+        self.elapsed = 0.
+    }
+}
+
+/// An animation changing an object of type T
+pub trait Animation<T> {
+    /// Advance the animation and apply it to the target
+    ///
+    /// # Arguments
+    /// * target - The object to animate
+    /// * seconds - Time to advance, in seconds
+    ///
+    /// Return the part of seconds left once the animation is finished
+    fn advance(&mut self, target: &mut T, seconds: f32) -> f32;
+
+    /// Tell whether the animation is finished
+    fn is_finished(&self) -> bool;
+
+    /// Restart the animation from the beginning
+    fn reset(&mut self) -> ();
+
+    /// Advance the animation by the time elapsed since the last frame
+    ///
+    /// # Arguments
+    /// * target - The object to animate
+    /// * elapsed - Time elapsed since the last update
+    fn update(&mut self, target: &mut T, elapsed: &Time) -> () {
+        self.advance(target, elapsed.as_seconds());
+    }
+}
+
+/// The properties of a Transformable driven by a PropertyTween
+#[derive(Clone, PartialEq, Eq, Show, Copy)]
+pub enum Property {
+    /// The position
+    Position,
+    /// The rotation, only the x coordinate of the values is used
+    Rotation,
+    /// The scale
+    Scale,
+    /// The origin
+    Origin
+}
+
+/// Animate a property of a Transformable
+pub struct PropertyTween {
+    #[doc(hidden)]
+    property: Property,
+    #[doc(hidden)]
+    tween: Tween<Vector2f>,
+    #[doc(hidden)]
+    on_complete: Option<Box<FnMut() + 'static>>
+}
+
+impl PropertyTween {
+    /// Create a new PropertyTween
+    ///
+    /// # Arguments
+    /// * property - The property to animate
+    /// * from - The value at the start
+    /// * to - The value at the end
+    /// * duration - The duration of the animation
+    /// * easing - The easing function
+    ///
+    /// Return a new PropertyTween
+    pub fn new(property: Property,
+               from: &Vector2f,
+               to: &Vector2f,
+               duration: &Time,
+               easing: Easing) -> PropertyTween {
+        PropertyTween {
+            property: property,
+            tween: Tween::new(*from, *to, duration, easing),
+            on_complete: None
+        }
+    }
+
+    /// Create a new PropertyTween animating the rotation
+    ///
+    /// # Arguments
+    /// * from - The angle at the start, in degrees
+    /// * to - The angle at the end, in degrees
+    /// * duration - The duration of the animation
+    /// * easing - The easing function
+    ///
+    /// Return a new PropertyTween
+    pub fn new_rotation(from: f32,
+                        to: f32,
+                        duration: &Time,
+                        easing: Easing) -> PropertyTween {
+        PropertyTween::new(Property::Rotation,
+                           &Vector2f::new(from, 0.),
+                           &Vector2f::new(to, 0.),
+                           duration,
+                           easing)
+    }
+
+    /// Set a function called once the animation is finished
+    ///
+    /// # Arguments
+    /// * on_complete - The function to call
+    pub fn set_on_complete(&mut self, on_complete: Box<FnMut() + 'static>) -> () {
+        self.on_complete = Some(on_complete)
+    }
+}
+
+impl<T: Transformable> Animation<T> for PropertyTween {
+    fn advance(&mut self, target: &mut T, seconds: f32) -> f32 {
+        let was_finished = self.tween.is_finished();
+        let left = self.tween.advance(seconds);
+        let value = self.tween.get_value();
+        match self.property {
+            Property::Position => target.set_position(&value),
+            Property::Rotation => target.set_rotation(value.x),
+            Property::Scale => target.set_scale(&value),
+            Property::Origin => target.set_origin(&value)
+        }
+        if !was_finished && self.tween.is_finished() {
+            match self.on_complete {
+                Some(ref mut on_complete) => (*on_complete)(),
+                None => ()
+            }
+        }
+        left
+    }
+
+    fn is_finished(&self) -> bool {
+        self.tween.is_finished()
+    }
+
+    fn reset(&mut self) -> () {
+        self.tween.reset()
+    }
+}
+
+/// Animate any value of an object through a function
+///
+/// For example a ValueTween<Sprite, Color> calling set_color animates
+/// the color of a sprite.
+pub struct ValueTween<T, V> {
+    #[doc(hidden)]
+    tween: Tween<V>,
+    #[doc(hidden)]
+    apply: Box<Fn(&mut T, V) + 'static>,
+    #[doc(hidden)]
+    on_complete: Option<Box<FnMut() + 'static>>
+}
+
+impl<T, V: Lerp> ValueTween<T, V> {
+    /// Create a new ValueTween
+    ///
+    /// # Arguments
+    /// * from - The value at the start
+    /// * to - The value at the end
+    /// * duration - The duration of the animation
+    /// * easing - The easing function
+    /// * apply - The function setting the value on the object
+    ///
+    /// Return a new ValueTween
+    pub fn new(from: V,
+               to: V,
+               duration: &Time,
+               easing: Easing,
+               apply: Box<Fn(&mut T, V) + 'static>) -> ValueTween<T, V> {
+        ValueTween {
+            tween: Tween::new(from, to, duration, easing),
+            apply: apply,
+            on_complete: None
+        }
+    }
+
+    /// Set a function called once the animation is finished
+    ///
+    /// # Arguments
+    /// * on_complete - The function to call
+    pub fn set_on_complete(&mut self, on_complete: Box<FnMut() + 'static>) -> () {
+        self.on_complete = Some(on_complete)
+    }
+}
+
+impl<T, V: Lerp> Animation<T> for ValueTween<T, V> {
+    fn advance(&mut self, target: &mut T, seconds: f32) -> f32 {
+        let was_finished = self.tween.is_finished();
+        let left = self.tween.advance(seconds);
+        (*self.apply)(target, self.tween.get_value());
+        if !was_finished && self.tween.is_finished() {
+            match self.on_complete {
+                Some(ref mut on_complete) => (*on_complete)(),
+                None => ()
+            }
+        }
+        left
+    }
+
+    fn is_finished(&self) -> bool {
+        self.tween.is_finished()
+    }
+
+    fn reset(&mut self) -> () {
+        self.tween.reset()
+    }
+}
+
+/// Wait for a duration, to space the animations of a Sequence
+#[derive(Clone, PartialEq, Show, Copy)]
+pub struct Delay {
+    #[doc(hidden)]
+    duration: f32,
+    #[doc(hidden)]
+    elapsed: f32
+}
+
+impl Delay {
+    /// Create a new Delay
+    ///
+    /// # Arguments
+    /// * duration - The duration to wait
+    ///
+    /// Return a new Delay
+    pub fn new(duration: &Time) -> Delay {
+        Delay {
+            duration: duration.as_seconds(),
+            elapsed: 0.
+        }
+    }
+}
+
+impl<T> Animation<T> for Delay {
+    fn advance(&mut self, _: &mut T, seconds: f32) -> f32 {
+        let left = self.duration - self.elapsed;
+        if seconds >= left {
+            self.elapsed = self.duration;
+            seconds - left
+        } else {
+            self.elapsed += seconds;
+            0.
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn reset(&mut self) -> () {
+        self.elapsed = 0.
+    }
+}
+
+/// Run animations one after the other
+pub struct Sequence<T> {
+    #[doc(hidden)]
+    animations: Vec<Box<Animation<T> + 'static>>,
+    #[doc(hidden)]
+    current: uint,
+    #[doc(hidden)]
+    looping: bool
+}
+
+impl<T> Sequence<T> {
+    /// Create a new empty Sequence
+    ///
+    /// Return a new Sequence
+    pub fn new() -> Sequence<T> {
+        Sequence {
+            animations: Vec::new(),
+            current: 0,
+            looping: false
+        }
+    }
+
+    /// Add an animation at the end of the sequence
+    ///
+    /// # Arguments
+    /// * animation - The animation to add
+    pub fn push(&mut self, animation: Box<Animation<T> + 'static>) -> () {
+        self.animations.push(animation)
+    }
+
+    /// Make the sequence restart once finished
+    ///
+    /// # Arguments
+    /// * looping - true to loop, false to stop at the end
+    pub fn set_loop(&mut self, looping: bool) -> () {
+        self.looping = looping
+    }
+}
+
+impl<T> Animation<T> for Sequence<T> {
+    fn advance(&mut self, target: &mut T, seconds: f32) -> f32 {
+        let mut left = seconds;
+        let mut pass_start: Option<f32> = None;
+        loop {
+            if self.current >= self.animations.len() {
+                if !self.looping || self.animations.is_empty() {
+                    return left;
+                }
+                // A whole pass which consumed no time would loop forever
+                match pass_start {
+                    Some(start) if left >= start => return 0.,
+                    _                            => ()
+                }
+                pass_start = Some(left);
+                self.reset();
+            }
+            left = self.animations[self.current].advance(target, left);
+            if !self.animations[self.current].is_finished() {
+                return 0.;
+            }
+            self.current += 1;
+            if left <= 0. && self.current < self.animations.len() {
+                return 0.;
+            }
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        !self.looping && self.current >= self.animations.len()
+    }
+
+    fn reset(&mut self) -> () {
+        self.current = 0;
+        for animation in self.animations.iter_mut() {
+            animation.reset();
+        }
+    }
+}
+
+/// Run animations at the same time
+pub struct Parallel<T> {
+    #[doc(hidden)]
+    animations: Vec<Box<Animation<T> + 'static>>
+}
+
+impl<T> Parallel<T> {
+    /// Create a new empty Parallel group
+    ///
+    /// Return a new Parallel
+    pub fn new() -> Parallel<T> {
+        Parallel {
+            animations: Vec::new()
+        }
+    }
+
+    /// Add an animation to the group
+    ///
+    /// # Arguments
+    /// * animation - The animation to add
+    pub fn push(&mut self, animation: Box<Animation<T> + 'static>) -> () {
+        self.animations.push(animation)
+    }
+}
+
+impl<T> Animation<T> for Parallel<T> {
+    fn advance(&mut self, target: &mut T, seconds: f32) -> f32 {
+        let mut left = seconds;
+        for animation in self.animations.iter_mut() {
+            if !animation.is_finished() {
+                left = left.min(animation.advance(target, seconds));
+            }
+        }
+        if self.is_finished() { left } else { 0. }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.animations.iter().all(|animation| animation.is_finished())
+    }
+
+    fn reset(&mut self) -> () {
+        for animation in self.animations.iter_mut() {
+            animation.reset();
+        }
+    }
+}
@@ -24,7 +24,7 @@
 
 //! Define a set of one or more 2D primitives
 
-use libc::c_uint;
+use libc::size_t;
 use std::mem;
 use std::ops::Index;
 
@@ -134,7 +134,7 @@ impl VertexArray {
     /// * vertex_count - New size of the array (number of vertices)
     pub fn resize(&mut self, vertex_count: uint) -> () {
         unsafe {
-            ffi::sfVertexArray_resize(self.vertex_array, vertex_count as c_uint)
+            ffi::sfVertexArray_resize(self.vertex_array, vertex_count as size_t)
         }
     }
 
@@ -229,7 +229,7 @@ impl VertexArray {
     pub fn get_vertex(&self, index: uint) -> &mut Vertex {
         unsafe {
             mem::transmute(ffi::sfVertexArray_getVertex(self.vertex_array,
-                                                         index as c_uint))
+                                                         index as size_t))
         }
     }
 
@@ -268,7 +268,7 @@ impl<'s> Iterator for Vertices<'s> {
             self.pos += 1;
             unsafe {
                 mem::transmute(ffi::sfVertexArray_getVertex(self.vertex_array,
-                                                             self.pos as c_uint))
+                                                             self.pos as size_t))
             }
         }
     }
@@ -281,7 +281,7 @@ impl Index<uint> for VertexArray {
         unsafe {
             mem::transmute::<*const Vertex, &'s Vertex>
                 (ffi::sfVertexArray_getVertex(self.vertex_array,
-                                              *_rhs as c_uint) as *const Vertex)
+                                              *_rhs as size_t) as *const Vertex)
         }
     }
 }
@@ -29,9 +29,11 @@
 //! the way that your drawable objects are drawn.
 
 use libc::c_float;
+use std::f32::consts::PI;
+use std::num::Float;
 
 use traits::Wrappable;
-use graphics::FloatRect;
+use graphics::{FloatRect, Transform};
 use system::vector2::Vector2f;
 
 use ffi::graphics::view as ffi;
@@ -188,7 +190,6 @@ impl View {
     /// # Arguments
     /// * center_x - New x center coordinate
     /// * center_y - New y center coordinate
-    ////
     pub fn set_center2f(&mut self, center_x: f32, center_y: f32) -> () {
         unsafe {
             ffi::sfView_setCenter(self.view, Vector2f::new(center_x, center_y))
@@ -225,11 +226,12 @@ impl View {
             ffi::sfView_move(self.view, *offset)
         }
     }
+
     /// Move a view relatively to its current position
     ///
     /// # Arguments
-    /// * offsetX - Offset x
-    /// * offsetY - Offset y
+    /// * offset_x - Offset x
+    /// * offset_y - Offset y
     pub fn move2f(&mut self, offset_x: f32, offset_y: f32) -> () {
         unsafe {
             ffi::sfView_move(self.view, Vector2f::new(offset_x, offset_y))
@@ -289,6 +291,50 @@ impl View {
             ffi::sfView_getViewport(self.view)
         }
     }
+
+    /// Get the rectangle of the world displayed by a view
+    ///
+    /// The rotation of the view is not taken into account, the
+    /// rectangle is the one given by the center and the size of the view.
+    ///
+    /// Return the displayed zone, in world coordinates
+    pub fn get_bounds(&self) -> FloatRect {
+        let center = self.get_center();
+        let size = self.get_size();
+        FloatRect::new(center.x - size.x / 2., center.y - size.y / 2., size.x, size.y)
+    }
+
+    /// Get the projection transform of a view
+    ///
+    /// This transform maps world coordinates to normalized device
+    /// coordinates, between -1 and 1, as SFML does internally.
+    ///
+    /// Return the projection transform of the view
+    pub fn get_transform(&self) -> Transform {
+        let center = self.get_center();
+        let size = self.get_size();
+        let angle = self.get_rotation() * PI / 180.;
+        let cosine = angle.cos();
+        let sine = angle.sin();
+        let tx = -center.x * cosine - center.y * sine + center.x;
+        let ty = center.x * sine - center.y * cosine + center.y;
+
+        let a = 2. / size.x;
+        let b = -2. / size.y;
+        let c = -a * center.x;
+        let d = -b * center.y;
+
+        Transform::new(a * cosine, a * sine,   a * tx + c,
+                       -b * sine,  b * cosine, b * ty + d,
+                       0.,         0.,         1.)
+    }
+
+    /// Get the inverse projection transform of a view
+    ///
+    /// Return the inverse of the projection transform of the view
+    pub fn get_inverse_transform(&self) -> Transform {
+        self.get_transform().get_inverse()
+    }
 }
 
 impl Clone for View {
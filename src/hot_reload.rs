@@ -0,0 +1,276 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Reload assets when their file changes
+//!
+//! HotReloader watches the files of textures, fonts and sound buffers
+//! and reloads them in place, which is handy while tweaking assets during
+//! development. Watching is done by comparing modification times, call
+//! poll regularly (once per second is plenty).
+//!
+//! Textures are updated in place, every sprite using them shows the new
+//! image right away. Fonts and sound buffers can't be updated in place by
+//! SFML: a reload creates a new shared handle, which get_font and
+//! get_sound_buffer return, and which poll gives to the texts and sounds
+//! registered with track_text and track_sound. The other users keep the
+//! old font or buffer, it is destroyed when the last of them drops it.
+//!
+//! This module is only available with the "hot_reload" feature.
+
+use std::rc::{Rc, Weak};
+use std::cell::RefCell;
+use std::io::fs;
+
+use graphics::{Texture, Font};
+use graphics::rc::Text;
+use audio::SoundBuffer;
+use audio::rc::Sound;
+
+/// A watched asset
+enum Asset {
+    TextureAsset(Rc<RefCell<Texture>>),
+    FontAsset(Rc<RefCell<Font>>),
+    SoundBufferAsset(Rc<RefCell<SoundBuffer>>)
+}
+
+/// A watched file
+struct Entry {
+    name: String,
+    filename: String,
+    modified: u64,
+    asset: Asset,
+    texts: Vec<Weak<RefCell<Text>>>,
+    sounds: Vec<Weak<RefCell<Sound>>>
+}
+
+/// Watch asset files and reload them when they change
+pub struct HotReloader {
+    #[doc(hidden)]
+    entries: Vec<Entry>
+}
+
+impl HotReloader {
+    /// Create a new HotReloader watching nothing
+    ///
+    /// Return a new HotReloader
+    pub fn new() -> HotReloader {
+        HotReloader {
+            entries: Vec::new()
+        }
+    }
+
+    /// Load a texture and watch its file
+    ///
+    /// # Arguments
+    /// * name - Name reported by poll when the texture is reloaded
+    /// * filename - Path of the image file to load
+    ///
+    /// Return Some(texture) or None if the first loading failed
+    pub fn watch_texture(&mut self, name: &str, filename: &str) -> Option<Rc<RefCell<Texture>>> {
+        Texture::new_from_file(filename).map(|texture| {
+            let texture = Rc::new(RefCell::new(texture));
+            self.watch(name, filename, Asset::TextureAsset(texture.clone()));
+            texture
+        })
+    }
+
+    /// Load a font and watch its file
+    ///
+    /// The returned handle keeps the font loaded now, use get_font to
+    /// get the reloaded one.
+    ///
+    /// # Arguments
+    /// * name - Name reported by poll when the font is reloaded
+    /// * filename - Path of the font file to load
+    ///
+    /// Return Some(font) or None if the first loading failed
+    pub fn watch_font(&mut self, name: &str, filename: &str) -> Option<Rc<RefCell<Font>>> {
+        Font::new_from_file(filename).map(|font| {
+            let font = Rc::new(RefCell::new(font));
+            self.watch(name, filename, Asset::FontAsset(font.clone()));
+            font
+        })
+    }
+
+    /// Load a sound buffer and watch its file
+    ///
+    /// The returned handle keeps the buffer loaded now, use
+    /// get_sound_buffer to get the reloaded one.
+    ///
+    /// # Arguments
+    /// * name - Name reported by poll when the sound buffer is reloaded
+    /// * filename - Path of the sound file to load
+    ///
+    /// Return Some(buffer) or None if the first loading failed
+    pub fn watch_sound_buffer(&mut self,
+                              name: &str,
+                              filename: &str) -> Option<Rc<RefCell<SoundBuffer>>> {
+        SoundBuffer::new(filename).map(|buffer| {
+            let buffer = Rc::new(RefCell::new(buffer));
+            self.watch(name, filename, Asset::SoundBufferAsset(buffer.clone()));
+            buffer
+        })
+    }
+
+    /// Get the latest version of a watched font
+    ///
+    /// # Arguments
+    /// * name - The name given when watching the font
+    ///
+    /// Return Some(font) or None if no font is watched with this name
+    pub fn get_font(&self, name: &str) -> Option<Rc<RefCell<Font>>> {
+        self.entries.iter().filter(|entry| entry.name.as_slice() == name)
+            .filter_map(|entry| match entry.asset {
+                Asset::FontAsset(ref font) => Some(font.clone()),
+                _                          => None
+            }).next()
+    }
+
+    /// Get the latest version of a watched sound buffer
+    ///
+    /// # Arguments
+    /// * name - The name given when watching the sound buffer
+    ///
+    /// Return Some(buffer) or None if no sound buffer is watched with this name
+    pub fn get_sound_buffer(&self, name: &str) -> Option<Rc<RefCell<SoundBuffer>>> {
+        self.entries.iter().filter(|entry| entry.name.as_slice() == name)
+            .filter_map(|entry| match entry.asset {
+                Asset::SoundBufferAsset(ref buffer) => Some(buffer.clone()),
+                _                                   => None
+            }).next()
+    }
+
+    /// Give the reloaded font to a text each time the watched font changes
+    ///
+    /// The text is only referenced weakly, dropping it stops the tracking.
+    ///
+    /// # Arguments
+    /// * name - The name given when watching the font
+    /// * text - The text using the font
+    pub fn track_text(&mut self, name: &str, text: &Rc<RefCell<Text>>) -> () {
+        for entry in self.entries.iter_mut().filter(|entry| entry.name.as_slice() == name) {
+            entry.texts.push(text.downgrade());
+        }
+    }
+
+    /// Give the reloaded buffer to a sound each time the watched buffer changes
+    ///
+    /// The sound is only referenced weakly, dropping it stops the tracking.
+    /// Setting the buffer stops the sound, like SFML does.
+    ///
+    /// # Arguments
+    /// * name - The name given when watching the sound buffer
+    /// * sound - The sound using the buffer
+    pub fn track_sound(&mut self, name: &str, sound: &Rc<RefCell<Sound>>) -> () {
+        for entry in self.entries.iter_mut().filter(|entry| entry.name.as_slice() == name) {
+            entry.sounds.push(sound.downgrade());
+        }
+    }
+
+    /// Stop watching an asset
+    ///
+    /// The asset itself stays valid.
+    ///
+    /// # Arguments
+    /// * name - The name given when watching the asset
+    pub fn unwatch(&mut self, name: &str) -> () {
+        self.entries.retain(|entry| entry.name.as_slice() != name)
+    }
+
+    /// Get the number of watched assets
+    pub fn get_watch_count(&self) -> uint {
+        self.entries.len()
+    }
+
+    /// Reload the assets whose file changed since the last call
+    ///
+    /// A file which fails to load (while it is still being written for
+    /// example) is tried again on the next call.
+    ///
+    /// Return the names of the reloaded assets
+    pub fn poll(&mut self) -> Vec<String> {
+        let mut reloaded = Vec::new();
+        for entry in self.entries.iter_mut() {
+            let modified = match modification_time(entry.filename.as_slice()) {
+                Some(modified) => modified,
+                None           => continue
+            };
+            if modified == entry.modified {
+                continue;
+            }
+            let filename = entry.filename.as_slice();
+            let success = match entry.asset {
+                Asset::TextureAsset(ref texture) => match Texture::new_from_file(filename) {
+                    Some(mut new) => { texture.borrow_mut().swap(&mut new); true },
+                    None          => false
+                },
+                Asset::FontAsset(ref mut font) => match Font::new_from_file(filename) {
+                    Some(new) => {
+                        *font = Rc::new(RefCell::new(new));
+                        entry.texts.retain(|text| match text.upgrade() {
+                            Some(text) => { text.borrow_mut().set_font(font.clone()); true },
+                            None       => false
+                        });
+                        true
+                    },
+                    None      => false
+                },
+                Asset::SoundBufferAsset(ref mut buffer) => match SoundBuffer::new(filename) {
+                    Some(new) => {
+                        *buffer = Rc::new(RefCell::new(new));
+                        entry.sounds.retain(|sound| match sound.upgrade() {
+                            Some(sound) => { sound.borrow_mut().set_buffer(buffer.clone()); true },
+                            None        => false
+                        });
+                        true
+                    },
+                    None      => false
+                }
+            };
+            if success {
+                entry.modified = modified;
+                reloaded.push(entry.name.clone());
+            }
+        }
+        reloaded
+    }
+
+    /// Start watching a loaded asset
+    fn watch(&mut self, name: &str, filename: &str, asset: Asset) -> () {
+        self.entries.push(Entry {
+                name: name.to_string(),
+                filename: filename.to_string(),
+                modified: modification_time(filename).unwrap_or(0),
+                asset: asset,
+                texts: Vec::new(),
+                sounds: Vec::new()
+            })
+    }
+}
+
+/// Get the last modification time of a file
+fn modification_time(filename: &str) -> Option<u64> {
+    fs::stat(&Path::new(filename)).ok().map(|stat| stat.modified)
+}
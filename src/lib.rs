@@ -131,10 +131,74 @@
 extern crate libc;
 extern crate core;
 
+// Implement Transformable for a type by forwarding to its inherent methods,
+// the lifetimes of the type follow it: impl_transformable!(Sprite<'s>, 's)
+macro_rules! impl_transformable {
+    ($ty:ty $(, $lt:tt)*) => (
+        impl<$($lt),*> ::traits::Transformable for $ty {
+            fn set_position(&mut self, position: &::system::vector2::Vector2f) -> () {
+                self.set_position(position)
+            }
+
+            fn set_rotation(&mut self, angle: f32) -> () {
+                self.set_rotation(angle)
+            }
+
+            fn set_scale(&mut self, scale: &::system::vector2::Vector2f) -> () {
+                self.set_scale(scale)
+            }
+
+            fn set_origin(&mut self, origin: &::system::vector2::Vector2f) -> () {
+                self.set_origin(origin)
+            }
+
+            fn get_position(&self) -> ::system::vector2::Vector2f {
+                self.get_position()
+            }
+
+            fn get_rotation(&self) -> f32 {
+                self.get_rotation()
+            }
+
+            fn get_scale(&self) -> ::system::vector2::Vector2f {
+                self.get_scale()
+            }
+
+            fn get_origin(&self) -> ::system::vector2::Vector2f {
+                self.get_origin()
+            }
+
+            fn move_(&mut self, offset: &::system::vector2::Vector2f) -> () {
+                self.move_(offset)
+            }
+
+            fn rotate(&mut self, angle: f32) -> () {
+                self.rotate(angle)
+            }
+
+            fn scale(&mut self, factors: &::system::vector2::Vector2f) -> () {
+                self.scale(factors)
+            }
+
+            fn get_transform(&self) -> ::graphics::Transform {
+                self.get_transform()
+            }
+
+            fn get_inverse_transform(&self) -> ::graphics::Transform {
+                self.get_inverse_transform()
+            }
+        }
+    )
+}
+
 pub mod traits;
 pub mod system;
 pub mod window;
 pub mod audio;
 pub mod graphics;
 pub mod network;
+pub mod loader;
+pub mod resource_cache;
+#[cfg(feature = "hot_reload")]
+pub mod hot_reload;
 mod ffi;
@@ -0,0 +1,320 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Threaded asset loading
+//!
+//! AssetLoader reads and decodes images, fonts and sound buffers on worker
+//! threads so a loading screen can keep drawing and handling events. The
+//! graphics objects (textures especially, which need the OpenGL context)
+//! are only created on the main thread, when poll is called. Sound buffers
+//! don't depend on a context and are created directly by the workers.
+//!
+//! ```ignore
+//! let mut loader = AssetLoader::new(2);
+//! loader.load_texture("player", "resources/player.png");
+//! loader.load_font("ui", "resources/sansation.ttf");
+//! while !loader.get_progress().is_done() {
+//!     loader.poll();
+//!     // handle events, draw the loading bar with get_progress().get_ratio()
+//! }
+//! let player = loader.take_texture("player");
+//! ```
+
+use std::collections::HashMap;
+use std::io::File;
+use std::sync::TaskPool;
+use std::sync::mpsc::{channel, Sender, Receiver};
+
+use graphics::{Image, Texture, Font};
+use audio::SoundBuffer;
+
+/// Data decoded by a worker thread
+enum Payload {
+    ImageData(uint, uint, Vec<u8>),
+    TextureData(uint, uint, Vec<u8>),
+    FontData(Vec<u8>),
+    SoundBufferData(SoundBuffer),
+    Failed
+}
+
+/// Progress of the loading
+#[derive(Clone, PartialEq, Eq, Show, Copy)]
+pub struct LoadProgress {
+    /// Number of assets loaded successfully
+    pub loaded: uint,
+    /// Number of assets which failed to load
+    pub failed: uint,
+    /// Number of assets requested
+    pub total: uint
+}
+
+impl LoadProgress {
+    /// Tell whether every requested asset is loaded or failed
+    pub fn is_done(&self) -> bool {
+        self.loaded + self.failed >= self.total
+    }
+
+    /// Get the completed part of the loading
+    ///
+    /// Return a ratio between 0 and 1, 1 if nothing was requested
+    pub fn get_ratio(&self) -> f32 {
+        if self.total == 0 {
+            1.
+        } else {
+            (self.loaded + self.failed) as f32 / self.total as f32
+        }
+    }
+}
+
+/// Load assets on worker threads
+pub struct AssetLoader {
+    #[doc(hidden)]
+    pool: TaskPool,
+    #[doc(hidden)]
+    sender: Sender<(String, Payload)>,
+    #[doc(hidden)]
+    receiver: Receiver<(String, Payload)>,
+    #[doc(hidden)]
+    progress: LoadProgress,
+    #[doc(hidden)]
+    images: HashMap<String, Image>,
+    #[doc(hidden)]
+    textures: HashMap<String, Texture>,
+    #[doc(hidden)]
+    fonts: HashMap<String, Font>,
+    #[doc(hidden)]
+    sound_buffers: HashMap<String, SoundBuffer>,
+    #[doc(hidden)]
+    failures: Vec<String>
+}
+
+impl AssetLoader {
+    /// Create a new AssetLoader
+    ///
+    /// # Arguments
+    /// * threads - Number of worker threads, at least 1
+    ///
+    /// Return a new AssetLoader
+    pub fn new(threads: uint) -> AssetLoader {
+        let (sender, receiver) = channel();
+        AssetLoader {
+            pool: TaskPool::new(if threads == 0 { 1 } else { threads }),
+            sender: sender,
+            receiver: receiver,
+            progress: LoadProgress {
+                loaded: 0,
+                failed: 0,
+                total: 0
+            },
+            images: HashMap::new(),
+            textures: HashMap::new(),
+            fonts: HashMap::new(),
+            sound_buffers: HashMap::new(),
+            failures: Vec::new()
+        }
+    }
+
+    /// Request an image to be loaded
+    ///
+    /// # Arguments
+    /// * name - Name used to take the image once loaded
+    /// * filename - Path of the image file to load
+    pub fn load_image(&mut self, name: &str, filename: &str) -> () {
+        self.spawn(name, filename, |&: filename: &str| {
+            match decode_image(filename) {
+                Some((width, height, pixels)) => Payload::ImageData(width, height, pixels),
+                None                          => Payload::Failed
+            }
+        })
+    }
+
+    /// Request a texture to be loaded
+    ///
+    /// The image is decoded on a worker thread, the texture is created
+    /// on the thread calling poll.
+    ///
+    /// # Arguments
+    /// * name - Name used to take the texture once loaded
+    /// * filename - Path of the image file to load
+    pub fn load_texture(&mut self, name: &str, filename: &str) -> () {
+        self.spawn(name, filename, |&: filename: &str| {
+            match decode_image(filename) {
+                Some((width, height, pixels)) => Payload::TextureData(width, height, pixels),
+                None                          => Payload::Failed
+            }
+        })
+    }
+
+    /// Request a font to be loaded
+    ///
+    /// # Arguments
+    /// * name - Name used to take the font once loaded
+    /// * filename - Path of the font file to load
+    pub fn load_font(&mut self, name: &str, filename: &str) -> () {
+        self.spawn(name, filename, |&: filename: &str| {
+            match File::open(&Path::new(filename)).read_to_end() {
+                Ok(data) => Payload::FontData(data),
+                Err(_)   => Payload::Failed
+            }
+        })
+    }
+
+    /// Request a sound buffer to be loaded
+    ///
+    /// # Arguments
+    /// * name - Name used to take the sound buffer once loaded
+    /// * filename - Path of the sound file to load
+    pub fn load_sound_buffer(&mut self, name: &str, filename: &str) -> () {
+        self.spawn(name, filename, |&: filename: &str| {
+            match SoundBuffer::new(filename) {
+                Some(buffer) => Payload::SoundBufferData(buffer),
+                None         => Payload::Failed
+            }
+        })
+    }
+
+    /// Finish the loading of the assets decoded since the last call
+    ///
+    /// This creates the SFML objects, it must be called regularly from
+    /// the thread owning the OpenGL context, usually once per frame.
+    ///
+    /// Return the number of assets completed by this call
+    pub fn poll(&mut self) -> uint {
+        let mut count = 0;
+        loop {
+            let (name, payload) = match self.receiver.try_recv() {
+                Ok(result) => result,
+                Err(_)     => break
+            };
+            let success = match payload {
+                Payload::ImageData(width, height, pixels) => {
+                    match Image::create_from_pixels(width, height, pixels.as_slice()) {
+                        Some(image) => { self.images.insert(name.clone(), image); true },
+                        None        => false
+                    }
+                },
+                Payload::TextureData(width, height, pixels) => {
+                    let texture = Image::create_from_pixels(width, height, pixels.as_slice())
+                        .and_then(|image| Texture::new_from_image(&image));
+                    match texture {
+                        Some(texture) => { self.textures.insert(name.clone(), texture); true },
+                        None          => false
+                    }
+                },
+                Payload::FontData(data) => {
+                    match Font::new_from_memory(data.as_slice()) {
+                        Some(font) => { self.fonts.insert(name.clone(), font); true },
+                        None       => false
+                    }
+                },
+                Payload::SoundBufferData(buffer) => {
+                    self.sound_buffers.insert(name.clone(), buffer);
+                    true
+                },
+                Payload::Failed => false
+            };
+            if success {
+                self.progress.loaded += 1;
+            } else {
+                self.progress.failed += 1;
+                self.failures.push(name);
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Get the progress of the loading
+    pub fn get_progress(&self) -> LoadProgress {
+        self.progress
+    }
+
+    /// Get the names of the assets which failed to load
+    pub fn get_failures(&self) -> &[String] {
+        self.failures.as_slice()
+    }
+
+    /// Take a loaded image
+    ///
+    /// # Arguments
+    /// * name - The name given to load_image
+    ///
+    /// Return Some(Image) or None if it isn't loaded (yet)
+    pub fn take_image(&mut self, name: &str) -> Option<Image> {
+        self.images.remove(name)
+    }
+
+    /// Take a loaded texture
+    ///
+    /// # Arguments
+    /// * name - The name given to load_texture
+    ///
+    /// Return Some(Texture) or None if it isn't loaded (yet)
+    pub fn take_texture(&mut self, name: &str) -> Option<Texture> {
+        self.textures.remove(name)
+    }
+
+    /// Take a loaded font
+    ///
+    /// # Arguments
+    /// * name - The name given to load_font
+    ///
+    /// Return Some(Font) or None if it isn't loaded (yet)
+    pub fn take_font(&mut self, name: &str) -> Option<Font> {
+        self.fonts.remove(name)
+    }
+
+    /// Take a loaded sound buffer
+    ///
+    /// # Arguments
+    /// * name - The name given to load_sound_buffer
+    ///
+    /// Return Some(SoundBuffer) or None if it isn't loaded (yet)
+    pub fn take_sound_buffer(&mut self, name: &str) -> Option<SoundBuffer> {
+        self.sound_buffers.remove(name)
+    }
+
+    /// Run a decoding function on a worker thread
+    fn spawn<F>(&mut self, name: &str, filename: &str, decode: F) -> ()
+        where F: Fn(&str) -> Payload + Send {
+        let sender = self.sender.clone();
+        let name = name.to_string();
+        let filename = filename.to_string();
+        self.progress.total += 1;
+        self.pool.execute(move |:| {
+            let payload = decode(filename.as_slice());
+            // The loader may have been dropped in the meantime
+            let _ = sender.send((name, payload));
+        });
+    }
+}
+
+/// Decode an image file to RGBA pixels
+fn decode_image(filename: &str) -> Option<(uint, uint, Vec<u8>)> {
+    Image::new_from_file(filename).map(|image| {
+        let size = image.get_size();
+        (size.x as uint, size.y as uint, image.get_pixels().to_vec())
+    })
+}
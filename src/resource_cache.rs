@@ -0,0 +1,202 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Cache of shared resources
+//!
+//! ResourceCache loads each file once and hands out shared handles to
+//! it, which is what the rc versions of the drawables and sounds take.
+//!
+//! ```ignore
+//! let mut textures = TextureCache::new();
+//! let player = textures.load("resources/player.png").unwrap();
+//! // Same texture, no second loading
+//! let again = textures.load("resources/player.png").unwrap();
+//! ```
+
+use std::collections::HashMap;
+use std::rc::{mod, Rc};
+use std::cell::RefCell;
+
+use graphics::{Texture, Font, Image};
+use audio::SoundBuffer;
+
+/// Resources which can be loaded from a file
+pub trait Resource {
+    /// Load the resource from a file
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to load
+    ///
+    /// Return Some(resource) or None
+    fn load_from_file(filename: &str) -> Option<Self>;
+}
+
+impl Resource for Texture {
+    fn load_from_file(filename: &str) -> Option<Texture> {
+        Texture::new_from_file(filename)
+    }
+}
+
+impl Resource for Image {
+    fn load_from_file(filename: &str) -> Option<Image> {
+        Image::new_from_file(filename)
+    }
+}
+
+impl Resource for Font {
+    fn load_from_file(filename: &str) -> Option<Font> {
+        Font::new_from_file(filename)
+    }
+}
+
+impl Resource for SoundBuffer {
+    fn load_from_file(filename: &str) -> Option<SoundBuffer> {
+        SoundBuffer::new(filename)
+    }
+}
+
+/// Cache of textures
+pub type TextureCache = ResourceCache<Texture>;
+/// Cache of images
+pub type ImageCache = ResourceCache<Image>;
+/// Cache of fonts
+pub type FontCache = ResourceCache<Font>;
+/// Cache of sound buffers
+pub type SoundBufferCache = ResourceCache<SoundBuffer>;
+
+/// Load resources once and share them
+pub struct ResourceCache<T> {
+    #[doc(hidden)]
+    resources: HashMap<String, Rc<RefCell<T>>>
+}
+
+impl<T: Resource> ResourceCache<T> {
+    /// Create a new empty ResourceCache
+    ///
+    /// Return a new ResourceCache
+    pub fn new() -> ResourceCache<T> {
+        ResourceCache {
+            resources: HashMap::new()
+        }
+    }
+
+    /// Get a resource, loading it the first time
+    ///
+    /// The path of the file is used as the key of the resource.
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to load
+    ///
+    /// Return Some(resource) or None if the loading failed
+    pub fn load(&mut self, filename: &str) -> Option<Rc<RefCell<T>>> {
+        self.load_with_key(filename, filename)
+    }
+
+    /// Get a resource by key, loading it the first time
+    ///
+    /// # Arguments
+    /// * key - The key of the resource
+    /// * filename - Path of the file to load if the key is unknown
+    ///
+    /// Return Some(resource) or None if the loading failed
+    pub fn load_with_key(&mut self, key: &str, filename: &str) -> Option<Rc<RefCell<T>>> {
+        match self.resources.get(key) {
+            Some(resource) => return Some(resource.clone()),
+            None           => ()
+        }
+        let resource: Option<T> = Resource::load_from_file(filename);
+        resource.map(|resource| self.insert(key, resource))
+    }
+
+    /// Add a resource created by other means
+    ///
+    /// A resource already using the key is replaced, existing handles
+    /// to it stay valid.
+    ///
+    /// # Arguments
+    /// * key - The key of the resource
+    /// * resource - The resource to add
+    ///
+    /// Return the shared handle to the resource
+    pub fn insert(&mut self, key: &str, resource: T) -> Rc<RefCell<T>> {
+        let resource = Rc::new(RefCell::new(resource));
+        self.resources.insert(key.to_string(), resource.clone());
+        resource
+    }
+
+    /// Get a resource already loaded
+    ///
+    /// # Arguments
+    /// * key - The key of the resource
+    ///
+    /// Return Some(resource) or None if the key is unknown
+    pub fn get(&self, key: &str) -> Option<Rc<RefCell<T>>> {
+        self.resources.get(key).map(|resource| resource.clone())
+    }
+
+    /// Tell whether a resource is loaded
+    ///
+    /// # Arguments
+    /// * key - The key of the resource
+    pub fn contains(&self, key: &str) -> bool {
+        self.resources.contains_key(key)
+    }
+
+    /// Remove a resource from the cache
+    ///
+    /// The resource is destroyed once the last handle to it is dropped.
+    ///
+    /// # Arguments
+    /// * key - The key of the resource
+    ///
+    /// Return true if the resource was in the cache
+    pub fn unload(&mut self, key: &str) -> bool {
+        self.resources.remove(key).is_some()
+    }
+
+    /// Remove the resources which are only held by the cache
+    ///
+    /// Return the number of resources removed
+    pub fn unload_unused(&mut self) -> uint {
+        let unused: Vec<String> = self.resources.iter()
+            .filter(|&(_, resource)| rc::strong_count(resource) == 1)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in unused.iter() {
+            self.resources.remove(key);
+        }
+        unused.len()
+    }
+
+    /// Remove every resource from the cache
+    pub fn clear(&mut self) -> () {
+        self.resources.clear()
+    }
+
+    /// Get the number of resources in the cache
+    pub fn len(&self) -> uint {
+        self.resources.len()
+    }
+}
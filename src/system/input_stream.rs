@@ -0,0 +1,206 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Abstract source of data for the resource loaders
+//!
+//! InputStream allows Texture, Image, Font, SoundBuffer and Music to be
+//! loaded from any source of data, like a file inside an archive, an
+//! encrypted pack or a network connection.
+
+use libc::c_void;
+use std::mem;
+use std::io::{Reader, Seek, SeekSet, SeekEnd, EndOfFile};
+use core::raw;
+
+use ffi::system::input_stream as ffi;
+
+/// Abstract source of data for the resource loaders
+///
+/// Every type implementing both Reader and Seek is an InputStream.
+pub trait InputStream {
+    /// Read data from the stream
+    ///
+    /// # Arguments
+    /// * data - Buffer where to copy the read data
+    ///
+    /// Return the number of bytes actually read, or -1 on error
+    fn read(&mut self, data: &mut [u8]) -> i64;
+
+    /// Change the current reading position
+    ///
+    /// # Arguments
+    /// * position - The position to seek to, from the beginning
+    ///
+    /// Return the position actually sought to, or -1 on error
+    fn seek(&mut self, position: i64) -> i64;
+
+    /// Get the current reading position in the stream
+    ///
+    /// Return the current position, or -1 on error
+    fn tell(&mut self) -> i64;
+
+    /// Return the size of the stream
+    ///
+    /// Return the total number of bytes available in the stream, or -1 on error
+    fn get_size(&mut self) -> i64;
+}
+
+impl<T: Reader + Seek> InputStream for T {
+    fn read(&mut self, data: &mut [u8]) -> i64 {
+        let mut total = 0u;
+        while total < data.len() {
+            match Reader::read(self, data.slice_from_mut(total)) {
+                Ok(count)                              => total += count,
+                Err(ref e) if e.kind == EndOfFile      => break,
+                Err(_)                                 => return -1
+            }
+        }
+        total as i64
+    }
+
+    fn seek(&mut self, position: i64) -> i64 {
+        match Seek::seek(self, position, SeekSet) {
+            Ok(_)  => position,
+            Err(_) => -1
+        }
+    }
+
+    fn tell(&mut self) -> i64 {
+        match Seek::tell(self) {
+            Ok(position) => position as i64,
+            Err(_)       => -1
+        }
+    }
+
+    fn get_size(&mut self) -> i64 {
+        let position = match Seek::tell(self) {
+            Ok(position) => position as i64,
+            Err(_)       => return -1
+        };
+        let size = match Seek::seek(self, 0, SeekEnd) {
+            Ok(_)  => InputStream::tell(self),
+            Err(_) => -1
+        };
+        match Seek::seek(self, position, SeekSet) {
+            Ok(_)  => size,
+            Err(_) => -1
+        }
+    }
+}
+
+// Bridge between an InputStream and the sfInputStream callbacks.
+// It must stay at the same address while CSFML uses it, so it is
+// always boxed.
+#[doc(hidden)]
+pub struct InputStreamBridge<'a> {
+    raw: ffi::sfInputStream,
+    stream: &'a mut (InputStream + 'a)
+}
+
+impl<'a> InputStreamBridge<'a> {
+    #[doc(hidden)]
+    pub fn new(stream: &'a mut (InputStream + 'a)) -> Box<InputStreamBridge<'a>> {
+        let mut bridge = Box::new(InputStreamBridge {
+            raw: ffi::sfInputStream {
+                read: read,
+                seek: seek,
+                tell: tell,
+                getSize: get_size,
+                userData: 0 as *mut c_void
+            },
+            stream: stream
+        });
+        bridge.raw.userData = &mut *bridge as *mut InputStreamBridge as *mut c_void;
+        bridge
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap(&mut self) -> *mut ffi::sfInputStream {
+        &mut self.raw as *mut ffi::sfInputStream
+    }
+}
+
+// An InputStream owned by the resource reading it, for the resources
+// which keep reading their source after being loaded (Font and Music).
+#[doc(hidden)]
+pub struct OwnedInputStream {
+    bridge: Box<InputStreamBridge<'static>>,
+    stream: Box<InputStream + 'static>
+}
+
+impl OwnedInputStream {
+    #[doc(hidden)]
+    pub fn new(mut stream: Box<InputStream + 'static>) -> OwnedInputStream {
+        // The boxed stream never moves, so the bridge can safely borrow it
+        // for as long as both are kept together.
+        let stream_ref: &'static mut (InputStream + 'static) = unsafe {
+            mem::transmute(&mut *stream)
+        };
+        OwnedInputStream {
+            bridge: InputStreamBridge::new(stream_ref),
+            stream: stream
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap(&mut self) -> *mut ffi::sfInputStream {
+        self.bridge.unwrap()
+    }
+}
+
+#[doc(hidden)]
+extern fn read(data: *mut c_void, size: i64, user_data: *mut c_void) -> i64 {
+    unsafe {
+        let bridge: &mut InputStreamBridge = mem::transmute(user_data);
+        let buffer: &mut [u8] = mem::transmute(raw::Slice {
+            data: data as *const u8,
+            len: size as uint
+        });
+        bridge.stream.read(buffer)
+    }
+}
+
+#[doc(hidden)]
+extern fn seek(position: i64, user_data: *mut c_void) -> i64 {
+    unsafe {
+        let bridge: &mut InputStreamBridge = mem::transmute(user_data);
+        bridge.stream.seek(position)
+    }
+}
+
+#[doc(hidden)]
+extern fn tell(user_data: *mut c_void) -> i64 {
+    unsafe {
+        let bridge: &mut InputStreamBridge = mem::transmute(user_data);
+        bridge.stream.tell()
+    }
+}
+
+#[doc(hidden)]
+extern fn get_size(user_data: *mut c_void) -> i64 {
+    unsafe {
+        let bridge: &mut InputStreamBridge = mem::transmute(user_data);
+        bridge.stream.get_size()
+    }
+}
@@ -33,6 +33,7 @@ pub use system::vector3::Vector3f;
 pub use system::msleep::sleep;
 pub use system::time::Time;
 pub use system::clock::Clock;
+pub use system::input_stream::InputStream;
 
 #[doc(hidden)]
 #[cfg(any(target_os="macos", target_os="linux", target_os="windows"))]
@@ -45,5 +46,9 @@ pub mod vector2;
 pub mod vector3;
 mod time;
 mod clock;
+#[doc(hidden)]
+pub mod input_stream;
+#[doc(hidden)]
+pub mod utf32;
 #[path = "sleep.rs"]
 mod msleep;
@@ -0,0 +1,40 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! Null terminated UTF-32 strings, as expected by the CSFML unicode functions
+
+/// Convert a string to a null terminated UTF-32 string
+pub fn to_utf32(string: &str) -> Vec<u32> {
+    let mut utf32: Vec<u32> = string.chars().map(|c| c as u32).collect();
+    utf32.push(0);
+    utf32
+}
+
+/// Make sure a UTF-32 string is null terminated
+pub fn to_terminated(mut string: Vec<u32>) -> Vec<u32> {
+    if string.last() != Some(&0) {
+        string.push(0);
+    }
+    string
+}
@@ -27,7 +27,11 @@
 pub use traits::drawable::Drawable;
 pub use traits::wrappable::Wrappable;
 pub use traits::shape_impl::ShapeImpl;
+pub use traits::transformable::Transformable;
+pub use traits::sound_source::SoundSource;
 
 pub mod drawable;
 pub mod shape_impl;
+pub mod transformable;
+pub mod sound_source;
 pub mod wrappable;
@@ -0,0 +1,115 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+//! SoundSource trait
+//!
+//! Implemented by each playable audio source (sounds, musics and custom
+//! streams), so mixers and audio managers can control them uniformly.
+
+use audio::Status;
+use system::vector3::Vector3f;
+
+/// The trait SoundSource is inherited by each object which can be played
+/// in the audio scene.
+pub trait SoundSource {
+    /// Start or resume playing the source
+    fn play(&mut self) -> ();
+
+    /// Pause the source
+    fn pause(&mut self) -> ();
+
+    /// Stop playing the source and reset its playing position
+    fn stop(&mut self) -> ();
+
+    /// Get the current status of the source (stopped, paused, playing)
+    ///
+    /// Return current status
+    fn get_status(&self) -> Status;
+
+    /// Set the volume of the source
+    ///
+    /// # Arguments
+    /// * volume - Volume of the source, in the range [0, 100]
+    fn set_volume(&mut self, volume: f32) -> ();
+
+    /// Get the volume of the source
+    ///
+    /// Return the volume of the source, in the range [0, 100]
+    fn get_volume(&self) -> f32;
+
+    /// Set the pitch of the source
+    ///
+    /// # Arguments
+    /// * pitch - New pitch to apply to the source
+    fn set_pitch(&mut self, pitch: f32) -> ();
+
+    /// Get the pitch of the source
+    ///
+    /// Return the pitch of the source
+    fn get_pitch(&self) -> f32;
+
+    /// Set the 3D position of the source in the audio scene
+    ///
+    /// # Arguments
+    /// * position - Position of the source in the scene
+    fn set_position(&mut self, position: &Vector3f) -> ();
+
+    /// Get the 3D position of the source in the audio scene
+    ///
+    /// Return the position of the source in the world
+    fn get_position(&self) -> Vector3f;
+
+    /// Set the attenuation factor of the source
+    ///
+    /// # Arguments
+    /// * attenuation - New attenuation factor of the source
+    fn set_attenuation(&mut self, attenuation: f32) -> ();
+
+    /// Get the attenuation factor of the source
+    ///
+    /// Return the attenuation factor of the source
+    fn get_attenuation(&self) -> f32;
+
+    /// Set the minimum distance of the source
+    ///
+    /// # Arguments
+    /// * distance - New minimum distance of the source
+    fn set_min_distance(&mut self, distance: f32) -> ();
+
+    /// Get the minimum distance of the source
+    ///
+    /// Return the minimum distance of the source
+    fn get_min_distance(&self) -> f32;
+
+    /// Make the source's position relative to the listener or absolute
+    ///
+    /// # Arguments
+    /// * relative - true to set the position relative, false to set it absolute
+    fn set_relative_to_listener(&mut self, relative: bool) -> ();
+
+    /// Tell whether the source's position is relative to the listener or is absolute
+    ///
+    /// Return true if the position is relative, false if it's absolute
+    fn is_relative_to_listener(&self) -> bool;
+}
@@ -0,0 +1,122 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Transformable trait
+//!
+//! Implemented by each object which has a position, a rotation, a scale and
+//! an origin, so generic code can manipulate their transform uniformly.
+
+use graphics::Transform;
+use system::vector2::Vector2f;
+
+/// The trait transformable is inherited by each object which can be positioned,
+/// rotated and scaled.
+///
+/// Only the setters, the getters and get_transform are required, move_, rotate,
+/// scale and get_inverse_transform are provided on top of them.
+pub trait Transformable {
+    /// Set the position of the object
+    ///
+    /// # Arguments
+    /// * position - The new position
+    fn set_position(&mut self, position: &Vector2f) -> ();
+
+    /// Set the orientation of the object
+    ///
+    /// # Arguments
+    /// * angle - The new rotation, in degrees
+    fn set_rotation(&mut self, angle: f32) -> ();
+
+    /// Set the scale factors of the object
+    ///
+    /// # Arguments
+    /// * scale - The new scale factors
+    fn set_scale(&mut self, scale: &Vector2f) -> ();
+
+    /// Set the local origin of the object
+    ///
+    /// # Arguments
+    /// * origin - The new origin
+    fn set_origin(&mut self, origin: &Vector2f) -> ();
+
+    /// Get the position of the object
+    ///
+    /// Return the current position
+    fn get_position(&self) -> Vector2f;
+
+    /// Get the orientation of the object
+    ///
+    /// Return the current rotation, in degrees
+    fn get_rotation(&self) -> f32;
+
+    /// Get the current scale of the object
+    ///
+    /// Return the current scale factors
+    fn get_scale(&self) -> Vector2f;
+
+    /// Get the local origin of the object
+    ///
+    /// Return the current origin
+    fn get_origin(&self) -> Vector2f;
+
+    /// Get the combined transform of the object
+    ///
+    /// Return the transform combining the position/rotation/scale/origin
+    fn get_transform(&self) -> Transform;
+
+    /// Move the object by a given offset
+    ///
+    /// # Arguments
+    /// * offset - Offset
+    fn move_(&mut self, offset: &Vector2f) -> () {
+        let position = self.get_position() + *offset;
+        self.set_position(&position)
+    }
+
+    /// Rotate the object
+    ///
+    /// # Arguments
+    /// * angle - Angle of rotation, in degrees
+    fn rotate(&mut self, angle: f32) -> () {
+        let rotation = self.get_rotation() + angle;
+        self.set_rotation(rotation)
+    }
+
+    /// Scale the object
+    ///
+    /// # Arguments
+    /// * factors - Scale factors
+    fn scale(&mut self, factors: &Vector2f) -> () {
+        let scale = self.get_scale();
+        self.set_scale(&Vector2f::new(scale.x * factors.x, scale.y * factors.y))
+    }
+
+    /// Get the inverse of the combined transform of the object
+    ///
+    /// Return the inverse of the combined transformations applied to the object
+    fn get_inverse_transform(&self) -> Transform {
+        self.get_transform().get_inverse()
+    }
+}
@@ -0,0 +1,73 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+/*!
+* Clipboard.
+*
+* Give access to the system clipboard, to copy and paste text.
+*/
+
+use std::char;
+
+use ffi::window::clipboard as ffi;
+
+/**
+* Get the content of the clipboard as a string
+*
+* This function returns the content of the clipboard as a string.
+* If the clipboard does not contain string it returns an empty string.
+*
+* Return the clipboard contents
+*/
+pub fn get_string() -> String {
+    let mut string = String::new();
+    unsafe {
+        let mut ptr = ffi::sfClipboard_getUnicodeString();
+        if ptr.is_null() {
+            return string;
+        }
+        while *ptr != 0 {
+            string.push(char::from_u32(*ptr).unwrap_or('\u{FFFD}'));
+            ptr = ptr.offset(1);
+        }
+    }
+    string
+}
+
+/**
+* Set the content of the clipboard as a string
+*
+* This function sets the content of the clipboard as a string.
+*
+* # Arguments
+* * text - The text to put in the clipboard
+*/
+pub fn set_string(text: &str) -> () {
+    let mut unicode: Vec<u32> = text.chars().map(|c| c as u32).collect();
+    unicode.push(0);
+    unsafe {
+        ffi::sfClipboard_setUnicodeString(unicode.as_ptr())
+    }
+}
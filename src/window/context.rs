@@ -25,8 +25,14 @@
 //! Drawing context
 //!
 //! Class holding a valid drawing context.
+//!
+//! A context is needed to use the graphics resources (Texture,
+//! RenderTexture, Shader, ...) without opening a window, for example in
+//! command line tools rendering to files or in headless test environments.
+//! Create a Context and keep it alive as long as the resources are used.
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
+use window::ContextSettings;
 use ffi::window::context as ffi;
 
 /// Drawing context
@@ -45,8 +51,25 @@ impl Context {
     ///
     /// Return New Context object
     pub fn new() -> Context {
-        Context{
-            cont: unsafe { ffi::sfContext_create() }
+        match Context::new_opt() {
+            Some(context) => context,
+            None => panic!("Unable to create a Context")
+        }
+    }
+
+    /// Create a new context
+    ///
+    /// This function activates the new context.
+    ///
+    /// Return Some(Context) or None if the context can't be created
+    pub fn new_opt() -> Option<Context> {
+        let cont = unsafe { ffi::sfContext_create() };
+        if cont.is_null() {
+            None
+        } else {
+            Some(Context {
+                cont: cont
+            })
         }
     }
 
@@ -54,12 +77,42 @@ impl Context {
     ///
     /// # Arguments
     /// * active - True to activate, False to deactivate
-    pub fn set_active(&mut self, active: bool) -> () {
-        unsafe {
+    ///
+    /// Return true on success, false on failure
+    pub fn set_active(&mut self, active: bool) -> bool {
+        let res = unsafe {
             match active {
                 true    => ffi::sfContext_setActive(self.cont, SFTRUE),
                 false   => ffi::sfContext_setActive(self.cont, SFFALSE)
             }
+        };
+        match res {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
+    /// Get the settings of a context
+    ///
+    /// Note that these settings may be different than the ones passed
+    /// to the constructor; they are indeed adjusted if the original
+    /// settings are not directly supported by the system.
+    ///
+    /// Return the structure defining the settings
+    pub fn get_settings(&self) -> ContextSettings {
+        unsafe {
+            ffi::sfContext_getSettings(self.cont)
+        }
+    }
+
+    /// Get the id of the context currently active on this thread
+    ///
+    /// The id is unique for each context and is never reused.
+    ///
+    /// Return the id of the active context or 0 if no context is active
+    pub fn get_active_context_id() -> u64 {
+        unsafe {
+            ffi::sfContext_getActiveContextId()
         }
     }
 }
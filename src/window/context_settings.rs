@@ -26,6 +26,14 @@
 //!
 //! Structure defining the window's creation settings
 
+/// Non-debug, compatibility context (this and the core attribute are
+/// mutually exclusive)
+pub const DEFAULT_ATTRIBUTE: u32 = 0;
+/// Core attribute
+pub const CORE_ATTRIBUTE: u32 = 1 << 0;
+/// Debug attribute
+pub const DEBUG_ATTRIBUTE: u32 = 1 << 2;
+
 /// OpenGL context settings
 ///
 /// Structure defining the window's creation settings
@@ -41,7 +49,13 @@ pub struct ContextSettings {
     /// Major number of the context version
     pub major_version: u32,
     /// Minor number of the context version
-    pub minor_version: u32
+    pub minor_version: u32,
+    /// The attribute flags to create the context with (DEFAULT_ATTRIBUTE,
+    /// CORE_ATTRIBUTE and DEBUG_ATTRIBUTE, combined with |)
+    pub attribute_flags: u32,
+    /// Whether the context framebuffer is sRGB capable (1) or not (0),
+    /// see set_srgb_capable and is_srgb_capable
+    pub srgb_capable: u32
 }
 
 impl ContextSettings {
@@ -53,13 +67,70 @@ impl ContextSettings {
     /// * `antialiasing_level`: 0
     /// * `major_version`: 2
     /// * `minor_version`: 0
+    /// * `attribute_flags`: DEFAULT_ATTRIBUTE
+    /// * `srgb_capable`: 0
     pub fn default() -> ContextSettings {
         ContextSettings {
             depth_bits: 0,
             stencil_bits: 0,
             antialiasing_level: 0,
             major_version: 2,
-            minor_version: 0
+            minor_version: 0,
+            attribute_flags: DEFAULT_ATTRIBUTE,
+            srgb_capable: 0
         }
     }
+
+    /// Create a ContextSettings
+    ///
+    /// # Arguments
+    /// * depth_bits - Bits of the depth buffer
+    /// * stencil_bits - Bits of the stencil buffer
+    /// * antialiasing_level - Level of antialiasing
+    /// * major_version - Major number of the context version
+    /// * minor_version - Minor number of the context version
+    ///
+    /// Return a new ContextSettings with the default attribute flags and
+    /// no sRGB capable framebuffer
+    pub fn new(depth_bits: u32,
+               stencil_bits: u32,
+               antialiasing_level: u32,
+               major_version: u32,
+               minor_version: u32) -> ContextSettings {
+        ContextSettings {
+            depth_bits: depth_bits,
+            stencil_bits: stencil_bits,
+            antialiasing_level: antialiasing_level,
+            major_version: major_version,
+            minor_version: minor_version,
+            attribute_flags: DEFAULT_ATTRIBUTE,
+            srgb_capable: 0
+        }
+    }
+
+    /// Request an sRGB capable framebuffer or not
+    ///
+    /// # Arguments
+    /// * srgb_capable - true to request an sRGB capable framebuffer
+    pub fn set_srgb_capable(&mut self, srgb_capable: bool) -> () {
+        self.srgb_capable = if srgb_capable { 1 } else { 0 };
+    }
+
+    /// Tell whether the framebuffer is sRGB capable
+    ///
+    /// Return true if an sRGB capable framebuffer was requested, or
+    /// was obtained for the settings returned by a window or context
+    pub fn is_srgb_capable(&self) -> bool {
+        self.srgb_capable != 0
+    }
+
+    /// Tell whether the context is a core profile one
+    pub fn is_core(&self) -> bool {
+        self.attribute_flags & CORE_ATTRIBUTE != 0
+    }
+
+    /// Tell whether the context is a debug one
+    pub fn is_debug(&self) -> bool {
+        self.attribute_flags & DEBUG_ATTRIBUTE != 0
+    }
 }
@@ -0,0 +1,148 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Cursor defines the appearance of a system cursor
+//!
+//! Cursors are either one of the native cursors of the system (arrow,
+//! hand, text beam, ...) or custom ones made from RGBA pixels.
+//! Use Window::set_mouse_cursor to display them.
+
+use libc::c_uint;
+
+use traits::Wrappable;
+use system::vector2::Vector2u;
+
+use ffi::window::cursor as ffi;
+
+/// Enumeration of the native system cursor types
+///
+/// Not every type is available on every system, creating an
+/// unavailable one fails.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
+pub enum CursorType {
+    /// Arrow cursor (default)
+    Arrow,
+    /// Busy arrow cursor
+    ArrowWait,
+    /// Busy cursor
+    Wait,
+    /// I-beam, cursor when hovering over a field allowing text entry
+    Text,
+    /// Pointing hand cursor
+    Hand,
+    /// Horizontal double arrow cursor
+    SizeHorizontal,
+    /// Vertical double arrow cursor
+    SizeVertical,
+    /// Double arrow cursor going from top-left to bottom-right
+    SizeTopLeftBottomRight,
+    /// Double arrow cursor going from bottom-left to top-right
+    SizeBottomLeftTopRight,
+    /// Combination of SizeHorizontal and SizeVertical
+    SizeAll,
+    /// Crosshair cursor
+    Cross,
+    /// Help cursor
+    Help,
+    /// Action not allowed cursor
+    NotAllowed
+}
+
+/// Cursor defines the appearance of a system cursor
+pub struct Cursor {
+    #[doc(hidden)]
+    cursor: *mut ffi::sfCursor
+}
+
+impl Cursor {
+    /// Create a cursor with the provided image
+    ///
+    /// pixels must be an array of width by height pixels in 32-bit
+    /// RGBA format. The hotspot is the pixel coordinate within the
+    /// cursor image which will be located exactly where the mouse
+    /// pointer position is.
+    ///
+    /// # Arguments
+    /// * pixels - Array of pixels of the image
+    /// * size - Width and height of the image
+    /// * hotspot - (x,y) location of the hotspot
+    ///
+    /// Return Some(Cursor) or None
+    pub fn new_from_pixels(pixels: &[u8], size: &Vector2u, hotspot: &Vector2u) -> Option<Cursor> {
+        if pixels.len() < size.x as uint * size.y as uint * 4 {
+            return None;
+        }
+        let cursor = unsafe {
+            ffi::sfCursor_createFromPixels(pixels.as_ptr(), *size, *hotspot)
+        };
+        if cursor.is_null() {
+            None
+        } else {
+            Some(Cursor {
+                    cursor: cursor
+                })
+        }
+    }
+
+    /// Create a native system cursor
+    ///
+    /// # Arguments
+    /// * cursor_type - Native system cursor type
+    ///
+    /// Return Some(Cursor) or None if the type isn't available
+    pub fn new_from_system(cursor_type: CursorType) -> Option<Cursor> {
+        let cursor = unsafe {
+            ffi::sfCursor_createFromSystem(cursor_type as c_uint)
+        };
+        if cursor.is_null() {
+            None
+        } else {
+            Some(Cursor {
+                    cursor: cursor
+                })
+        }
+    }
+}
+
+impl Wrappable<*mut ffi::sfCursor> for Cursor {
+    fn wrap(cursor: *mut ffi::sfCursor) -> Cursor {
+        Cursor {
+            cursor: cursor
+        }
+    }
+
+    fn unwrap(&self) -> *mut ffi::sfCursor {
+        self.cursor
+    }
+}
+
+impl Drop for Cursor {
+    /// Destructor for class Cursor. Destroy all the ressource.
+    fn drop(&mut self) {
+        unsafe {
+            ffi::sfCursor_destroy(self.cursor)
+        }
+    }
+}
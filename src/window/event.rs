@@ -27,15 +27,19 @@
 //! Event holds all the informations about a system event that just happened.
 
 pub use self::Event::{Closed, Resized, LostFocus, GainedFocus, TextEntered,
-                     KeyPressed, KeyReleased, MouseWheelMoved,
+                     KeyPressed, KeyReleased, MouseWheelMoved, MouseWheelScrolled,
                      MouseButtonPressed, MouseButtonReleased, MouseMoved,
                      MouseEntered, MouseLeft, JoystickButtonPressed,
                      JoystickButtonReleased, JoystickMoved, JoystickConnected,
-                     JoystickDisconnected, NoEvent};
+                     JoystickDisconnected, TouchBegan, TouchMoved, TouchEnded,
+                     SensorChanged, ScaleFactorChanged, NoEvent};
 
-use window::keyboard::Key;
-use window::mouse::MouseButton;
+use window::keyboard::{Key, Scancode};
+use window::mouse::{MouseButton, MouseWheel};
 use window::joystick::Axis;
+use window::sensor::SensorType;
+use system::{Clock, Time};
+use std::rc::Rc;
 
 /// Definition of all the event types
 #[derive(Clone, PartialEq, PartialOrd, Show, Copy)]
@@ -55,13 +59,15 @@ pub enum Event {
     GainedFocus,
     /// A character was entered
     TextEntered {
-       /// The character entered by the user
+       /// The character entered by the user, any Unicode character
         code: char
     },
     /// A key was pressed
     KeyPressed {
        /// The pressed key
         code: Key,
+       /// The physical key, independent of the keyboard layout
+        scancode: Scancode,
        /// Is alt pressed too?
         alt: bool,
        /// Is ctrl pressed too?
@@ -75,6 +81,8 @@ pub enum Event {
     KeyReleased {
        /// The released key
         code: Key,
+       /// The physical key, independent of the keyboard layout
+        scancode: Scancode,
        /// Is alt released too?
         alt: bool,
        /// Is ctrl released too?
@@ -84,7 +92,10 @@ pub enum Event {
        /// Is system released too?
         system: bool
     },
-    /// The mouse wheel was scrolled
+    /// The vertical mouse wheel was scrolled
+    ///
+    /// Prefer MouseWheelScrolled which also reports the horizontal
+    /// wheel and fractional deltas.
     MouseWheelMoved {
        /// Number of ticks the wheel has moved (positive is up, negative is down)
         delta: i32,
@@ -93,6 +104,18 @@ pub enum Event {
        /// Y position of the mouse pointer, relative to the top of the owner window.
         y: i32
     },
+    /// A mouse wheel was scrolled
+    MouseWheelScrolled {
+       /// Which wheel was scrolled.
+        wheel: MouseWheel,
+       /// Wheel offset, positive is up or left, negative is down or right.
+       /// High precision wheels and trackpads report fractional offsets.
+        delta: f32,
+       /// X position of the mouse pointer, relative to the left of the owner window.
+        x: i32,
+       /// Y position of the mouse pointer, relative to the top of the owner window.
+        y: i32
+    },
     /// A mouse button was pressed
     MouseButtonPressed {
        /// Code of the button that has been pressed.
@@ -155,20 +178,109 @@ pub enum Event {
        /// Index of the joystick (in range [0 .. joystick::Count - 1])
         joystickid: u32
     },
+    /// A touch event began
+    TouchBegan {
+       /// Index of the finger in case of multi-touch events.
+        finger: u32,
+       /// X position of the touch, relative to the left of the owner window.
+        x: i32,
+       /// Y position of the touch, relative to the top of the owner window.
+        y: i32
+    },
+    /// A touch moved
+    TouchMoved {
+       /// Index of the finger in case of multi-touch events.
+        finger: u32,
+       /// X position of the touch, relative to the left of the owner window.
+        x: i32,
+       /// Y position of the touch, relative to the top of the owner window.
+        y: i32
+    },
+    /// A touch event ended
+    TouchEnded {
+       /// Index of the finger in case of multi-touch events.
+        finger: u32,
+       /// X position of the touch, relative to the left of the owner window.
+        x: i32,
+       /// Y position of the touch, relative to the top of the owner window.
+        y: i32
+    },
+    /// A sensor value changed
+    SensorChanged {
+       /// Type of the sensor.
+        sensor: SensorType,
+       /// Current value of the sensor on X axis.
+        x: f32,
+       /// Current value of the sensor on Y axis.
+        y: f32,
+       /// Current value of the sensor on Z axis.
+        z: f32
+    },
+    /// The scale factor of the window changed (it was moved to a monitor
+    /// with another DPI, or the desktop settings changed)
+    ScaleFactorChanged {
+       /// New scale factor, 1 for 96 DPI
+        factor: f32
+    },
     /// No Event
     NoEvent
 }
 
+/// An event with the time it was polled at
+///
+/// SFML doesn't timestamp the events, they are stamped when the
+/// application polls them, which is accurate when the events are polled
+/// at the start of every frame.
+pub struct TimedEvent {
+    /// The event
+    pub event: Event,
+    /// The time the event was polled at, since the window was created
+    pub time: Time
+}
+
+/// An iterator adding the poll time to events (see Window::timed_events)
+pub struct TimedEvents<I> {
+    #[doc(hidden)]
+    events: I,
+    #[doc(hidden)]
+    clock: Rc<Clock>
+}
+
+impl<I: Iterator<Item = Event>> TimedEvents<I> {
+    #[doc(hidden)]
+    pub fn new(events: I, clock: Rc<Clock>) -> TimedEvents<I> {
+        TimedEvents {
+            events: events,
+            clock: clock
+        }
+    }
+}
+
+impl<I: Iterator<Item = Event>> Iterator for TimedEvents<I> {
+    type Item = TimedEvent;
+
+    fn next(&mut self) -> Option<TimedEvent> {
+        self.events.next().map(|event| TimedEvent {
+            event: event,
+            time: self.clock.get_elapsed_time()
+        })
+    }
+}
+
 #[doc(hidden)]
 #[allow(non_upper_case_globals)]
 pub mod raw {
 
     use ffi::sfml_types::SfBool;
+    use window::mouse::MouseWheel;
 
     pub type sfKeyCode = ::libc::c_int;
+    pub type sfScancode = ::libc::c_int;
 
     pub type sfMouseButton = ::libc::c_uint;
+    pub type sfMouseWheel = ::libc::c_uint;
     pub type sfJoystickAxis = ::libc::c_uint;
+    pub type sfSensorType = ::libc::c_uint;
 
     pub type sfEventType = ::libc::c_uint;
     pub const sfEvtClosed: ::libc::c_uint = 0;
@@ -179,22 +291,28 @@ pub mod raw {
     pub const sfEvtKeyPressed: ::libc::c_uint = 5;
     pub const sfEvtKeyReleased: ::libc::c_uint = 6;
     pub const sfEvtMouseWheelMoved: ::libc::c_uint = 7;
-    pub const sfEvtMouseButtonPressed: ::libc::c_uint = 8;
-    pub const sfEvtMouseButtonReleased: ::libc::c_uint = 9;
-    pub const sfEvtMouseMoved: ::libc::c_uint = 10;
-    pub const sfEvtMouseEntered: ::libc::c_uint = 11;
-    pub const sfEvtMouseLeft: ::libc::c_uint = 12;
-    pub const sfEvtJoystickButtonPressed: ::libc::c_uint = 13;
-    pub const sfEvtJoystickButtonReleased: ::libc::c_uint = 14;
-    pub const sfEvtJoystickMoved: ::libc::c_uint = 15;
-    pub const sfEvtJoystickConnected: ::libc::c_uint = 16;
-    pub const sfEvtJoystickDisconnected: ::libc::c_uint = 17;
+    pub const sfEvtMouseWheelScrolled: ::libc::c_uint = 8;
+    pub const sfEvtMouseButtonPressed: ::libc::c_uint = 9;
+    pub const sfEvtMouseButtonReleased: ::libc::c_uint = 10;
+    pub const sfEvtMouseMoved: ::libc::c_uint = 11;
+    pub const sfEvtMouseEntered: ::libc::c_uint = 12;
+    pub const sfEvtMouseLeft: ::libc::c_uint = 13;
+    pub const sfEvtJoystickButtonPressed: ::libc::c_uint = 14;
+    pub const sfEvtJoystickButtonReleased: ::libc::c_uint = 15;
+    pub const sfEvtJoystickMoved: ::libc::c_uint = 16;
+    pub const sfEvtJoystickConnected: ::libc::c_uint = 17;
+    pub const sfEvtJoystickDisconnected: ::libc::c_uint = 18;
+    pub const sfEvtTouchBegan: ::libc::c_uint = 19;
+    pub const sfEvtTouchMoved: ::libc::c_uint = 20;
+    pub const sfEvtTouchEnded: ::libc::c_uint = 21;
+    pub const sfEvtSensorChanged: ::libc::c_uint = 22;
 
     #[repr(C)]
     #[derive(Copy)]
     pub struct sfKeyEvent {
         pub _type: sfEventType,
         pub code: sfKeyCode,
+        pub scancode: sfScancode,
         pub alt: SfBool,
         pub control: SfBool,
         pub shift: SfBool,
@@ -234,6 +352,16 @@ pub mod raw {
         pub y: ::libc::c_int,
     }
 
+    #[repr(C)]
+    #[derive(Copy)]
+    pub struct sfMouseWheelScrollEvent {
+        pub _type: sfEventType,
+        pub wheel: sfMouseWheel,
+        pub delta: ::libc::c_float,
+        pub x: ::libc::c_int,
+        pub y: ::libc::c_int,
+    }
+
     #[repr(C)]
     #[derive(Copy)]
     pub struct sfJoystickMoveEvent {
@@ -258,6 +386,25 @@ pub mod raw {
         pub joystickid: ::libc::c_uint,
     }
 
+    #[repr(C)]
+    #[derive(Copy)]
+    pub struct sfTouchEvent {
+        pub _type: sfEventType,
+        pub finger: ::libc::c_uint,
+        pub x: ::libc::c_int,
+        pub y: ::libc::c_int,
+    }
+
+    #[repr(C)]
+    #[derive(Copy)]
+    pub struct sfSensorEvent {
+        pub _type: sfEventType,
+        pub sensorType: sfSensorType,
+        pub x: ::libc::c_float,
+        pub y: ::libc::c_float,
+        pub z: ::libc::c_float,
+    }
+
     #[repr(C)]
     #[derive(Copy)]
     pub struct sfSizeEvent {
@@ -269,7 +416,7 @@ pub mod raw {
     #[repr(C)]
     #[derive(Copy)]
     pub struct sfEvent {
-        pub data: [u32; 6u],
+        pub data: [u32; 7u],
     }
 
     impl sfEvent {
@@ -285,6 +432,7 @@ pub mod raw {
         pub fn key(&mut self, _type: sfEventType) -> super::Event {
             let e: *mut sfKeyEvent = unsafe { ::std::mem::transmute(self) };
             let code = unsafe { ::std::mem::transmute((*e).code as i64) };
+            let scancode = unsafe { ::std::mem::transmute((*e).scancode as i64) };
             let alt = unsafe { (*e).alt.to_bool() };
             let ctrl = unsafe { (*e).control.to_bool() };
             let shift = unsafe { (*e).shift.to_bool() };
@@ -293,6 +441,7 @@ pub mod raw {
                 sfEvtKeyPressed => {
                     super::KeyPressed {
                         code: code,
+                        scancode: scancode,
                         alt: alt,
                         ctrl: ctrl,
                         shift: shift,
@@ -302,6 +451,7 @@ pub mod raw {
                 sfEvtKeyReleased => {
                     super::KeyReleased {
                         code: code,
+                        scancode: scancode,
                         alt: alt,
                         ctrl: ctrl,
                         shift: shift,
@@ -314,7 +464,9 @@ pub mod raw {
 
         pub fn text(&mut self) -> super::Event {
             let e: *mut sfTextEvent = unsafe { ::std::mem::transmute(self) };
-            unsafe { super::TextEntered { code: ((*e).unicode as u8) as char } }
+            // The character is a UTF-32 unit, invalid ones become U+FFFD
+            let code = ::std::char::from_u32(unsafe { (*e).unicode }).unwrap_or('\u{FFFD}');
+            super::TextEntered { code: code }
         }
 
         pub fn mouse_move(&mut self) -> super::Event {
@@ -340,6 +492,22 @@ pub mod raw {
             unsafe { super::MouseWheelMoved { delta: (*e).delta, x: (*e).x, y: (*e).y } }
         }
 
+        pub fn mouse_wheel_scroll(&mut self) -> super::Event {
+            let e: *mut sfMouseWheelScrollEvent = unsafe { ::std::mem::transmute(self) };
+            let wheel = match unsafe { (*e).wheel } {
+                1 => MouseWheel::HorizontalWheel,
+                _ => MouseWheel::VerticalWheel
+            };
+            unsafe {
+                super::MouseWheelScrolled {
+                    wheel: wheel,
+                    delta: (*e).delta,
+                    x: (*e).x,
+                    y: (*e).y
+                }
+            }
+        }
+
         pub fn joystick_move(&mut self) -> super::Event {
             let e: *mut sfJoystickMoveEvent = unsafe { ::std::mem::transmute(self) };
             super::JoystickMoved {
@@ -373,6 +541,26 @@ pub mod raw {
                 _ => unreachable!()
             }
         }
+
+        pub fn touch(&mut self, _type: sfEventType) -> super::Event {
+            let e: *mut sfTouchEvent = unsafe { ::std::mem::transmute(self) };
+            let finger = unsafe { (*e).finger };
+            let x = unsafe { (*e).x };
+            let y = unsafe { (*e).y };
+
+            match _type {
+                sfEvtTouchBegan => super::TouchBegan { finger: finger, x: x, y: y },
+                sfEvtTouchMoved => super::TouchMoved { finger: finger, x: x, y: y },
+                sfEvtTouchEnded => super::TouchEnded { finger: finger, x: x, y: y },
+                _ => unreachable!()
+            }
+        }
+
+        pub fn sensor(&mut self) -> super::Event {
+            let e: *mut sfSensorEvent = unsafe { ::std::mem::transmute(self) };
+            let sensor = unsafe { ::std::mem::transmute((*e).sensorType as u8) };
+            unsafe { super::SensorChanged { sensor: sensor, x: (*e).x, y: (*e).y, z: (*e).z } }
+        }
     }
 
     pub fn get_wrapped_event(event: &mut sfEvent) -> super::Event {
@@ -387,16 +575,21 @@ pub mod raw {
             sfEvtKeyPressed => event.key(_type),
             sfEvtKeyReleased => event.key(_type),
             sfEvtMouseWheelMoved => event.mouse_wheel(),
+            sfEvtMouseWheelScrolled => event.mouse_wheel_scroll(),
             sfEvtMouseButtonPressed => event.mouse_button(_type),
             sfEvtMouseButtonReleased => event.mouse_button(_type),
             sfEvtMouseMoved => event.mouse_move(),
-            sfEvtMouseEntered => super::MouseLeft,
-            sfEvtMouseLeft => super::MouseEntered,
+            sfEvtMouseEntered => super::MouseEntered,
+            sfEvtMouseLeft => super::MouseLeft,
             sfEvtJoystickButtonPressed => event.joystick_button(_type),
             sfEvtJoystickButtonReleased => event.joystick_button(_type),
             sfEvtJoystickMoved => event.joystick_move(),
             sfEvtJoystickConnected => event.joystick_connect(_type),
             sfEvtJoystickDisconnected => event.joystick_connect(_type),
+            sfEvtTouchBegan => event.touch(_type),
+            sfEvtTouchMoved => event.touch(_type),
+            sfEvtTouchEnded => event.touch(_type),
+            sfEvtSensorChanged => event.sensor(),
             _ => super::NoEvent
         }
     }
@@ -0,0 +1,174 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Queue of events which can be inspected without consuming them
+//!
+//! EventQueue stores the events polled from a window so that separate
+//! systems (user interface, gameplay, ...) can each peek at them, take
+//! the ones they handle and leave the others.
+//!
+//! ```ignore
+//! let mut queue = EventQueue::new();
+//! queue.fill(window.events());
+//! let clicks = queue.take_if(|e| match *e {
+//!     event::MouseButtonPressed { .. } => ui.contains_mouse(),
+//!     _                                => false
+//! });
+//! for event in queue.drain() {
+//!     // gameplay
+//! }
+//! ```
+
+use std::collections::RingBuf;
+use std::collections::ring_buf;
+
+use window::event::Event;
+
+/// Queue of events which can be inspected without consuming them
+#[derive(Clone, Show)]
+pub struct EventQueue {
+    #[doc(hidden)]
+    events: RingBuf<Event>
+}
+
+impl EventQueue {
+    /// Create a new empty EventQueue
+    ///
+    /// Return a new EventQueue
+    pub fn new() -> EventQueue {
+        EventQueue {
+            events: RingBuf::new()
+        }
+    }
+
+    /// Add the events of an iterator at the end of the queue
+    ///
+    /// Usually called with window.events() once per frame.
+    ///
+    /// # Arguments
+    /// * events - The events to add
+    pub fn fill<I: Iterator<Item = Event>>(&mut self, events: I) -> () {
+        self.events.extend(events)
+    }
+
+    /// Add an event at the end of the queue
+    ///
+    /// # Arguments
+    /// * event - The event to add
+    pub fn push(&mut self, event: Event) -> () {
+        self.events.push_back(event)
+    }
+
+    /// Get the next event without removing it
+    ///
+    /// Return Some(event) or None if the queue is empty
+    pub fn peek(&self) -> Option<&Event> {
+        self.events.front()
+    }
+
+    /// Remove the next event and return it
+    ///
+    /// Return Some(event) or None if the queue is empty
+    pub fn pop(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    /// Iterate over the events without removing them
+    pub fn iter(&self) -> ring_buf::Iter<Event> {
+        self.events.iter()
+    }
+
+    /// Get the events matching a predicate without removing them
+    ///
+    /// # Arguments
+    /// * predicate - Return true for the events to get
+    ///
+    /// Return the matching events, in order
+    pub fn filter<P: FnMut(&Event) -> bool>(&self, mut predicate: P) -> Vec<&Event> {
+        self.events.iter().filter(|event| predicate(*event)).collect()
+    }
+
+    /// Tell whether an event matches a predicate
+    ///
+    /// # Arguments
+    /// * predicate - Return true for the events to look for
+    pub fn any<P: FnMut(&Event) -> bool>(&self, mut predicate: P) -> bool {
+        self.events.iter().any(|event| predicate(event))
+    }
+
+    /// Remove the events matching a predicate and return them
+    ///
+    /// The other events stay in the queue, in order.
+    ///
+    /// # Arguments
+    /// * predicate - Return true for the events to take
+    ///
+    /// Return the matching events, in order
+    pub fn take_if<P: FnMut(&Event) -> bool>(&mut self, mut predicate: P) -> Vec<Event> {
+        let mut taken = Vec::new();
+        let mut kept = RingBuf::with_capacity(self.events.len());
+        loop {
+            match self.events.pop_front() {
+                Some(event) => if predicate(&event) {
+                    taken.push(event)
+                } else {
+                    kept.push_back(event)
+                },
+                None        => break
+            }
+        }
+        self.events = kept;
+        taken
+    }
+
+    /// Remove every event and return them
+    ///
+    /// Return the events, in order
+    pub fn drain(&mut self) -> Vec<Event> {
+        let mut events = Vec::with_capacity(self.events.len());
+        loop {
+            match self.events.pop_front() {
+                Some(event) => events.push(event),
+                None        => break
+            }
+        }
+        events
+    }
+
+    /// Remove every event
+    pub fn clear(&mut self) -> () {
+        self.events.clear()
+    }
+
+    /// Get the number of events in the queue
+    pub fn len(&self) -> uint {
+        self.events.len()
+    }
+
+    /// Tell whether the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
@@ -0,0 +1,385 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Record the events of a window and play them back
+//!
+//! EventRecorder writes down every event with the index of the frame it
+//! was polled in. EventPlayer reads a recording and gives the events
+//! back frame by frame, so a session can be replayed to reproduce a bug
+//! or drive automated tests of the user interface.
+//!
+//! ```ignore
+//! let mut recorder = EventRecorder::new();
+//! while window.is_open() {
+//!     for event in window.events() {
+//!         recorder.record(&event);
+//!         handle(event);
+//!     }
+//!     recorder.next_frame();
+//!     // ...
+//! }
+//! recorder.save_to_file("session.events");
+//!
+//! let mut player = EventPlayer::new_from_file("session.events").unwrap();
+//! while !player.is_finished() {
+//!     for event in player.next_frame().into_iter() {
+//!         handle(event);
+//!     }
+//!     // ...
+//! }
+//! ```
+//!
+//! The replay is only deterministic if the application itself is: use a
+//! fixed time step and seeded random numbers while recording and playing.
+
+use std::io::File;
+use std::mem;
+use std::vec::Vec;
+
+use window::event;
+use window::event::Event;
+use window::keyboard::{Key, Scancode};
+use window::mouse::MouseWheel;
+
+/// First line of a recording, followed by one event per line
+static HEADER: &'static str = "rsfml-events 1";
+
+/// Record the events of a window
+#[derive(Clone, Show)]
+pub struct EventRecorder {
+    #[doc(hidden)]
+    frame: uint,
+    #[doc(hidden)]
+    events: Vec<(uint, Event)>
+}
+
+impl EventRecorder {
+    /// Create a new EventRecorder, starting at frame 0
+    pub fn new() -> EventRecorder {
+        EventRecorder {
+            frame: 0,
+            events: Vec::new()
+        }
+    }
+
+    /// Record an event in the current frame
+    ///
+    /// # Arguments
+    /// * event - The event, NoEvent is ignored
+    pub fn record(&mut self, event: &Event) -> () {
+        match *event {
+            event::NoEvent => (),
+            _              => self.events.push((self.frame, *event))
+        }
+    }
+
+    /// Go to the next frame
+    ///
+    /// Call this once per frame, after polling the events.
+    pub fn next_frame(&mut self) -> () {
+        self.frame += 1
+    }
+
+    /// Get the index of the current frame
+    pub fn get_frame(&self) -> uint {
+        self.frame
+    }
+
+    /// Get the number of events recorded
+    pub fn get_event_count(&self) -> uint {
+        self.events.len()
+    }
+
+    /// Forget all the recorded events and start again at frame 0
+    pub fn clear(&mut self) -> () {
+        self.frame = 0;
+        self.events.clear()
+    }
+
+    /// Save the recording in a string
+    ///
+    /// Return the recording, to be read back with EventPlayer::new_from_string
+    pub fn save_to_string(&self) -> String {
+        let mut recording = String::from_str(HEADER);
+        recording.push('\n');
+        for &(frame, ref event) in self.events.iter() {
+            recording.push_str(format!("{} {}\n", frame, encode(event)).as_slice());
+        }
+        // The frame count, so trailing frames without events are replayed
+        recording.push_str(format!("{} End\n", self.frame).as_slice());
+        recording
+    }
+
+    /// Save the recording in a file
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to write
+    ///
+    /// Return true if the file was written
+    pub fn save_to_file(&self, filename: &str) -> bool {
+        File::create(&Path::new(filename)).write_str(self.save_to_string().as_slice()).is_ok()
+    }
+}
+
+/// Play back recorded events
+#[derive(Clone, Show)]
+pub struct EventPlayer {
+    #[doc(hidden)]
+    frame: uint,
+    #[doc(hidden)]
+    frame_count: uint,
+    #[doc(hidden)]
+    position: uint,
+    #[doc(hidden)]
+    events: Vec<(uint, Event)>
+}
+
+impl EventPlayer {
+    /// Read a recording saved with EventRecorder::save_to_string
+    ///
+    /// # Arguments
+    /// * recording - The recording
+    ///
+    /// Return Some(EventPlayer) or None if the recording is malformed
+    pub fn new_from_string(recording: &str) -> Option<EventPlayer> {
+        let mut lines = recording.lines();
+        if lines.next().map(|line| line.trim()) != Some(HEADER) {
+            return None;
+        }
+        let mut events = Vec::new();
+        let mut frame_count = 0;
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.words().collect();
+            let frame = match fields[0].parse::<uint>() {
+                Some(frame) => frame,
+                None        => return None
+            };
+            if fields.len() == 2 && fields[1] == "End" {
+                frame_count = frame;
+                continue;
+            }
+            match decode(fields.slice_from(1)) {
+                Some(event) => events.push((frame, event)),
+                None        => return None
+            }
+            if frame >= frame_count {
+                frame_count = frame + 1;
+            }
+        }
+        Some(EventPlayer {
+            frame: 0,
+            frame_count: frame_count,
+            position: 0,
+            events: events
+        })
+    }
+
+    /// Read a recording saved with EventRecorder::save_to_file
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to load
+    ///
+    /// Return Some(EventPlayer) or None if the file can't be read or is malformed
+    pub fn new_from_file(filename: &str) -> Option<EventPlayer> {
+        match File::open(&Path::new(filename)).read_to_string() {
+            Ok(recording) => EventPlayer::new_from_string(recording.as_slice()),
+            Err(_)        => None
+        }
+    }
+
+    /// Get the events of the current frame and go to the next one
+    ///
+    /// Return the events recorded in the frame, in their original order
+    pub fn next_frame(&mut self) -> Vec<Event> {
+        let mut events = Vec::new();
+        while self.position < self.events.len() {
+            let (frame, event) = self.events[self.position];
+            if frame > self.frame {
+                break;
+            }
+            events.push(event);
+            self.position += 1;
+        }
+        self.frame += 1;
+        events
+    }
+
+    /// Get the index of the frame next_frame will return
+    pub fn get_frame(&self) -> uint {
+        self.frame
+    }
+
+    /// Get the number of frames of the recording
+    pub fn get_frame_count(&self) -> uint {
+        self.frame_count
+    }
+
+    /// Tell whether all the frames were played
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.frame_count
+    }
+
+    /// Play the recording again from the start
+    pub fn reset(&mut self) -> () {
+        self.frame = 0;
+        self.position = 0
+    }
+}
+
+/// Write an event as its name followed by its fields
+fn encode(event: &Event) -> String {
+    let flag = |&: b: bool| if b { 1u } else { 0 };
+    match *event {
+        event::Closed => String::from_str("Closed"),
+        event::Resized { width, height } => format!("Resized {} {}", width, height),
+        event::LostFocus => String::from_str("LostFocus"),
+        event::GainedFocus => String::from_str("GainedFocus"),
+        event::TextEntered { code } => format!("TextEntered {}", code as u32),
+        event::KeyPressed { code, scancode, alt, ctrl, shift, system } =>
+            format!("KeyPressed {} {} {} {} {} {}", code as i64, scancode as i64,
+                    flag(alt), flag(ctrl), flag(shift), flag(system)),
+        event::KeyReleased { code, scancode, alt, ctrl, shift, system } =>
+            format!("KeyReleased {} {} {} {} {} {}", code as i64, scancode as i64,
+                    flag(alt), flag(ctrl), flag(shift), flag(system)),
+        event::MouseWheelMoved { delta, x, y } => format!("MouseWheelMoved {} {} {}", delta, x, y),
+        event::MouseWheelScrolled { wheel, delta, x, y } =>
+            format!("MouseWheelScrolled {} {} {} {}", wheel as uint, delta, x, y),
+        event::MouseButtonPressed { button, x, y } =>
+            format!("MouseButtonPressed {} {} {}", button as uint, x, y),
+        event::MouseButtonReleased { button, x, y } =>
+            format!("MouseButtonReleased {} {} {}", button as uint, x, y),
+        event::MouseMoved { x, y } => format!("MouseMoved {} {}", x, y),
+        event::MouseEntered => String::from_str("MouseEntered"),
+        event::MouseLeft => String::from_str("MouseLeft"),
+        event::JoystickButtonPressed { joystickid, button } =>
+            format!("JoystickButtonPressed {} {}", joystickid, button),
+        event::JoystickButtonReleased { joystickid, button } =>
+            format!("JoystickButtonReleased {} {}", joystickid, button),
+        event::JoystickMoved { joystickid, axis, position } =>
+            format!("JoystickMoved {} {} {}", joystickid, axis as uint, position),
+        event::JoystickConnected { joystickid } => format!("JoystickConnected {}", joystickid),
+        event::JoystickDisconnected { joystickid } => format!("JoystickDisconnected {}", joystickid),
+        event::TouchBegan { finger, x, y } => format!("TouchBegan {} {} {}", finger, x, y),
+        event::TouchMoved { finger, x, y } => format!("TouchMoved {} {} {}", finger, x, y),
+        event::TouchEnded { finger, x, y } => format!("TouchEnded {} {} {}", finger, x, y),
+        event::SensorChanged { sensor, x, y, z } =>
+            format!("SensorChanged {} {} {} {}", sensor as uint, x, y, z),
+        event::ScaleFactorChanged { factor } => format!("ScaleFactorChanged {}", factor),
+        event::NoEvent => String::from_str("NoEvent")
+    }
+}
+
+/// Read an event written by encode
+fn decode(fields: &[&str]) -> Option<Event> {
+    if fields.is_empty() {
+        return None;
+    }
+    let args = fields.slice_from(1);
+    // Parse the nth field, leaving the function if it's missing or invalid
+    macro_rules! arg {
+        ($n:expr) => (
+            match args.get($n).and_then(|field| field.parse()) {
+                Some(value) => value,
+                None        => return None
+            }
+        )
+    }
+    // Parse the nth field as a fieldless enum whose variants go up to max
+    macro_rules! enum_arg {
+        ($n:expr, $max:expr) => ({
+            let index: uint = arg!($n);
+            if index > $max {
+                return None;
+            }
+            unsafe { mem::transmute(index as u8) }
+        })
+    }
+    let event = match (fields[0], args.len()) {
+        ("Closed", 0) => event::Closed,
+        ("Resized", 2) => event::Resized { width: arg!(0), height: arg!(1) },
+        ("LostFocus", 0) => event::LostFocus,
+        ("GainedFocus", 0) => event::GainedFocus,
+        ("TextEntered", 1) => match ::std::char::from_u32(arg!(0)) {
+            Some(code) => event::TextEntered { code: code },
+            None       => return None
+        },
+        ("KeyPressed", 6) | ("KeyReleased", 6) => {
+            let code: i64 = arg!(0);
+            let scancode: i64 = arg!(1);
+            if code < -1 || code >= Key::KeyCount as i64 ||
+               scancode < -1 || scancode >= Scancode::ScancodeCount as i64 {
+                return None;
+            }
+            let code = unsafe { mem::transmute(code) };
+            let scancode = unsafe { mem::transmute(scancode) };
+            let (alt, ctrl, shift, system) = (arg!(2) != 0u, arg!(3) != 0u,
+                                              arg!(4) != 0u, arg!(5) != 0u);
+            if fields[0] == "KeyPressed" {
+                event::KeyPressed { code: code, scancode: scancode, alt: alt,
+                                    ctrl: ctrl, shift: shift, system: system }
+            } else {
+                event::KeyReleased { code: code, scancode: scancode, alt: alt,
+                                     ctrl: ctrl, shift: shift, system: system }
+            }
+        },
+        ("MouseWheelMoved", 3) => event::MouseWheelMoved { delta: arg!(0), x: arg!(1), y: arg!(2) },
+        ("MouseWheelScrolled", 4) => {
+            let wheel = match arg!(0) {
+                0u => MouseWheel::VerticalWheel,
+                1  => MouseWheel::HorizontalWheel,
+                _  => return None
+            };
+            event::MouseWheelScrolled { wheel: wheel, delta: arg!(1), x: arg!(2), y: arg!(3) }
+        },
+        ("MouseButtonPressed", 3) =>
+            event::MouseButtonPressed { button: enum_arg!(0, 4), x: arg!(1), y: arg!(2) },
+        ("MouseButtonReleased", 3) =>
+            event::MouseButtonReleased { button: enum_arg!(0, 4), x: arg!(1), y: arg!(2) },
+        ("MouseMoved", 2) => event::MouseMoved { x: arg!(0), y: arg!(1) },
+        ("MouseEntered", 0) => event::MouseEntered,
+        ("MouseLeft", 0) => event::MouseLeft,
+        ("JoystickButtonPressed", 2) =>
+            event::JoystickButtonPressed { joystickid: arg!(0), button: arg!(1) },
+        ("JoystickButtonReleased", 2) =>
+            event::JoystickButtonReleased { joystickid: arg!(0), button: arg!(1) },
+        ("JoystickMoved", 3) =>
+            event::JoystickMoved { joystickid: arg!(0), axis: enum_arg!(1, 7), position: arg!(2) },
+        ("JoystickConnected", 1) => event::JoystickConnected { joystickid: arg!(0) },
+        ("JoystickDisconnected", 1) => event::JoystickDisconnected { joystickid: arg!(0) },
+        ("TouchBegan", 3) => event::TouchBegan { finger: arg!(0), x: arg!(1), y: arg!(2) },
+        ("TouchMoved", 3) => event::TouchMoved { finger: arg!(0), x: arg!(1), y: arg!(2) },
+        ("TouchEnded", 3) => event::TouchEnded { finger: arg!(0), x: arg!(1), y: arg!(2) },
+        ("SensorChanged", 4) =>
+            event::SensorChanged { sensor: enum_arg!(0, 5), x: arg!(1), y: arg!(2), z: arg!(3) },
+        ("ScaleFactorChanged", 1) => event::ScaleFactorChanged { factor: arg!(0) },
+        ("NoEvent", 0) => event::NoEvent,
+        _ => return None
+    };
+    Some(event)
+}
@@ -0,0 +1,517 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Logical gamepad buttons and axes on top of joysticks
+//!
+//! Joysticks report raw button indices and axes which differ from one
+//! device to another. A GamepadMapping translates them to the usual
+//! layout of a gamepad (A/B/X/Y, sticks, triggers, d-pad). Mappings can
+//! be written by hand or read from an SDL game controller database
+//! (gamecontrollerdb.txt), and Gamepads picks the mapping of each
+//! connected joystick from its vendor and product ids.
+//!
+//! SDL numbers the axes of a device in the order of their Linux codes,
+//! they are translated to X, Y, Z, U, V, R which is how SFML names them
+//! on Linux. The hat is read from PovX and PovY, up being negative PovY.
+
+use std::num::Float;
+
+use window::joystick;
+use window::joystick::Axis;
+
+/// Logical buttons of a gamepad
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
+pub enum GamepadButton {
+    /// The bottom face button (A on Xbox pads, cross on PlayStation pads).
+    A,
+    /// The right face button.
+    B,
+    /// The left face button.
+    X,
+    /// The top face button.
+    Y,
+    /// The left shoulder button.
+    LeftShoulder,
+    /// The right shoulder button.
+    RightShoulder,
+    /// The back (select, share) button.
+    Back,
+    /// The start (options) button.
+    Start,
+    /// The guide (home) button.
+    Guide,
+    /// Pressing the left stick.
+    LeftStick,
+    /// Pressing the right stick.
+    RightStick,
+    /// Up on the d-pad.
+    DPadUp,
+    /// Down on the d-pad.
+    DPadDown,
+    /// Left on the d-pad.
+    DPadLeft,
+    /// Right on the d-pad.
+    DPadRight
+}
+
+/// Logical axes of a gamepad
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
+pub enum GamepadAxis {
+    /// Horizontal position of the left stick, in range [-1 .. 1].
+    LeftX,
+    /// Vertical position of the left stick, in range [-1 .. 1], down is positive.
+    LeftY,
+    /// Horizontal position of the right stick, in range [-1 .. 1].
+    RightX,
+    /// Vertical position of the right stick, in range [-1 .. 1], down is positive.
+    RightY,
+    /// Position of the left trigger, in range [0 .. 1].
+    LeftTrigger,
+    /// Position of the right trigger, in range [0 .. 1].
+    RightTrigger
+}
+
+/// A raw joystick input
+#[derive(Clone, PartialEq, Show, Copy)]
+pub enum InputSource {
+    /// A joystick button, by index.
+    JoystickButton(uint),
+    /// A whole joystick axis, inverted if the boolean is true.
+    JoystickAxis(Axis, bool),
+    /// Half of a joystick axis, the positive one if the boolean is true.
+    JoystickHalfAxis(Axis, bool)
+}
+
+impl InputSource {
+    /// Get the value of the input on a joystick
+    ///
+    /// Return the value, in range [-1 .. 1] for whole axes and
+    /// [0 .. 1] for buttons and half axes
+    fn get_value(&self, joystick: uint) -> f32 {
+        match *self {
+            InputSource::JoystickButton(button) =>
+                if joystick::is_button_pressed(joystick, button) { 1. } else { 0. },
+            InputSource::JoystickAxis(axis, inverted) => {
+                let value = joystick::get_axis_position(joystick, axis) / 100.;
+                if inverted { -value } else { value }
+            },
+            InputSource::JoystickHalfAxis(axis, positive) => {
+                let value = joystick::get_axis_position(joystick, axis) / 100.;
+                (if positive { value } else { -value }).max(0.)
+            }
+        }
+    }
+}
+
+/// Translation of the raw inputs of a device to logical ones
+#[derive(Clone, PartialEq, Show)]
+pub struct GamepadMapping {
+    #[doc(hidden)]
+    name: String,
+    #[doc(hidden)]
+    vendor_id: uint,
+    #[doc(hidden)]
+    product_id: uint,
+    #[doc(hidden)]
+    buttons: Vec<(GamepadButton, InputSource)>,
+    #[doc(hidden)]
+    axes: Vec<(GamepadAxis, InputSource)>
+}
+
+impl GamepadMapping {
+    /// Create a new mapping without any input
+    ///
+    /// # Arguments
+    /// * name - Name of the device
+    /// * vendor_id - Manufacturer identifier of the device
+    /// * product_id - Product identifier of the device
+    ///
+    /// Return a new GamepadMapping
+    pub fn new(name: &str, vendor_id: uint, product_id: uint) -> GamepadMapping {
+        GamepadMapping {
+            name: name.to_string(),
+            vendor_id: vendor_id,
+            product_id: product_id,
+            buttons: Vec::new(),
+            axes: Vec::new()
+        }
+    }
+
+    /// Create the mapping of an Xbox 360 pad as seen on Linux
+    ///
+    /// This is used for the devices without a mapping of their own,
+    /// most pads follow this layout or a close one.
+    ///
+    /// Return a new GamepadMapping
+    pub fn new_default() -> GamepadMapping {
+        let mut mapping = GamepadMapping::new("Default gamepad", 0, 0);
+        let buttons = [GamepadButton::A, GamepadButton::B, GamepadButton::X,
+                       GamepadButton::Y, GamepadButton::LeftShoulder,
+                       GamepadButton::RightShoulder, GamepadButton::Back,
+                       GamepadButton::Start, GamepadButton::Guide,
+                       GamepadButton::LeftStick, GamepadButton::RightStick];
+        for (index, &button) in buttons.iter().enumerate() {
+            mapping.set_button(button, InputSource::JoystickButton(index));
+        }
+        mapping.set_button(GamepadButton::DPadUp, InputSource::JoystickHalfAxis(Axis::PovY, false));
+        mapping.set_button(GamepadButton::DPadDown, InputSource::JoystickHalfAxis(Axis::PovY, true));
+        mapping.set_button(GamepadButton::DPadLeft, InputSource::JoystickHalfAxis(Axis::PovX, false));
+        mapping.set_button(GamepadButton::DPadRight, InputSource::JoystickHalfAxis(Axis::PovX, true));
+        mapping.set_axis(GamepadAxis::LeftX, InputSource::JoystickAxis(Axis::X, false));
+        mapping.set_axis(GamepadAxis::LeftY, InputSource::JoystickAxis(Axis::Y, false));
+        mapping.set_axis(GamepadAxis::RightX, InputSource::JoystickAxis(Axis::U, false));
+        mapping.set_axis(GamepadAxis::RightY, InputSource::JoystickAxis(Axis::V, false));
+        mapping.set_axis(GamepadAxis::LeftTrigger, InputSource::JoystickAxis(Axis::Z, false));
+        mapping.set_axis(GamepadAxis::RightTrigger, InputSource::JoystickAxis(Axis::R, false));
+        mapping
+    }
+
+    /// Create a mapping from a line of an SDL game controller database
+    ///
+    /// The line looks like "GUID,name,a:b0,b:b1,...,leftx:a0,...".
+    /// Unknown fields are ignored.
+    ///
+    /// # Arguments
+    /// * line - The mapping line
+    ///
+    /// Return Some(GamepadMapping) or None if the line isn't a mapping
+    pub fn new_from_sdl(line: &str) -> Option<GamepadMapping> {
+        let mut fields = line.trim().split(',');
+        let guid = match fields.next() {
+            Some(guid) if guid.len() == 32 => guid,
+            _                              => return None
+        };
+        let name = match fields.next() {
+            Some(name) => name,
+            None       => return None
+        };
+        let (vendor_id, product_id) = match (parse_guid_u16(guid, 8), parse_guid_u16(guid, 16)) {
+            (Some(vendor), Some(product)) => (vendor, product),
+            _                             => return None
+        };
+        let mut mapping = GamepadMapping::new(name, vendor_id, product_id);
+        for field in fields {
+            let mut parts = field.splitn(1, ':');
+            let (key, value) = match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => (key, value),
+                _                        => continue
+            };
+            let source = match parse_sdl_source(value) {
+                Some(source) => source,
+                None         => continue
+            };
+            match sdl_button(key) {
+                Some(button) => { mapping.set_button(button, source); continue },
+                None         => ()
+            }
+            match sdl_axis(key) {
+                Some(axis) => mapping.set_axis(axis, source),
+                None       => ()
+            }
+        }
+        Some(mapping)
+    }
+
+    /// Get the name of the device
+    pub fn get_name(&self) -> &str {
+        self.name.as_slice()
+    }
+
+    /// Get the manufacturer identifier of the device
+    pub fn get_vendor_id(&self) -> uint {
+        self.vendor_id
+    }
+
+    /// Get the product identifier of the device
+    pub fn get_product_id(&self) -> uint {
+        self.product_id
+    }
+
+    /// Set the raw input of a logical button
+    ///
+    /// # Arguments
+    /// * button - The logical button
+    /// * source - The raw input
+    pub fn set_button(&mut self, button: GamepadButton, source: InputSource) -> () {
+        self.buttons.retain(|&(b, _)| b != button);
+        self.buttons.push((button, source))
+    }
+
+    /// Get the raw input of a logical button
+    ///
+    /// Return Some(source) or None if the button isn't mapped
+    pub fn get_button(&self, button: GamepadButton) -> Option<InputSource> {
+        self.buttons.iter().find(|&&(b, _)| b == button).map(|&(_, source)| source)
+    }
+
+    /// Set the raw input of a logical axis
+    ///
+    /// # Arguments
+    /// * axis - The logical axis
+    /// * source - The raw input
+    pub fn set_axis(&mut self, axis: GamepadAxis, source: InputSource) -> () {
+        self.axes.retain(|&(a, _)| a != axis);
+        self.axes.push((axis, source))
+    }
+
+    /// Get the raw input of a logical axis
+    ///
+    /// Return Some(source) or None if the axis isn't mapped
+    pub fn get_axis(&self, axis: GamepadAxis) -> Option<InputSource> {
+        self.axes.iter().find(|&&(a, _)| a == axis).map(|&(_, source)| source)
+    }
+
+    /// Check if a logical button is pressed on a joystick
+    ///
+    /// Axes mapped to a button count as pressed past half of their range.
+    ///
+    /// # Arguments
+    /// * joystick - Index of the joystick
+    /// * button - The logical button
+    ///
+    /// Return true if the button is pressed
+    pub fn is_button_pressed(&self, joystick: uint, button: GamepadButton) -> bool {
+        match self.get_button(button) {
+            Some(source) => source.get_value(joystick) > 0.5,
+            None         => false
+        }
+    }
+
+    /// Get the position of a logical axis on a joystick
+    ///
+    /// # Arguments
+    /// * joystick - Index of the joystick
+    /// * axis - The logical axis
+    ///
+    /// Return the position, in range [-1 .. 1] for sticks and [0 .. 1]
+    /// for triggers, 0 if the axis isn't mapped
+    pub fn get_axis_position(&self, joystick: uint, axis: GamepadAxis) -> f32 {
+        let source = match self.get_axis(axis) {
+            Some(source) => source,
+            None         => return 0.
+        };
+        let value = source.get_value(joystick);
+        match (axis, source) {
+            // Triggers mapped to a whole axis rest at its minimum
+            (GamepadAxis::LeftTrigger, InputSource::JoystickAxis(..)) |
+            (GamepadAxis::RightTrigger, InputSource::JoystickAxis(..)) => (value + 1.) / 2.,
+            _ => value
+        }
+    }
+}
+
+/// The mappings of the known devices
+#[derive(Clone, PartialEq, Show)]
+pub struct Gamepads {
+    #[doc(hidden)]
+    mappings: Vec<GamepadMapping>,
+    #[doc(hidden)]
+    default: GamepadMapping
+}
+
+impl Gamepads {
+    /// Create a new Gamepads without any known device
+    ///
+    /// Every joystick uses the default mapping until mappings are added.
+    ///
+    /// Return a new Gamepads
+    pub fn new() -> Gamepads {
+        Gamepads {
+            mappings: Vec::new(),
+            default: GamepadMapping::new_default()
+        }
+    }
+
+    /// Add the mappings of an SDL game controller database
+    ///
+    /// Empty lines and comments are skipped.
+    ///
+    /// # Arguments
+    /// * database - Content of the database
+    ///
+    /// Return the number of mappings added
+    pub fn add_sdl_mappings(&mut self, database: &str) -> uint {
+        let mut count = 0;
+        for line in database.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("#") {
+                continue;
+            }
+            match GamepadMapping::new_from_sdl(line) {
+                Some(mapping) => { self.add_mapping(mapping); count += 1 },
+                None          => ()
+            }
+        }
+        count
+    }
+
+    /// Add a mapping, replacing the one of the same device
+    ///
+    /// # Arguments
+    /// * mapping - The mapping to add
+    pub fn add_mapping(&mut self, mapping: GamepadMapping) -> () {
+        self.mappings.retain(|m| m.vendor_id != mapping.vendor_id ||
+                                 m.product_id != mapping.product_id);
+        self.mappings.push(mapping)
+    }
+
+    /// Set the mapping used for unknown devices
+    ///
+    /// # Arguments
+    /// * mapping - The default mapping
+    pub fn set_default_mapping(&mut self, mapping: GamepadMapping) -> () {
+        self.default = mapping
+    }
+
+    /// Get the mapping of a joystick
+    ///
+    /// # Arguments
+    /// * joystick - Index of the joystick
+    ///
+    /// Return the mapping of its device or the default mapping
+    pub fn get_mapping(&self, joystick: uint) -> &GamepadMapping {
+        let id = joystick::get_identification(joystick);
+        self.mappings.iter()
+            .find(|m| m.vendor_id == id.vendor_id && m.product_id == id.product_id)
+            .unwrap_or(&self.default)
+    }
+
+    /// Check if a logical button is pressed on a joystick
+    ///
+    /// # Arguments
+    /// * joystick - Index of the joystick
+    /// * button - The logical button
+    ///
+    /// Return true if the button is pressed
+    pub fn is_button_pressed(&self, joystick: uint, button: GamepadButton) -> bool {
+        self.get_mapping(joystick).is_button_pressed(joystick, button)
+    }
+
+    /// Get the position of a logical axis on a joystick
+    ///
+    /// # Arguments
+    /// * joystick - Index of the joystick
+    /// * axis - The logical axis
+    ///
+    /// Return the position, in range [-1 .. 1] for sticks and [0 .. 1]
+    /// for triggers
+    pub fn get_axis_position(&self, joystick: uint, axis: GamepadAxis) -> f32 {
+        self.get_mapping(joystick).get_axis_position(joystick, axis)
+    }
+}
+
+/// Read a little endian u16 stored as 4 hex digits in an SDL GUID
+fn parse_guid_u16(guid: &str, offset: uint) -> Option<uint> {
+    let byte = |&: i: uint| -> Option<uint> {
+        guid.slice(i, i + 2).chars().fold(Some(0u), |acc, c| {
+            match (acc, c.to_digit(16)) {
+                (Some(acc), Some(digit)) => Some(acc * 16 + digit),
+                _                        => None
+            }
+        })
+    };
+    match (byte(offset), byte(offset + 2)) {
+        (Some(low), Some(high)) => Some(low | (high << 8)),
+        _                       => None
+    }
+}
+
+/// Parse the value of an SDL mapping field, like "b0", "-a1", "a2~" or "h0.4"
+fn parse_sdl_source(value: &str) -> Option<InputSource> {
+    let (half, value) = if value.starts_with("+") {
+        (Some(true), value.slice_from(1))
+    } else if value.starts_with("-") {
+        (Some(false), value.slice_from(1))
+    } else {
+        (None, value)
+    };
+    let (inverted, value) = if value.ends_with("~") {
+        (true, value.slice_to(value.len() - 1))
+    } else {
+        (false, value)
+    };
+    if value.starts_with("b") {
+        value.slice_from(1).parse().map(|button| InputSource::JoystickButton(button))
+    } else if value.starts_with("a") {
+        let axis = match value.slice_from(1).parse::<uint>() {
+            Some(0) => Axis::X,
+            Some(1) => Axis::Y,
+            Some(2) => Axis::Z,
+            Some(3) => Axis::U,
+            Some(4) => Axis::V,
+            Some(5) => Axis::R,
+            _       => return None
+        };
+        match half {
+            Some(positive) => Some(InputSource::JoystickHalfAxis(axis, positive != inverted)),
+            None           => Some(InputSource::JoystickAxis(axis, inverted))
+        }
+    } else if value == "h0.1" {
+        Some(InputSource::JoystickHalfAxis(Axis::PovY, false))
+    } else if value == "h0.2" {
+        Some(InputSource::JoystickHalfAxis(Axis::PovX, true))
+    } else if value == "h0.4" {
+        Some(InputSource::JoystickHalfAxis(Axis::PovY, true))
+    } else if value == "h0.8" {
+        Some(InputSource::JoystickHalfAxis(Axis::PovX, false))
+    } else {
+        None
+    }
+}
+
+/// Get the logical button of an SDL mapping key
+fn sdl_button(key: &str) -> Option<GamepadButton> {
+    match key {
+        "a"             => Some(GamepadButton::A),
+        "b"             => Some(GamepadButton::B),
+        "x"             => Some(GamepadButton::X),
+        "y"             => Some(GamepadButton::Y),
+        "leftshoulder"  => Some(GamepadButton::LeftShoulder),
+        "rightshoulder" => Some(GamepadButton::RightShoulder),
+        "back"          => Some(GamepadButton::Back),
+        "start"         => Some(GamepadButton::Start),
+        "guide"         => Some(GamepadButton::Guide),
+        "leftstick"     => Some(GamepadButton::LeftStick),
+        "rightstick"    => Some(GamepadButton::RightStick),
+        "dpup"          => Some(GamepadButton::DPadUp),
+        "dpdown"        => Some(GamepadButton::DPadDown),
+        "dpleft"        => Some(GamepadButton::DPadLeft),
+        "dpright"       => Some(GamepadButton::DPadRight),
+        _               => None
+    }
+}
+
+/// Get the logical axis of an SDL mapping key
+fn sdl_axis(key: &str) -> Option<GamepadAxis> {
+    match key {
+        "leftx"        => Some(GamepadAxis::LeftX),
+        "lefty"        => Some(GamepadAxis::LeftY),
+        "rightx"       => Some(GamepadAxis::RightX),
+        "righty"       => Some(GamepadAxis::RightY),
+        "lefttrigger"  => Some(GamepadAxis::LeftTrigger),
+        "righttrigger" => Some(GamepadAxis::RightTrigger),
+        _              => None
+    }
+}
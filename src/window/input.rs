@@ -0,0 +1,359 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Bind named actions to inputs
+//!
+//! An ActionMap lets the game ask for "jump" or "move_x" instead of a
+//! given key, so the bindings can be changed by the players and saved
+//! with their settings. The inputs are read in real time, query the map
+//! once per frame after handling the events.
+//!
+//! A saved ActionMap is a text with one binding per line:
+//!
+//! ```ignore
+//! jump key 57 1
+//! jump joystick_button 0 0 1
+//! move_x key 71 -1
+//! move_x key 72 1
+//! move_x joystick_axis 0 0 0.2 1
+//! ```
+//!
+//! The fields are the action name, the kind of input, its identifiers
+//! (key code, scancode, mouse button, joystick and button or axis, dead
+//! zone of the axis) and the scale of the binding.
+
+use std::collections::HashMap;
+use std::io::File;
+use std::mem;
+use std::num::Float;
+use std::vec::Vec;
+
+use window::{keyboard, mouse, joystick};
+use window::keyboard::{Key, Scancode};
+use window::mouse::MouseButton;
+use window::joystick::Axis;
+
+/// An input an action can be bound to
+#[derive(Clone, PartialEq, Show, Copy)]
+pub enum Binding {
+    /// A key, by its code in the current keyboard layout
+    KeyBinding(Key),
+    /// A key, by its physical position
+    ScancodeBinding(Scancode),
+    /// A mouse button
+    MouseButtonBinding(MouseButton),
+    /// A button of a joystick: joystick index, button index
+    JoystickButtonBinding(uint, uint),
+    /// An axis of a joystick: joystick index, axis, dead zone from 0 to 1
+    ///
+    /// Positions closer to the center than the dead zone are ignored.
+    JoystickAxisBinding(uint, Axis, f32)
+}
+
+impl Binding {
+    /// Read the value of the input
+    ///
+    /// Return 1 for a pressed key or button, the position of the axis
+    /// from -1 to 1 for a joystick axis, 0 otherwise
+    pub fn get_value(&self) -> f32 {
+        let pressed = match *self {
+            Binding::KeyBinding(key)                          => keyboard::is_key_pressed(key),
+            Binding::ScancodeBinding(code)                    => keyboard::is_scancode_pressed(code),
+            Binding::MouseButtonBinding(button)               => mouse::is_button_pressed(button),
+            Binding::JoystickButtonBinding(joystick, button)  => joystick::is_button_pressed(joystick, button),
+            Binding::JoystickAxisBinding(joystick, axis, dead_zone) => {
+                let position = joystick::get_axis_position(joystick, axis) / 100.;
+                return if position.abs() < dead_zone { 0. } else { position };
+            }
+        };
+        if pressed { 1. } else { 0. }
+    }
+}
+
+/// Bind named actions to inputs
+pub struct ActionMap {
+    #[doc(hidden)]
+    actions: HashMap<String, Vec<(Binding, f32)>>
+}
+
+impl ActionMap {
+    /// Create an empty ActionMap
+    pub fn new() -> ActionMap {
+        ActionMap {
+            actions: HashMap::new()
+        }
+    }
+
+    /// Read an ActionMap saved with save_to_string
+    ///
+    /// # Arguments
+    /// * description - The saved bindings
+    ///
+    /// Return Some(ActionMap) or None if a line is malformed
+    pub fn new_from_string(description: &str) -> Option<ActionMap> {
+        let mut map = ActionMap::new();
+        for line in description.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("#") {
+                continue;
+            }
+            match parse_line(line) {
+                Some((action, binding, scale)) => map.bind_scaled(action, binding, scale),
+                None                           => return None
+            }
+        }
+        Some(map)
+    }
+
+    /// Read an ActionMap saved with save_to_file
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to load
+    ///
+    /// Return Some(ActionMap) or None if the file can't be read or is malformed
+    pub fn new_from_file(filename: &str) -> Option<ActionMap> {
+        match File::open(&Path::new(filename)).read_to_string() {
+            Ok(description) => ActionMap::new_from_string(description.as_slice()),
+            Err(_)          => None
+        }
+    }
+
+    /// Bind an action to an input
+    ///
+    /// An action can be bound to several inputs.
+    ///
+    /// # Arguments
+    /// * action - Name of the action, without whitespace
+    /// * binding - The input
+    pub fn bind(&mut self, action: &str, binding: Binding) -> () {
+        self.bind_scaled(action, binding, 1.)
+    }
+
+    /// Bind an action to an input, scaling its value
+    ///
+    /// A negative scale makes an input push the value of the action the
+    /// other way, to bind the left and right arrows to the same axis for
+    /// instance.
+    ///
+    /// # Arguments
+    /// * action - Name of the action, without whitespace
+    /// * binding - The input
+    /// * scale - Factor applied to the value of the input
+    pub fn bind_scaled(&mut self, action: &str, binding: Binding, scale: f32) -> () {
+        if !self.actions.contains_key(action) {
+            self.actions.insert(action.to_string(), Vec::new());
+        }
+        match self.actions.get_mut(action) {
+            Some(bindings) => bindings.push((binding, scale)),
+            None           => ()
+        }
+    }
+
+    /// Replace all the bindings of an action by a single input
+    ///
+    /// # Arguments
+    /// * action - Name of the action, without whitespace
+    /// * binding - The new input
+    pub fn rebind(&mut self, action: &str, binding: Binding) -> () {
+        self.clear(action);
+        self.bind(action, binding)
+    }
+
+    /// Remove a binding of an action
+    ///
+    /// # Arguments
+    /// * action - Name of the action
+    /// * binding - The input to unbind
+    pub fn unbind(&mut self, action: &str, binding: &Binding) -> () {
+        match self.actions.get_mut(action) {
+            Some(bindings) => bindings.retain(|&(ref b, _)| b != binding),
+            None           => ()
+        }
+    }
+
+    /// Remove all the bindings of an action
+    ///
+    /// # Arguments
+    /// * action - Name of the action
+    pub fn clear(&mut self, action: &str) -> () {
+        self.actions.remove(action);
+    }
+
+    /// Get the inputs an action is bound to
+    ///
+    /// # Arguments
+    /// * action - Name of the action
+    ///
+    /// Return the bindings of the action
+    pub fn get_bindings(&self, action: &str) -> Vec<Binding> {
+        match self.actions.get(action) {
+            Some(bindings) => bindings.iter().map(|&(binding, _)| binding).collect(),
+            None           => Vec::new()
+        }
+    }
+
+    /// Get the names of all the actions
+    pub fn get_actions(&self) -> Vec<String> {
+        self.actions.keys().map(|action| action.clone()).collect()
+    }
+
+    /// Tell whether one of the inputs of an action is active
+    ///
+    /// # Arguments
+    /// * action - Name of the action
+    ///
+    /// Return true if a key or button of the action is pressed or an axis
+    /// is out of its dead zone
+    pub fn is_active(&self, action: &str) -> bool {
+        match self.actions.get(action) {
+            Some(bindings) => bindings.iter().any(|&(binding, _)| binding.get_value() != 0.),
+            None           => false
+        }
+    }
+
+    /// Get the value of an action
+    ///
+    /// The values of its inputs, multiplied by their scale, are added.
+    ///
+    /// # Arguments
+    /// * action - Name of the action
+    ///
+    /// Return the value of the action, clamped between -1 and 1
+    pub fn get_value(&self, action: &str) -> f32 {
+        let value = match self.actions.get(action) {
+            Some(bindings) => bindings.iter().fold(0., |acc, &(binding, scale)| {
+                acc + binding.get_value() * scale
+            }),
+            None => 0.
+        };
+        value.max(-1.).min(1.)
+    }
+
+    /// Save the bindings in a string
+    ///
+    /// Return the bindings, to be read back with new_from_string
+    pub fn save_to_string(&self) -> String {
+        let mut actions = self.get_actions();
+        actions.sort();
+        let mut description = String::new();
+        for action in actions.iter() {
+            let bindings = match self.actions.get(action.as_slice()) {
+                Some(bindings) => bindings,
+                None           => continue
+            };
+            for &(binding, scale) in bindings.iter() {
+                let input = match binding {
+                    Binding::KeyBinding(key)                 => format!("key {}", key as i64),
+                    Binding::ScancodeBinding(code)           => format!("scancode {}", code as i64),
+                    Binding::MouseButtonBinding(button)      => format!("mouse_button {}", button as uint),
+                    Binding::JoystickButtonBinding(joystick, button) =>
+                        format!("joystick_button {} {}", joystick, button),
+                    Binding::JoystickAxisBinding(joystick, axis, dead_zone) =>
+                        format!("joystick_axis {} {} {}", joystick, axis as uint, dead_zone)
+                };
+                description.push_str(format!("{} {} {}\n", action, input, scale).as_slice());
+            }
+        }
+        description
+    }
+
+    /// Save the bindings in a file
+    ///
+    /// # Arguments
+    /// * filename - Path of the file to write
+    ///
+    /// Return true if the file was written
+    pub fn save_to_file(&self, filename: &str) -> bool {
+        File::create(&Path::new(filename)).write_str(self.save_to_string().as_slice()).is_ok()
+    }
+}
+
+/// Parse a line of a saved ActionMap
+fn parse_line(line: &str) -> Option<(&str, Binding, f32)> {
+    let fields: Vec<&str> = line.words().collect();
+    if fields.len() < 4 {
+        return None;
+    }
+    let action = fields[0];
+    let numbers = fields.slice(2, fields.len() - 1);
+    let scale = match fields[fields.len() - 1].parse::<f32>() {
+        Some(scale) => scale,
+        None        => return None
+    };
+    let binding = match (fields[1], numbers.len()) {
+        ("key", 1) => match numbers[0].parse::<i64>() {
+            Some(code) if code >= -1 && code < Key::KeyCount as i64 =>
+                Binding::KeyBinding(unsafe { mem::transmute(code) }),
+            _ => return None
+        },
+        ("scancode", 1) => match numbers[0].parse::<i64>() {
+            Some(code) if code >= -1 && code < Scancode::ScancodeCount as i64 =>
+                Binding::ScancodeBinding(unsafe { mem::transmute(code) }),
+            _ => return None
+        },
+        ("mouse_button", 1) => match numbers[0].parse::<uint>().and_then(mouse_button) {
+            Some(button) => Binding::MouseButtonBinding(button),
+            None         => return None
+        },
+        ("joystick_button", 2) => match (numbers[0].parse(), numbers[1].parse()) {
+            (Some(joystick), Some(button)) => Binding::JoystickButtonBinding(joystick, button),
+            _                              => return None
+        },
+        ("joystick_axis", 3) => match (numbers[0].parse(),
+                                       numbers[1].parse::<uint>().and_then(axis),
+                                       numbers[2].parse()) {
+            (Some(joystick), Some(axis), Some(dead_zone)) =>
+                Binding::JoystickAxisBinding(joystick, axis, dead_zone),
+            _ => return None
+        },
+        _ => return None
+    };
+    Some((action, binding, scale))
+}
+
+fn mouse_button(index: uint) -> Option<MouseButton> {
+    match index {
+        0 => Some(MouseButton::MouseLeft),
+        1 => Some(MouseButton::MouseRight),
+        2 => Some(MouseButton::MouseMiddle),
+        3 => Some(MouseButton::MouseXButton1),
+        4 => Some(MouseButton::MouseXButton2),
+        _ => None
+    }
+}
+
+fn axis(index: uint) -> Option<Axis> {
+    match index {
+        0 => Some(Axis::X),
+        1 => Some(Axis::Y),
+        2 => Some(Axis::Z),
+        3 => Some(Axis::R),
+        4 => Some(Axis::U),
+        5 => Some(Axis::V),
+        6 => Some(Axis::PovX),
+        7 => Some(Axis::PovY),
+        _ => None
+    }
+}
@@ -29,6 +29,7 @@
  */
 
 use libc::{c_uint};
+use std::ffi::c_str_to_bytes;
 
 use ffi::sfml_types::{SFFALSE, SFTRUE};
 use ffi::window::joystick as ffi;
@@ -61,6 +62,17 @@ pub enum Axis {
     PovY
 }
 
+/// Identification of a joystick
+#[derive(Clone, PartialEq, Eq, Show)]
+pub struct JoystickIdentification {
+    /// Name of the joystick
+    pub name: String,
+    /// Manufacturer identifier
+    pub vendor_id: uint,
+    /// Product identifier
+    pub product_id: uint
+}
+
 /**
  * Check if the joystick is connected
  *
@@ -150,6 +162,33 @@ pub fn get_axis_position(joystick: uint, axis: Axis) -> f32 {
     }
 }
 
+/**
+ * Get the identification of a joystick
+ *
+ * If the joystick is not connected, the name is empty and the
+ * identifiers are 0.
+ *
+ * # Arguments
+ * * joystick - Index of the joystick
+ *
+ * Return the name, vendor id and product id of the joystick
+ */
+pub fn get_identification(joystick: uint) -> JoystickIdentification {
+    unsafe {
+        let id = ffi::sfJoystick_getIdentification(joystick as c_uint);
+        let name = if id.name.is_null() {
+            String::new()
+        } else {
+            String::from_utf8_lossy(c_str_to_bytes(&id.name)).into_owned()
+        };
+        JoystickIdentification {
+            name: name,
+            vendor_id: id.vendorId as uint,
+            product_id: id.productId as uint
+        }
+    }
+}
+
 /**
  * Update the states of all joysticks
  *
@@ -25,7 +25,9 @@
 
 //! Keyboard inputs Give acces to real-time keyboard input.
 
-use libc::c_int;
+use libc::{c_int, c_void, free};
+use std::ffi::c_str_to_bytes;
+use std::mem;
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::window::keyboard as ffi;
@@ -140,6 +142,165 @@ pub enum Key {
     KeyCount
 }
 
+/// Scancodes
+///
+/// The scancode identifies a key by its physical position on the
+/// keyboard, regardless of the keyboard layout: Scancode::W is the key
+/// at the W position of a QWERTY keyboard, which is Z on an AZERTY one.
+#[repr(i64)]
+#[allow(missing_docs)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
+pub enum Scancode {
+    Unknown = -1,
+    A = 0,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
+    Num0,
+    Enter,
+    Escape,
+    Backspace,
+    Tab,
+    Space,
+    Hyphen,
+    Equal,
+    LBracket,
+    RBracket,
+    Backslash,
+    Semicolon,
+    Apostrophe,
+    Grave,
+    Comma,
+    Period,
+    Slash,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    CapsLock,
+    PrintScreen,
+    ScrollLock,
+    Pause,
+    Insert,
+    Home,
+    PageUp,
+    Delete,
+    End,
+    PageDown,
+    Right,
+    Left,
+    Down,
+    Up,
+    NumLock,
+    NumpadDivide,
+    NumpadMultiply,
+    NumpadMinus,
+    NumpadPlus,
+    NumpadEqual,
+    NumpadEnter,
+    NumpadDecimal,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    Numpad0,
+    NonUsBackslash,
+    Application,
+    Execute,
+    ModeChange,
+    Help,
+    Menu,
+    Select,
+    Redo,
+    Undo,
+    Cut,
+    Copy,
+    Paste,
+    VolumeMute,
+    VolumeUp,
+    VolumeDown,
+    MediaPlayPause,
+    MediaStop,
+    MediaNextTrack,
+    MediaPreviousTrack,
+    LControl,
+    LShift,
+    LAlt,
+    LSystem,
+    RControl,
+    RShift,
+    RAlt,
+    RSystem,
+    Back,
+    Forward,
+    Refresh,
+    Stop,
+    Search,
+    Favorites,
+    HomePage,
+    LaunchApplication1,
+    LaunchApplication2,
+    LaunchMail,
+    LaunchMediaSelect,
+    ScancodeCount
+}
+
 /**
  * Check if a key is pressed.
  *
@@ -156,3 +317,96 @@ pub fn is_key_pressed(key: Key) -> bool {
         }
     }
 }
+
+/**
+ * Show or hide the virtual keyboard.
+ *
+ * Warning: the virtual keyboard is not supported on all systems. It will
+ * typically be implemented on mobile OSes (Android, iOS) but not on
+ * desktop OSes (Windows, Linux, ...). If the virtual keyboard is not
+ * available, this function does nothing.
+ *
+ * # Arguments
+ * * visible - true to show, false to hide
+ */
+pub fn set_virtual_keyboard_visible(visible: bool) -> () {
+    unsafe {
+        match visible {
+            true  => ffi::sfKeyboard_setVirtualKeyboardVisible(SFTRUE),
+            false => ffi::sfKeyboard_setVirtualKeyboardVisible(SFFALSE)
+        }
+    }
+}
+
+/**
+ * Check if a key is pressed, by its scancode.
+ *
+ * # Arguments
+ * * code - The scancode to check
+ *
+ * Return true if the physical key is pressed, false otherwise.
+ */
+pub fn is_scancode_pressed(code: Scancode) -> bool {
+    unsafe {
+        match ffi::sfKeyboard_isScancodePressed(code as c_int) {
+            SFFALSE  => false,
+            SFTRUE   => true
+        }
+    }
+}
+
+/**
+ * Localize a physical key to a logical one.
+ *
+ * # Arguments
+ * * code - The scancode to localize
+ *
+ * Return the key corresponding to the scancode under the current
+ * keyboard layout, or Key::Unknown if the scancode cannot be mapped to a Key.
+ */
+pub fn localize(code: Scancode) -> Key {
+    unsafe {
+        mem::transmute(ffi::sfKeyboard_localize(code as c_int) as i64)
+    }
+}
+
+/**
+ * Identify the physical key corresponding to a logical one.
+ *
+ * # Arguments
+ * * key - The key to delocalize
+ *
+ * Return the scancode corresponding to the key under the current
+ * keyboard layout, or Scancode::Unknown if the key cannot be mapped to a Scancode.
+ */
+pub fn delocalize(key: Key) -> Scancode {
+    unsafe {
+        mem::transmute(ffi::sfKeyboard_delocalize(key as c_int) as i64)
+    }
+}
+
+/**
+ * Provide a string representation for a given scancode.
+ *
+ * The returned string is a short, non-technical description of the key
+ * represented with the given scancode, in the language of the
+ * keyboard layout, for example "Left Shift", "Enter" or "Q". It is meant
+ * to be displayed to the users, in keybinding menus for instance.
+ *
+ * # Arguments
+ * * code - The scancode to describe
+ *
+ * Return the description of the scancode.
+ */
+pub fn get_description(code: Scancode) -> String {
+    unsafe {
+        let description = ffi::sfKeyboard_getDescription(code as c_int);
+        if description.is_null() {
+            return String::new();
+        }
+        let string = String::from_utf8_lossy(c_str_to_bytes(&description)).into_owned();
+        // The string is allocated by CSFML for the caller
+        free(description as *mut c_void);
+        string
+    }
+}
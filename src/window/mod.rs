@@ -25,12 +25,21 @@
 //! Provides OpenGL-based windows, and abstractions for events and input handling.
 
 
-pub use window::window::Window;
-pub use window::video_mode::VideoMode;
+pub use window::window::{Window, Events};
+pub use window::video_mode::{VideoMode, FullscreenModes};
 pub use window::context::Context;
-pub use window::context_settings::ContextSettings;
+pub use window::context_settings::{ContextSettings, DEFAULT_ATTRIBUTE,
+                                   CORE_ATTRIBUTE, DEBUG_ATTRIBUTE};
+pub use window::event_queue::EventQueue;
+pub use window::event_recording::{EventRecorder, EventPlayer};
+pub use window::text_input::TextInput;
+pub use window::cursor::{Cursor, CursorType};
 pub use window::window_style::{WindowStyle, NoStyle, Titlebar,
-                               Resize, Close, Fullscreen, DefaultStyle};
+                               Resize, Close, Fullscreen, DefaultStyle,
+                               BorderlessFullscreen};
+pub use ffi::sfml_types::sfWindowHandle as WindowHandle;
+#[doc(hidden)]
+pub use window::scale_factor::ScaleWatcher;
 
 #[doc(hidden)]
 #[cfg(any(target_os="macos", target_os="linux", target_os="windows"))]
@@ -45,7 +54,17 @@ mod video_mode;
 mod context;
 mod context_settings;
 pub mod joystick;
+pub mod gamepad;
+pub mod input;
 pub mod keyboard;
 pub mod mouse;
+pub mod touch;
+pub mod sensor;
+pub mod clipboard;
 pub mod event;
 mod window_style;
+mod event_queue;
+mod event_recording;
+mod scale_factor;
+mod text_input;
+mod cursor;
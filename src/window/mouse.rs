@@ -29,9 +29,13 @@
 */
 
 use libc::c_uint;
+use std::ptr;
+
+use system::vector2::Vector2i;
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::window::mouse as ffi;
+use ffi::window::window::{sfMouse_getPosition, sfMouse_setPosition};
 
 /// Mouse buttons
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
@@ -48,6 +52,15 @@ pub enum MouseButton {
     MouseXButton2
 }
 
+/// Mouse wheels
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
+pub enum MouseWheel {
+    /// The vertical mouse wheel.
+    VerticalWheel,
+    /// The horizontal mouse wheel (tilt wheel or trackpad).
+    HorizontalWheel
+}
+
 /**
 * Check if a mouse button is pressed
 *
@@ -64,3 +77,32 @@ pub fn is_button_pressed(button: MouseButton) -> bool {
         }
     }
 }
+
+/**
+* Get the current position of the mouse in desktop coordinates
+*
+* Use Window::get_mouse_position or RenderWindow::get_mouse_position
+* to get the position relative to a window.
+*
+* Return the current position of the mouse, in desktop coordinates
+*/
+pub fn get_position() -> Vector2i {
+    unsafe {
+        sfMouse_getPosition(ptr::null_mut())
+    }
+}
+
+/**
+* Set the current position of the mouse in desktop coordinates
+*
+* Use Window::set_mouse_position or RenderWindow::set_mouse_position
+* to set the position relative to a window.
+*
+* # Arguments
+* * position - New position of the mouse, in desktop coordinates
+*/
+pub fn set_position(position: &Vector2i) -> () {
+    unsafe {
+        sfMouse_setPosition(*position, ptr::null_mut())
+    }
+}
@@ -0,0 +1,84 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Report the changes of the scale factor of a window as events
+//!
+//! SFML has no event for it, so the scale factor is read again each
+//! time the window is resized or gains the focus, which is when it is
+//! moved to another monitor or the desktop settings change.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use window::event;
+use window::event::Event;
+use window::WindowHandle;
+use ffi::native;
+
+/// Watch the scale factor of a window
+///
+/// It is shared by the window and its Events iterators.
+#[doc(hidden)]
+pub struct ScaleWatcher {
+    #[doc(hidden)]
+    factor: Cell<f32>,
+    #[doc(hidden)]
+    pending: Cell<Option<Event>>
+}
+
+impl ScaleWatcher {
+    /// Start watching the scale factor of a window
+    pub fn new(handle: WindowHandle) -> Rc<ScaleWatcher> {
+        Rc::new(ScaleWatcher {
+            factor: Cell::new(native::get_scale_factor(handle)),
+            pending: Cell::new(None)
+        })
+    }
+
+    /// Look at an event polled from the window
+    ///
+    /// If the scale factor changed, a ScaleFactorChanged event is kept
+    /// to be returned by take_pending.
+    pub fn watch(&self, handle: WindowHandle, event: Event) -> Event {
+        match event {
+            event::Resized { .. } | event::GainedFocus => {
+                let factor = native::get_scale_factor(handle);
+                if factor != self.factor.get() {
+                    self.factor.set(factor);
+                    self.pending.set(Some(event::ScaleFactorChanged { factor: factor }));
+                }
+            },
+            _ => ()
+        }
+        event
+    }
+
+    /// Take the ScaleFactorChanged event to return before polling again
+    pub fn take_pending(&self) -> Option<Event> {
+        let pending = self.pending.get();
+        self.pending.set(None);
+        pending
+    }
+}
@@ -0,0 +1,110 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+/*!
+* Sensors.
+*
+* Give access to the real-time state of the sensors (accelerometer,
+* gyroscope, ...) available on mobile and tablet devices.
+*
+* Sensors are disabled by default to save battery, enable the ones
+* you need with set_enabled. Their changes are also reported with
+* SensorChanged events.
+*/
+
+use libc::c_uint;
+
+use system::vector3::Vector3f;
+
+use ffi::sfml_types::{SFTRUE, SFFALSE};
+use ffi::window::sensor as ffi;
+
+/// Sensor types
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Show, Copy)]
+pub enum SensorType {
+    /// Measures the raw acceleration (m/s^2).
+    Accelerometer,
+    /// Measures the raw rotation rates (degrees/s).
+    Gyroscope,
+    /// Measures the ambient magnetic field (micro-teslas).
+    Magnetometer,
+    /// Measures the direction and intensity of gravity, independent of device acceleration (m/s^2).
+    Gravity,
+    /// Measures the direction and intensity of device acceleration, independent of the gravity (m/s^2).
+    UserAcceleration,
+    /// Measures the absolute 3D orientation (degrees).
+    Orientation
+}
+
+/**
+* Check if a sensor is available on the underlying platform
+*
+* # Arguments
+* * sensor - Sensor to check
+*
+* Return true if the sensor is available, false otherwise
+*/
+pub fn is_available(sensor: SensorType) -> bool {
+    unsafe {
+        match ffi::sfSensor_isAvailable(sensor as c_uint) {
+            SFFALSE   => false,
+            SFTRUE    => true
+        }
+    }
+}
+
+/**
+* Enable or disable a sensor
+*
+* All sensors are disabled by default, to avoid consuming too
+* much battery power. Once a sensor is enabled, it starts
+* sending events of the corresponding type.
+*
+* # Arguments
+* * sensor - Sensor to enable
+* * enabled - true to enable, false to disable
+*/
+pub fn set_enabled(sensor: SensorType, enabled: bool) -> () {
+    unsafe {
+        match enabled {
+            true  => ffi::sfSensor_setEnabled(sensor as c_uint, SFTRUE),
+            false => ffi::sfSensor_setEnabled(sensor as c_uint, SFFALSE)
+        }
+    }
+}
+
+/**
+* Get the current sensor value
+*
+* # Arguments
+* * sensor - Sensor to read
+*
+* Return the current sensor value
+*/
+pub fn get_value(sensor: SensorType) -> Vector3f {
+    unsafe {
+        ffi::sfSensor_getValue(sensor as c_uint)
+    }
+}
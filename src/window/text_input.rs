@@ -0,0 +1,146 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+//! Accumulate the text typed by the user
+//!
+//! TextInput builds a String from TextEntered events, handling backspace
+//! and ignoring the other control characters, for in-game text boxes.
+
+use window::event::{Event, TextEntered};
+
+/// Accumulate the text typed by the user
+#[derive(Clone, PartialEq, Eq, Show)]
+pub struct TextInput {
+    #[doc(hidden)]
+    text: String,
+    #[doc(hidden)]
+    max_length: Option<uint>,
+    #[doc(hidden)]
+    multiline: bool
+}
+
+impl TextInput {
+    /// Create a new empty TextInput
+    ///
+    /// Return a new TextInput
+    pub fn new() -> TextInput {
+        TextInput {
+            text: String::new(),
+            max_length: None,
+            multiline: false
+        }
+    }
+
+    /// Handle an event
+    ///
+    /// Only TextEntered events are used, the others are ignored.
+    ///
+    /// # Arguments
+    /// * event - The event to handle
+    ///
+    /// Return true if the text changed
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match *event {
+            TextEntered { code } => self.push(code),
+            _                    => false
+        }
+    }
+
+    /// Add a character as if it was typed
+    ///
+    /// Backspace removes the last character, carriage return adds a new
+    /// line if the input is multiline, other control characters are
+    /// ignored.
+    ///
+    /// # Arguments
+    /// * code - The character typed
+    ///
+    /// Return true if the text changed
+    pub fn push(&mut self, code: char) -> bool {
+        match code {
+            '\x08' => self.text.pop().is_some(),
+            '\r' | '\n' if self.multiline => self.push_char('\n'),
+            c if c.is_control() => false,
+            c => self.push_char(c)
+        }
+    }
+
+    /// Get the text
+    pub fn get_text(&self) -> &str {
+        self.text.as_slice()
+    }
+
+    /// Replace the text
+    ///
+    /// # Arguments
+    /// * text - The new text
+    pub fn set_text(&mut self, text: &str) -> () {
+        self.text = text.to_string()
+    }
+
+    /// Remove all the text
+    pub fn clear(&mut self) -> () {
+        self.text.clear()
+    }
+
+    /// Limit the number of characters
+    ///
+    /// # Arguments
+    /// * max_length - Some(maximum) or None for no limit
+    pub fn set_max_length(&mut self, max_length: Option<uint>) -> () {
+        self.max_length = max_length
+    }
+
+    /// Get the maximum number of characters
+    ///
+    /// Return Some(maximum) or None if there is no limit
+    pub fn get_max_length(&self) -> Option<uint> {
+        self.max_length
+    }
+
+    /// Allow new lines in the text
+    ///
+    /// # Arguments
+    /// * multiline - true to add a new line on return
+    pub fn set_multiline(&mut self, multiline: bool) -> () {
+        self.multiline = multiline
+    }
+
+    /// Tell whether new lines are allowed in the text
+    pub fn is_multiline(&self) -> bool {
+        self.multiline
+    }
+
+    /// Add a character if the maximum length allows it
+    fn push_char(&mut self, c: char) -> bool {
+        match self.max_length {
+            Some(max) if self.text.chars().count() >= max => false,
+            _ => {
+                self.text.push(c);
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/*
+* Rust-SFML - Copyright (c) 2013 Letang Jeremy.
+*
+* The original software, SFML library, is provided by Laurent Gomila.
+*
+* This software is provided 'as-is', without any express or implied warranty.
+* In no event will the authors be held liable for any damages arising from
+* the use of this software.
+*
+* Permission is granted to anyone to use this software for any purpose,
+* including commercial applications, and to alter it and redistribute it
+* freely, subject to the following restrictions:
+*
+* 1. The origin of this software must not be misrepresented; you must not claim
+*    that you wrote the original software. If you use this software in a product,
+*    an acknowledgment in the product documentation would be appreciated but is
+*    not required.
+*
+* 2. Altered source versions must be plainly marked as such, and must not be
+*    misrepresented as being the original software.
+*
+* 3. This notice may not be removed or altered from any source distribution.
+*/
+
+
+/*!
+* Touch events.
+*
+* Give access to the real-time state of the touches
+*/
+
+use libc::c_uint;
+use std::ptr;
+
+use system::vector2::Vector2i;
+
+use ffi::sfml_types::{SFTRUE, SFFALSE};
+use ffi::window::touch as ffi;
+use ffi::window::window::sfTouch_getPosition;
+
+/**
+* Check if a touch event is currently down
+*
+* # Arguments
+* * finger - Finger index
+*
+* Return true if finger is currently touching the screen, false otherwise
+*/
+pub fn is_down(finger: uint) -> bool {
+    unsafe {
+        match ffi::sfTouch_isDown(finger as c_uint) {
+            SFFALSE   => false,
+            SFTRUE    => true
+        }
+    }
+}
+
+/**
+* Get the current position of a touch in desktop coordinates
+*
+* Use Window::get_touch_position or RenderWindow::get_touch_position
+* to get the position relative to a window.
+*
+* # Arguments
+* * finger - Finger index
+*
+* Return the current position of the finger, in desktop coordinates
+*/
+pub fn get_position(finger: uint) -> Vector2i {
+    unsafe {
+        sfTouch_getPosition(finger as c_uint, ptr::null_mut())
+    }
+}
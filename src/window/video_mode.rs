@@ -96,7 +96,7 @@ impl VideoMode {
 
     /// Static Method, get the current desktop video mode
     ///
-    /// return the urrent desktop video mode
+    /// return the current desktop video mode
     pub fn get_desktop_mode() -> VideoMode {
         let mode = unsafe { ffi::sfVideoMode_getDesktopMode() };
         VideoMode{
@@ -145,6 +145,62 @@ impl VideoMode {
 
         Some(ret_tab)
     }
+
+    /// Static Method, iterate over the video modes supported in fullscreen mode
+    ///
+    /// This is the iterator version of get_fullscreen_modes, it yields
+    /// nothing if no fullscreen mode is available.
+    ///
+    /// ```ignore
+    /// for mode in VideoMode::fullscreen_modes().filter(|m| m.bits_per_pixel == 32) {
+    ///     println!("{}x{}", mode.width, mode.height);
+    /// }
+    /// ```
+    ///
+    /// Return an iterator over all the supported VideoMode
+    pub fn fullscreen_modes() -> FullscreenModes {
+        let mut size: size_t = 0;
+        let modes = unsafe {
+            ffi::sfVideoMode_getFullscreenModes(&mut size)
+        };
+        FullscreenModes {
+            modes: modes as *const ffi::sfVideoMode,
+            count: if modes.is_null() { 0 } else { size as uint },
+            index: 0
+        }
+    }
+}
+
+/// An iterator over the video modes supported in fullscreen mode
+///
+/// The modes are sorted from best to worst.
+pub struct FullscreenModes {
+    #[doc(hidden)]
+    modes: *const ffi::sfVideoMode,
+    #[doc(hidden)]
+    count: uint,
+    #[doc(hidden)]
+    index: uint
+}
+
+impl Iterator for FullscreenModes {
+    type Item = VideoMode;
+
+    fn next(&mut self) -> Option<VideoMode> {
+        if self.index >= self.count {
+            None
+        } else {
+            // The array is owned by SFML and valid for the whole program
+            let mode = unsafe { (*self.modes.offset(self.index as int)).clone() };
+            self.index += 1;
+            Some(Wrappable::wrap(mode))
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let left = self.count - self.index;
+        (left, Some(left))
+    }
 }
 
 #[doc(hidden)]
@@ -29,16 +29,22 @@
 //! and abstractions for events and input handling.
 
 use libc::{c_uint, c_float};
-use std::ptr;
+use std::cmp;
+use std::rc::Rc;
 use std::vec::Vec;
-use std::ffi::CString;
 
 use traits::Wrappable;
-use window::{event, VideoMode, ContextSettings, WindowStyle};
+use window::event::TimedEvents;
+use window::{event, VideoMode, ContextSettings, WindowStyle, Cursor,
+             BorderlessFullscreen, WindowHandle, ScaleWatcher};
 use system::vector2::{Vector2i, Vector2u};
+use graphics::Image;
+use system::{Clock, Time, sleep};
+use system::utf32::{to_utf32, to_terminated};
 
 use ffi::sfml_types::{SFTRUE, SFFALSE};
 use ffi::window::window as ffi;
+use ffi::native;
 
 ///
 /// Window manipulation
@@ -50,13 +56,25 @@ pub struct Window {
     #[doc(hidden)]
     window: *mut ffi::sfWindow,
     #[doc(hidden)]
-    title_length: uint
+    relative_mouse: bool,
+    #[doc(hidden)]
+    cursor: Option<Rc<Cursor>>,
+    #[doc(hidden)]
+    scale: Rc<ScaleWatcher>,
+    #[doc(hidden)]
+    clock: Rc<Clock>
 }
 
 /// An iterator over all the events in the events queue (internally call poll_event)
+///
+/// The iterator stops once the queue is empty, so it only yields the
+/// events pending when it is created (and those arriving meanwhile).
+/// It must not be kept after the window is destroyed.
 pub struct Events {
+    #[doc(hidden)]
     window: *mut ffi::sfWindow,
-    event: event::raw::sfEvent,
+    #[doc(hidden)]
+    scale: Rc<ScaleWatcher>
 }
 
 impl Window {
@@ -83,20 +101,7 @@ impl Window {
                title: &str,
                style: WindowStyle,
                settings: &ContextSettings) -> Option<Window> {
-
-        let mut sf_win: *mut ffi::sfWindow = ptr::null_mut();
-        let c_str = CString::from_slice(title.as_bytes()).as_ptr();
-        unsafe {
-            sf_win = ffi::sfWindow_create(mode.unwrap(), c_str, style as u32, settings);
-        };
-        if sf_win.is_null() {
-            None
-        } else {
-            Some (Window {
-                    window: sf_win,
-                    title_length: title.len()
-                })
-        }
+        Window::new_with_unicode(mode, to_utf32(title), style, settings)
     }
 
     /// Construct a new window (with a UTF-32 title)
@@ -123,28 +128,117 @@ impl Window {
                             style: WindowStyle,
                             settings: &ContextSettings) -> Option<Window> {
 
+        let title = to_terminated(title);
+        let (mode, sf_style) = style.resolve(mode);
         let sf_win =
             unsafe { ffi::sfWindow_createUnicode(mode.unwrap(),
                                                  title.as_ptr(),
-                                                 style as u32, settings) };
+                                                 sf_style, settings) };
+        if sf_win.is_null() {
+            None
+        } else {
+            if style == BorderlessFullscreen {
+                unsafe { ffi::sfWindow_setPosition(sf_win, Vector2i::new(0, 0)) }
+            }
+            Some (Window {
+                    window: sf_win,
+                    relative_mouse: false,
+                    cursor: None,
+                    scale: ScaleWatcher::new(unsafe { ffi::sfWindow_getSystemHandle(sf_win) }),
+                    clock: Rc::new(Clock::new())
+                })
+        }
+    }
+
+    /// Construct a new window kept above all the other windows
+    ///
+    /// This is Window::new followed by set_always_on_top, for
+    /// companion windows and overlays which must start on top.
+    ///
+    /// # Arguments
+    /// * mode - Video mode to use (defines the width, height and depth of the rendering area of the window)
+    /// * title - Title of the window
+    /// * style - Window style
+    /// * settings - Additional settings for the underlying OpenGL context
+    ///
+    /// Return Some(Window) or None
+    pub fn new_always_on_top(mode: VideoMode,
+                             title: &str,
+                             style: WindowStyle,
+                             settings: &ContextSettings) -> Option<Window> {
+        Window::new(mode, title, style, settings).map(|mut window| {
+            window.set_always_on_top(true);
+            window
+        })
+    }
+
+    /// Construct a window from an existing control
+    ///
+    /// Use this constructor if you want to create an OpenGL rendering
+    /// area into an already existing control, like a widget of a GUI
+    /// toolkit.
+    ///
+    /// The second parameter is a pointer to a structure specifying
+    /// advanced OpenGL context settings such as antialiasing,
+    /// depth-buffer bits, etc.
+    ///
+    /// # Arguments
+    /// * handle - Platform-specific handle of the control
+    /// * settings - Additional settings for the underlying OpenGL context
+    ///
+    /// Return Some(Window) or None
+    pub fn new_from_handle(handle: WindowHandle,
+                           settings: &ContextSettings) -> Option<Window> {
+        let sf_win = unsafe { ffi::sfWindow_createFromHandle(handle, settings) };
         if sf_win.is_null() {
             None
         } else {
             Some (Window {
                     window: sf_win,
-                    title_length: title.len()
+                    relative_mouse: false,
+                    cursor: None,
+                    scale: ScaleWatcher::new(unsafe { ffi::sfWindow_getSystemHandle(sf_win) }),
+                    clock: Rc::new(Clock::new())
                 })
         }
     }
 
     /// Return an iterator over all the event currently in the events queue.
+    ///
+    /// This replaces the usual poll_event loop:
+    ///
+    /// ```ignore
+    /// for event in window.events() {
+    ///     match event {
+    ///         event::Closed => window.close(),
+    ///         _             => {}
+    ///     }
+    /// }
+    /// ```
     pub fn events(&self) -> Events {
         Events {
-            window: self.window.clone(),
-            event: event::raw::sfEvent { data: [032; 6u] }
+            window: self.window,
+            scale: self.scale.clone()
         }
     }
 
+    /// Return an iterator over the events in the events queue, with the
+    /// time they were polled at
+    ///
+    /// The times come from a monotonic clock started when the window was
+    /// created, the same as get_time, so they can be compared across
+    /// frames to detect double clicks or long presses.
+    pub fn timed_events(&self) -> TimedEvents<Events> {
+        TimedEvents::new(self.events(), self.clock.clone())
+    }
+
+    /// Get the time elapsed since the window was created
+    ///
+    /// Return the time on the clock used to stamp the events
+    pub fn get_time(&self) -> Time {
+        self.clock.get_elapsed_time()
+    }
+
     ///  Pop the event on top of event queue, if any, and return it
     ///
     /// This function is not blocking: if there's no pending event then
@@ -155,7 +249,11 @@ impl Window {
     ///
     /// Return the event if an event was returned, or NoEvent if the event queue was empty
     pub fn poll_event(&mut self) -> event::Event {
-        let mut event = event::raw::sfEvent { data: [032; 6u] };
+        match self.scale.take_pending() {
+            Some(event) => return event,
+            None        => ()
+        }
+        let mut event = event::raw::sfEvent { data: [032; 7u] };
         let have_event: bool =  unsafe {
             match ffi::sfWindow_pollEvent(self.window, &mut event) {
                 SFFALSE     => false,
@@ -165,7 +263,8 @@ impl Window {
         if have_event == false {
             event::NoEvent
         } else {
-            event::raw::get_wrapped_event(&mut event)
+            self.scale.watch(self.get_system_handle(),
+                             event::raw::get_wrapped_event(&mut event))
         }
     }
 
@@ -181,7 +280,11 @@ impl Window {
     ///
     /// Return the event or NoEvent if an error has occured
     pub fn wait_event(&mut self) -> event::Event {
-        let mut event = event::raw::sfEvent { data: [032; 6u] };
+        match self.scale.take_pending() {
+            Some(event) => return event,
+            None        => ()
+        }
+        let mut event = event::raw::sfEvent { data: [032; 7u] };
         let have_event: bool =  unsafe {
             match ffi::sfWindow_waitEvent(self.window, &mut event) {
                 SFFALSE     => false,
@@ -191,7 +294,34 @@ impl Window {
         if have_event == false {
             return event::NoEvent;
         } else {
-            event::raw::get_wrapped_event(&mut event)
+            self.scale.watch(self.get_system_handle(),
+                             event::raw::get_wrapped_event(&mut event))
+        }
+    }
+
+    /// Wait for an event and return it, giving up after a timeout
+    ///
+    /// Unlike wait_event this doesn't block forever, which lets
+    /// event-driven applications wake up periodically to run timers.
+    /// The window is polled every few milliseconds while waiting.
+    ///
+    /// # Arguments
+    /// * timeout - Maximum time to wait
+    ///
+    /// Return Some(event) or None if no event arrived before the timeout
+    pub fn wait_event_timeout(&mut self, timeout: Time) -> Option<event::Event> {
+        let clock = Clock::new();
+        loop {
+            match self.poll_event() {
+                event::NoEvent => (),
+                event          => return Some(event)
+            }
+            let remaining = timeout.as_microseconds() -
+                clock.get_elapsed_time().as_microseconds();
+            if remaining <= 0 {
+                return None;
+            }
+            sleep(Time::with_microseconds(cmp::min(remaining, 5000)));
         }
     }
 
@@ -200,6 +330,7 @@ impl Window {
     /// # Arguments
     /// * title - New title
     pub fn set_unicode_title(&mut self, title: Vec<u32>) -> () {
+        let title = to_terminated(title);
         unsafe {
             ffi::sfWindow_setUnicodeTitle(self.window, title.as_ptr())
         }
@@ -211,13 +342,25 @@ impl Window {
     /// # Arguments
     /// * width - Icon's width, in pixels
     /// * height - Icon's height, in pixels
-    /// * pixels - Vector of pixels
-    pub fn set_icon(&mut self, width: uint, height: uint, pixels: Vec<u8>) -> () {
+    /// * pixels - Slice of pixels
+    pub fn set_icon(&mut self, width: uint, height: uint, pixels: &[u8]) -> () {
+        if pixels.len() < width * height * 4 {
+            panic!("Not enough pixels for the icon")
+        }
         unsafe {
             ffi::sfWindow_setIcon(self.window, width as c_uint, height as c_uint, pixels.as_ptr())
         }
     }
 
+    /// Change a window's icon to an image
+    ///
+    /// # Arguments
+    /// * image - The icon image
+    pub fn set_icon_from_image(&mut self, image: &Image) -> () {
+        let size = image.get_size();
+        self.set_icon(size.x as uint, size.y as uint, image.get_pixels())
+    }
+
     /// Close a window and destroy all the attached resources
     ///
     /// After calling this method, the Window object remains
@@ -258,13 +401,12 @@ impl Window {
 
     /// Change the title of a window
     ///
+    /// The title can contain any Unicode character.
+    ///
     /// # Arguments
     /// * title - New title
     pub fn set_title(&mut self, title: &str) -> () {
-        let c_str = CString::from_slice(title.as_bytes()).as_ptr();
-        unsafe {
-            ffi::sfWindow_setTitle(self.window, c_str)
-        }
+        self.set_unicode_title(to_utf32(title))
     }
 
     /// Show or hide a window
@@ -280,10 +422,64 @@ impl Window {
         }
     }
 
+    /// Change the opacity of a window
+    ///
+    /// This goes through the native window handle. On Linux the
+    /// window manager must support _NET_WM_WINDOW_OPACITY (most
+    /// compositing ones do).
+    ///
+    /// # Arguments
+    /// * opacity - Opacity, from 0 (fully transparent) to 1 (opaque)
+    ///
+    /// Return true if the opacity was changed
+    pub fn set_opacity(&mut self, opacity: f32) -> bool {
+        native::set_opacity(self.get_system_handle(), opacity)
+    }
+
+    /// Keep a window above all the other ones, or stop doing so
+    ///
+    /// This goes through the native window handle. Use
+    /// new_always_on_top to have the window start on top.
+    ///
+    /// # Arguments
+    /// * on_top - true to keep the window on top, false for the normal behavior
+    ///
+    /// Return true if the request was sent to the window system
+    pub fn set_always_on_top(&mut self, on_top: bool) -> bool {
+        native::set_always_on_top(self.get_system_handle(), on_top)
+    }
+
+    /// Get the OS-specific handle of the window
+    ///
+    /// The type of the returned handle is WindowHandle, which is
+    /// HWND on Windows, Window on Linux/FreeBSD and NSWindow on OS X.
+    /// You shouldn't need to use this function, unless you have
+    /// very specific stuff to implement that SFML doesn't support,
+    /// or implement a temporary workaround until a bug is fixed.
+    ///
+    /// Return the system handle of the window
+    pub fn get_system_handle(&self) -> WindowHandle {
+        unsafe {
+            ffi::sfWindow_getSystemHandle(self.window)
+        }
+    }
+
+    /// Get the scale factor of the window
+    ///
+    /// The scale factor is the ratio between the DPI of the monitor and
+    /// the usual 96 DPI: fonts and layouts should be scaled by it to keep
+    /// their physical size. A ScaleFactorChanged event is sent when it
+    /// changes.
+    ///
+    /// Return the scale factor, 1 if it can't be known
+    pub fn get_scale_factor(&self) -> f32 {
+        native::get_scale_factor(self.get_system_handle())
+    }
+
     /// Show or hide the mouse cursor
     ///
     /// # Arguments
-    /// * visible - true to  false to hide
+    /// * visible - true to show, false to hide
     pub fn set_mouse_cursor_visible(&mut self, visible: bool) -> () {
         unsafe {
             match visible {
@@ -293,6 +489,63 @@ impl Window {
         }
     }
 
+    /// Grab or release the mouse cursor
+    ///
+    /// If set, grabs the mouse cursor inside this window's client
+    /// area so it may no longer be moved outside its bounds.
+    /// Note that grabbing is only active while the window has
+    /// focus.
+    ///
+    /// # Arguments
+    /// * grabbed - true to enable, false to disable
+    pub fn set_mouse_cursor_grabbed(&mut self, grabbed: bool) -> () {
+        unsafe {
+            match grabbed {
+                true    => ffi::sfWindow_setMouseCursorGrabbed(self.window, SFTRUE),
+                false   => ffi::sfWindow_setMouseCursorGrabbed(self.window, SFFALSE)
+            }
+        }
+    }
+
+    /// Set the displayed cursor to a native system cursor or a custom one
+    ///
+    /// The window keeps the cursor alive for as long as it displays it,
+    /// and the same cursor can be shared by several windows.
+    ///
+    /// # Arguments
+    /// * cursor - Cursor to display
+    pub fn set_mouse_cursor(&mut self, cursor: Rc<Cursor>) -> () {
+        unsafe {
+            ffi::sfWindow_setMouseCursor(self.window, cursor.unwrap())
+        }
+        self.cursor = Some(cursor);
+    }
+
+    /// Request the current window to be made the active foreground window
+    ///
+    /// At any given time, only one window may have the input focus
+    /// to receive input events such as keystrokes or mouse events.
+    /// If a window requests focus, it only hints to the operating
+    /// system, that it would like to be focused. The operating system
+    /// is free to deny the request.
+    pub fn request_focus(&mut self) -> () {
+        unsafe {
+            ffi::sfWindow_requestFocus(self.window)
+        }
+    }
+
+    /// Check whether the window has the input focus
+    ///
+    /// The GainedFocus and LostFocus events report the changes.
+    ///
+    /// Return true if the window has focus, false otherwise
+    pub fn has_focus(&self) -> bool {
+        match unsafe { ffi::sfWindow_hasFocus(self.window) } {
+            SFFALSE => false,
+            SFTRUE  => true
+        }
+    }
+
     /// Enable or disable vertical synchronization
     ///
     /// Activating vertical synchronization will limit the number
@@ -463,6 +716,69 @@ impl Window {
         }
     }
 
+    /// Enable or disable the relative mouse mode
+    ///
+    /// In relative mode the cursor is hidden, grabbed and kept at the
+    /// center of the window, so get_mouse_delta reports the motion of
+    /// the mouse even when the cursor would have reached the edge of
+    /// the screen. This is the usual mode of camera-look controls.
+    ///
+    /// Keeping the cursor centered generates MouseMoved events,
+    /// ignore them while the relative mode is enabled.
+    ///
+    /// # Arguments
+    /// * enabled - true to enable, false to disable
+    pub fn set_relative_mouse_mode(&mut self, enabled: bool) -> () {
+        self.relative_mouse = enabled;
+        self.set_mouse_cursor_visible(!enabled);
+        self.set_mouse_cursor_grabbed(enabled);
+        if enabled {
+            let center = self.get_window_center();
+            self.set_mouse_position(&center);
+        }
+    }
+
+    /// Tell whether the relative mouse mode is enabled
+    pub fn is_relative_mouse_mode(&self) -> bool {
+        self.relative_mouse
+    }
+
+    /// Get the motion of the mouse since the last call
+    ///
+    /// Only available in relative mode, call it once per frame.
+    /// The motion is zero while the window doesn't have the focus.
+    ///
+    /// Return the motion of the mouse, in pixels
+    pub fn get_mouse_delta(&mut self) -> Vector2i {
+        if !self.relative_mouse || !self.has_focus() {
+            return Vector2i::new(0, 0);
+        }
+        let center = self.get_window_center();
+        let position = self.get_mouse_position();
+        self.set_mouse_position(&center);
+        Vector2i::new(position.x - center.x, position.y - center.y)
+    }
+
+    /// Get the center of the window, in window coordinates
+    fn get_window_center(&self) -> Vector2i {
+        let size = self.get_size();
+        Vector2i::new((size.x / 2) as i32, (size.y / 2) as i32)
+    }
+
+    /// Get the current position of a touch
+    ///
+    /// This function returns the current touch position relative to the window.
+    ///
+    /// # Arguments
+    /// * finger - Finger index
+    ///
+    /// Return the current position of the finger, relative to the window
+    pub fn get_touch_position(&self, finger: uint) -> Vector2i {
+        unsafe {
+            ffi::sfTouch_getPosition(finger as c_uint, self.window)
+        }
+    }
+
     #[doc(hidden)]
     pub fn unwrap(&self) -> *mut ffi::sfWindow {
         self.window
@@ -473,10 +789,17 @@ impl Iterator for Events {
     type Item = event::Event;
 
     fn next(&mut self) -> Option<event::Event> {
-        let mut event = event::raw::sfEvent { data: [032; 6u] };
+        match self.scale.take_pending() {
+            Some(event) => return Some(event),
+            None        => ()
+        }
+        let mut event = event::raw::sfEvent { data: [032; 7u] };
         match unsafe { ffi::sfWindow_pollEvent(self.window, &mut event) } {
             SFFALSE     => None,
-            SFTRUE      => Some(event::raw::get_wrapped_event(&mut event))
+            SFTRUE      => {
+                let handle = unsafe { ffi::sfWindow_getSystemHandle(self.window) };
+                Some(self.scale.watch(handle, event::raw::get_wrapped_event(&mut event)))
+            }
         }
     }
 }
@@ -27,7 +27,9 @@
 //! Availables window styles
 
 pub use self::WindowStyle::{NoStyle, Titlebar, Resize, Close, Fullscreen,
-                            DefaultStyle};
+                            DefaultStyle, BorderlessFullscreen};
+
+use window::VideoMode;
 
 /// Enumeration of window creation styles
 #[repr(C)]
@@ -44,5 +46,22 @@ pub enum WindowStyle {
     /// Fullscreen mode (this flag and all others are mutually exclusive)
     Fullscreen = 8,
     /// Default window style.
-    DefaultStyle = 7
+    DefaultStyle = 7,
+    /// Undecorated window covering the whole desktop, the video mode is
+    /// ignored (this flag and all others are mutually exclusive)
+    BorderlessFullscreen = 16
+}
+
+impl WindowStyle {
+    /// Get the video mode and the raw style to create a window with
+    ///
+    /// BorderlessFullscreen has no CSFML counterpart, it is turned into
+    /// an undecorated window with the size of the desktop.
+    #[doc(hidden)]
+    pub fn resolve(&self, mode: VideoMode) -> (VideoMode, u32) {
+        match *self {
+            BorderlessFullscreen => (VideoMode::get_desktop_mode(), NoStyle as u32),
+            style                => (mode, style as u32)
+        }
+    }
 }
\ No newline at end of file